@@ -21,6 +21,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -48,9 +50,17 @@ export function getAdminAccountDiscriminatorBytes() {
 export type AdminAccount = {
   discriminator: ReadonlyUint8Array;
   authority: Address;
+  /** Share of every pool fee going to the protocol, in basis points */
+  protocolFeeRate: number;
+  protocolFeeAuthority: Address;
 };
 
-export type AdminAccountArgs_ = { authority: Address };
+export type AdminAccountArgs_ = {
+  authority: Address;
+  /** Share of every pool fee going to the protocol, in basis points */
+  protocolFeeRate: number;
+  protocolFeeAuthority: Address;
+};
 
 /** Gets the encoder for {@link AdminAccountArgs_} account data. */
 export function getAdminAccountEncoder(): FixedSizeEncoder<AdminAccountArgs_> {
@@ -58,6 +68,8 @@ export function getAdminAccountEncoder(): FixedSizeEncoder<AdminAccountArgs_> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['authority', getAddressEncoder()],
+      ['protocolFeeRate', getU16Encoder()],
+      ['protocolFeeAuthority', getAddressEncoder()],
     ]),
     (value) => ({ ...value, discriminator: ADMIN_ACCOUNT_DISCRIMINATOR })
   );
@@ -68,6 +80,8 @@ export function getAdminAccountDecoder(): FixedSizeDecoder<AdminAccount> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['authority', getAddressDecoder()],
+    ['protocolFeeRate', getU16Decoder()],
+    ['protocolFeeAuthority', getAddressDecoder()],
   ]);
 }
 
@@ -133,5 +147,5 @@ export async function fetchAllMaybeAdminAccount(
 }
 
 export function getAdminAccountSize(): number {
-  return 40;
+  return 74;
 }
@@ -17,30 +17,34 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
-  getStakePoolConfigArgsDecoder,
-  getStakePoolConfigArgsEncoder,
-  type StakePoolConfigArgs,
-  type StakePoolConfigArgsArgs_,
+  getConfigChangeDecoder,
+  getConfigChangeEncoder,
+  type ConfigChange,
+  type ConfigChangeArgs_,
 } from '../types';
 
 export const ANNOUNCED_CONFIG_ACCOUNT_DISCRIMINATOR = new Uint8Array([
@@ -58,25 +62,51 @@ export type AnnouncedConfigAccount = {
   stakePool: Address;
   authority: Address;
   timestamp: bigint;
-  config: StakePoolConfigArgs;
+  /** The config_version the changes were validated against */
+  configVersion: bigint;
+  changes: Array<ConfigChange>;
+  /** The pool's governance_quorum_rate at the announcement, 0 without governance */
+  quorumRate: number;
+  /** Active stake of the pool at the announcement, the quorum is a share of it */
+  totalStake: bigint;
+  yesVotes: bigint;
+  noVotes: bigint;
+  /** Receives the rent back when the announcement is vetoed */
+  rentPayer: Address;
 };
 
 export type AnnouncedConfigAccountArgs_ = {
   stakePool: Address;
   authority: Address;
   timestamp: number | bigint;
-  config: StakePoolConfigArgsArgs_;
+  /** The config_version the changes were validated against */
+  configVersion: number | bigint;
+  changes: Array<ConfigChangeArgs_>;
+  /** The pool's governance_quorum_rate at the announcement, 0 without governance */
+  quorumRate: number;
+  /** Active stake of the pool at the announcement, the quorum is a share of it */
+  totalStake: number | bigint;
+  yesVotes: number | bigint;
+  noVotes: number | bigint;
+  /** Receives the rent back when the announcement is vetoed */
+  rentPayer: Address;
 };
 
 /** Gets the encoder for {@link AnnouncedConfigAccountArgs_} account data. */
-export function getAnnouncedConfigAccountEncoder(): FixedSizeEncoder<AnnouncedConfigAccountArgs_> {
+export function getAnnouncedConfigAccountEncoder(): Encoder<AnnouncedConfigAccountArgs_> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['stakePool', getAddressEncoder()],
       ['authority', getAddressEncoder()],
       ['timestamp', getU64Encoder()],
-      ['config', getStakePoolConfigArgsEncoder()],
+      ['configVersion', getU64Encoder()],
+      ['changes', getArrayEncoder(getConfigChangeEncoder())],
+      ['quorumRate', getU16Encoder()],
+      ['totalStake', getU64Encoder()],
+      ['yesVotes', getU64Encoder()],
+      ['noVotes', getU64Encoder()],
+      ['rentPayer', getAddressEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -86,18 +116,24 @@ export function getAnnouncedConfigAccountEncoder(): FixedSizeEncoder<AnnouncedCo
 }
 
 /** Gets the decoder for {@link AnnouncedConfigAccount} account data. */
-export function getAnnouncedConfigAccountDecoder(): FixedSizeDecoder<AnnouncedConfigAccount> {
+export function getAnnouncedConfigAccountDecoder(): Decoder<AnnouncedConfigAccount> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['stakePool', getAddressDecoder()],
     ['authority', getAddressDecoder()],
     ['timestamp', getU64Decoder()],
-    ['config', getStakePoolConfigArgsDecoder()],
+    ['configVersion', getU64Decoder()],
+    ['changes', getArrayDecoder(getConfigChangeDecoder())],
+    ['quorumRate', getU16Decoder()],
+    ['totalStake', getU64Decoder()],
+    ['yesVotes', getU64Decoder()],
+    ['noVotes', getU64Decoder()],
+    ['rentPayer', getAddressDecoder()],
   ]);
 }
 
 /** Gets the codec for {@link AnnouncedConfigAccount} account data. */
-export function getAnnouncedConfigAccountCodec(): FixedSizeCodec<
+export function getAnnouncedConfigAccountCodec(): Codec<
   AnnouncedConfigAccountArgs_,
   AnnouncedConfigAccount
 > {
@@ -175,7 +211,3 @@ export async function fetchAllMaybeAnnouncedConfigAccount(
     decodeAnnouncedConfigAccount(maybeAccount)
   );
 }
-
-export function getAnnouncedConfigAccountSize(): number {
-  return 96;
-}
@@ -8,6 +8,13 @@
 
 export * from './adminAccount';
 export * from './announcedConfigAccount';
+export * from './assetLockAccount';
+export * from './configChangeAccount';
+export * from './deviceScoreAccount';
+export * from './distributorAccount';
 export * from './nftStakeAccount';
+export * from './rewardStreamAccount';
 export * from './stakePoolAccount';
+export * from './userPoolStakeAccount';
 export * from './userStakeAccount';
+export * from './voteRecordAccount';
@@ -17,10 +17,16 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -38,6 +44,20 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getAssetStandardDecoder,
+  getAssetStandardEncoder,
+  getStakeActivationDecoder,
+  getStakeActivationEncoder,
+  getStakeHistoryDecoder,
+  getStakeHistoryEncoder,
+  type AssetStandard,
+  type AssetStandardArgs_,
+  type StakeActivation,
+  type StakeActivationArgs_,
+  type StakeHistory,
+  type StakeHistoryArgs_,
+} from '../types';
 
 export const NFT_STAKE_ACCOUNT_DISCRIMINATOR = new Uint8Array([
   109, 22, 236, 62, 96, 242, 14, 116,
@@ -55,8 +75,38 @@ export type NftStakeAccount = {
   stakeAuthority: Address;
   depositAuthority: Address;
   nftTokenAccount: Address;
+  collection: Address;
   amount: bigint;
   commisionRate: number;
+  depositorCount: number;
+  /**
+   * Cumulative growth of deposits from slashing and redistributed penalties,
+   * scaled by SLASH_FACTOR_PRECISION
+   */
+  slashFactor: bigint;
+  /** The asset was unstaked with depositors left, they still settle against slash_factor */
+  unstaked: boolean;
+  stakeHistory: StakeHistory;
+  activation: StakeActivation;
+  /** Receives the rent back when the account is closed */
+  rentPayer: Address;
+  assetStandard: AssetStandard;
+  /**
+   * Cumulative stake entering the nft stake's positions through deposits, redelegations and
+   * compounded rewards, and leaving them through withdrawals, redelegations and slashing.
+   * Penalties redistributed to the other depositors stay, so that
+   * amount == total_deposited - total_withdrawn - total_slashed
+   */
+  totalDeposited: bigint;
+  totalWithdrawn: bigint;
+  totalSlashed: bigint;
+  /** Last time a position of the nft stake changed */
+  lastActivityTimestamp: bigint;
+  /**
+   * Legacy user stakes are still being migrated, positions stay frozen until
+   * finalize_nft_stake_migration
+   */
+  migrating: boolean;
 };
 
 export type NftStakeAccountArgs_ = {
@@ -64,8 +114,38 @@ export type NftStakeAccountArgs_ = {
   stakeAuthority: Address;
   depositAuthority: Address;
   nftTokenAccount: Address;
+  collection: Address;
   amount: number | bigint;
   commisionRate: number;
+  depositorCount: number;
+  /**
+   * Cumulative growth of deposits from slashing and redistributed penalties,
+   * scaled by SLASH_FACTOR_PRECISION
+   */
+  slashFactor: number | bigint;
+  /** The asset was unstaked with depositors left, they still settle against slash_factor */
+  unstaked: boolean;
+  stakeHistory: StakeHistoryArgs_;
+  activation: StakeActivationArgs_;
+  /** Receives the rent back when the account is closed */
+  rentPayer: Address;
+  assetStandard: AssetStandardArgs_;
+  /**
+   * Cumulative stake entering the nft stake's positions through deposits, redelegations and
+   * compounded rewards, and leaving them through withdrawals, redelegations and slashing.
+   * Penalties redistributed to the other depositors stay, so that
+   * amount == total_deposited - total_withdrawn - total_slashed
+   */
+  totalDeposited: number | bigint;
+  totalWithdrawn: number | bigint;
+  totalSlashed: number | bigint;
+  /** Last time a position of the nft stake changed */
+  lastActivityTimestamp: number | bigint;
+  /**
+   * Legacy user stakes are still being migrated, positions stay frozen until
+   * finalize_nft_stake_migration
+   */
+  migrating: boolean;
 };
 
 /** Gets the encoder for {@link NftStakeAccountArgs_} account data. */
@@ -77,8 +157,21 @@ export function getNftStakeAccountEncoder(): FixedSizeEncoder<NftStakeAccountArg
       ['stakeAuthority', getAddressEncoder()],
       ['depositAuthority', getAddressEncoder()],
       ['nftTokenAccount', getAddressEncoder()],
+      ['collection', getAddressEncoder()],
       ['amount', getU64Encoder()],
       ['commisionRate', getU8Encoder()],
+      ['depositorCount', getU32Encoder()],
+      ['slashFactor', getU128Encoder()],
+      ['unstaked', getBooleanEncoder()],
+      ['stakeHistory', getStakeHistoryEncoder()],
+      ['activation', getStakeActivationEncoder()],
+      ['rentPayer', getAddressEncoder()],
+      ['assetStandard', getAssetStandardEncoder()],
+      ['totalDeposited', getU64Encoder()],
+      ['totalWithdrawn', getU64Encoder()],
+      ['totalSlashed', getU64Encoder()],
+      ['lastActivityTimestamp', getU64Encoder()],
+      ['migrating', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: NFT_STAKE_ACCOUNT_DISCRIMINATOR })
   );
@@ -92,8 +185,21 @@ export function getNftStakeAccountDecoder(): FixedSizeDecoder<NftStakeAccount> {
     ['stakeAuthority', getAddressDecoder()],
     ['depositAuthority', getAddressDecoder()],
     ['nftTokenAccount', getAddressDecoder()],
+    ['collection', getAddressDecoder()],
     ['amount', getU64Decoder()],
     ['commisionRate', getU8Decoder()],
+    ['depositorCount', getU32Decoder()],
+    ['slashFactor', getU128Decoder()],
+    ['unstaked', getBooleanDecoder()],
+    ['stakeHistory', getStakeHistoryDecoder()],
+    ['activation', getStakeActivationDecoder()],
+    ['rentPayer', getAddressDecoder()],
+    ['assetStandard', getAssetStandardDecoder()],
+    ['totalDeposited', getU64Decoder()],
+    ['totalWithdrawn', getU64Decoder()],
+    ['totalSlashed', getU64Decoder()],
+    ['lastActivityTimestamp', getU64Decoder()],
+    ['migrating', getBooleanDecoder()],
   ]);
 }
 
@@ -169,5 +275,5 @@ export async function fetchAllMaybeNftStakeAccount(
 }
 
 export function getNftStakeAccountSize(): number {
-  return 145;
+  return 520;
 }
@@ -17,12 +17,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -41,8 +47,12 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getStakeActivationDecoder,
+  getStakeActivationEncoder,
   getStakePoolConfigDecoder,
   getStakePoolConfigEncoder,
+  type StakeActivation,
+  type StakeActivationArgs_,
   type StakePoolConfig,
   type StakePoolConfigArgs_,
 } from '../types';
@@ -64,6 +74,35 @@ export type StakePoolAccount = {
   config: StakePoolConfig;
   stakeTokenAccount: Address;
   totalAmount: bigint;
+  /** Receipts can change hands, whoever holds them redeems the stake they back with withdraw */
+  receiptMint: Option<Address>;
+  /** No new stakes or deposits once set */
+  sunset: boolean;
+  /** Nft stakes not unstaked yet */
+  nftStakeCount: bigint;
+  rewardVaultCount: number;
+  rewardStreams: Array<Option<Address>>;
+  nextRewardStreamId: bigint;
+  /** Bumped by every confirmed config change */
+  configVersion: bigint;
+  /** Unclaimed fees held in stake_token_account, not part of total_amount */
+  poolFees: bigint;
+  protocolFees: bigint;
+  activation: StakeActivation;
+  /** Users with principal in the pool, one per UserPoolStakeAccount */
+  depositorCount: bigint;
+  /**
+   * Cumulative stake entering the pool through deposits and compounded rewards, and leaving
+   * it through withdrawals and slashing, so that
+   * total_amount == total_deposited - total_withdrawn - total_slashed
+   */
+  totalDeposited: bigint;
+  totalWithdrawn: bigint;
+  totalSlashed: bigint;
+  /** Last time a stake in the pool changed */
+  lastActivityTimestamp: bigint;
+  /** Timestamp of the latest announced config */
+  announcedAt: bigint;
 };
 
 export type StakePoolAccountArgs_ = {
@@ -72,6 +111,35 @@ export type StakePoolAccountArgs_ = {
   config: StakePoolConfigArgs_;
   stakeTokenAccount: Address;
   totalAmount: number | bigint;
+  /** Receipts can change hands, whoever holds them redeems the stake they back with withdraw */
+  receiptMint: OptionOrNullable<Address>;
+  /** No new stakes or deposits once set */
+  sunset: boolean;
+  /** Nft stakes not unstaked yet */
+  nftStakeCount: number | bigint;
+  rewardVaultCount: number;
+  rewardStreams: Array<OptionOrNullable<Address>>;
+  nextRewardStreamId: number | bigint;
+  /** Bumped by every confirmed config change */
+  configVersion: number | bigint;
+  /** Unclaimed fees held in stake_token_account, not part of total_amount */
+  poolFees: number | bigint;
+  protocolFees: number | bigint;
+  activation: StakeActivationArgs_;
+  /** Users with principal in the pool, one per UserPoolStakeAccount */
+  depositorCount: number | bigint;
+  /**
+   * Cumulative stake entering the pool through deposits and compounded rewards, and leaving
+   * it through withdrawals and slashing, so that
+   * total_amount == total_deposited - total_withdrawn - total_slashed
+   */
+  totalDeposited: number | bigint;
+  totalWithdrawn: number | bigint;
+  totalSlashed: number | bigint;
+  /** Last time a stake in the pool changed */
+  lastActivityTimestamp: number | bigint;
+  /** Timestamp of the latest announced config */
+  announcedAt: number | bigint;
 };
 
 /** Gets the encoder for {@link StakePoolAccountArgs_} account data. */
@@ -84,6 +152,25 @@ export function getStakePoolAccountEncoder(): Encoder<StakePoolAccountArgs_> {
       ['config', getStakePoolConfigEncoder()],
       ['stakeTokenAccount', getAddressEncoder()],
       ['totalAmount', getU64Encoder()],
+      ['receiptMint', getOptionEncoder(getAddressEncoder())],
+      ['sunset', getBooleanEncoder()],
+      ['nftStakeCount', getU64Encoder()],
+      ['rewardVaultCount', getU32Encoder()],
+      [
+        'rewardStreams',
+        getArrayEncoder(getOptionEncoder(getAddressEncoder()), { size: 8 }),
+      ],
+      ['nextRewardStreamId', getU64Encoder()],
+      ['configVersion', getU64Encoder()],
+      ['poolFees', getU64Encoder()],
+      ['protocolFees', getU64Encoder()],
+      ['activation', getStakeActivationEncoder()],
+      ['depositorCount', getU64Encoder()],
+      ['totalDeposited', getU64Encoder()],
+      ['totalWithdrawn', getU64Encoder()],
+      ['totalSlashed', getU64Encoder()],
+      ['lastActivityTimestamp', getU64Encoder()],
+      ['announcedAt', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: STAKE_POOL_ACCOUNT_DISCRIMINATOR })
   );
@@ -98,6 +185,25 @@ export function getStakePoolAccountDecoder(): Decoder<StakePoolAccount> {
     ['config', getStakePoolConfigDecoder()],
     ['stakeTokenAccount', getAddressDecoder()],
     ['totalAmount', getU64Decoder()],
+    ['receiptMint', getOptionDecoder(getAddressDecoder())],
+    ['sunset', getBooleanDecoder()],
+    ['nftStakeCount', getU64Decoder()],
+    ['rewardVaultCount', getU32Decoder()],
+    [
+      'rewardStreams',
+      getArrayDecoder(getOptionDecoder(getAddressDecoder()), { size: 8 }),
+    ],
+    ['nextRewardStreamId', getU64Decoder()],
+    ['configVersion', getU64Decoder()],
+    ['poolFees', getU64Decoder()],
+    ['protocolFees', getU64Decoder()],
+    ['activation', getStakeActivationDecoder()],
+    ['depositorCount', getU64Decoder()],
+    ['totalDeposited', getU64Decoder()],
+    ['totalWithdrawn', getU64Decoder()],
+    ['totalSlashed', getU64Decoder()],
+    ['lastActivityTimestamp', getU64Decoder()],
+    ['announcedAt', getU64Decoder()],
   ]);
 }
 
@@ -17,10 +17,16 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -36,6 +42,20 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getStakeActivationDecoder,
+  getStakeActivationEncoder,
+  getStakeHistoryDecoder,
+  getStakeHistoryEncoder,
+  getUserRewardDecoder,
+  getUserRewardEncoder,
+  type StakeActivation,
+  type StakeActivationArgs_,
+  type StakeHistory,
+  type StakeHistoryArgs_,
+  type UserReward,
+  type UserRewardArgs_,
+} from '../types';
 
 export const USER_STAKE_ACCOUNT_DISCRIMINATOR = new Uint8Array([
   167, 87, 153, 81, 129, 95, 15, 213,
@@ -54,6 +74,27 @@ export type UserStakeAccount = {
   user: Address;
   amount: bigint;
   lastDepositTimestamp: bigint;
+  /** The nft stake slash_factor at the last settlement */
+  slashFactor: bigint;
+  /** Part of amount backed by receipt tokens, burned on withdraw */
+  receiptAmount: bigint;
+  /** Reward checkpoints, one per reward stream slot of the pool */
+  rewards: Array<UserReward>;
+  /** Tracks the settled amount, pending slashes only show up after settlement */
+  stakeHistory: StakeHistory;
+  /** Receives the rent back when the account is closed */
+  rentPayer: Address;
+  /** Deposited amount not yet withdrawn, counted against max_per_user */
+  principal: bigint;
+  activation: StakeActivation;
+  /** Lets anyone compound rewards in the stake mint into the stake */
+  autoCompound: boolean;
+  /**
+   * Amount held before the position first grew after the announcement at
+   * vote_snapshot_time, stake added later does not vote on it
+   */
+  voteSnapshotAmount: bigint;
+  voteSnapshotTime: bigint;
 };
 
 export type UserStakeAccountArgs_ = {
@@ -62,6 +103,27 @@ export type UserStakeAccountArgs_ = {
   user: Address;
   amount: number | bigint;
   lastDepositTimestamp: number | bigint;
+  /** The nft stake slash_factor at the last settlement */
+  slashFactor: number | bigint;
+  /** Part of amount backed by receipt tokens, burned on withdraw */
+  receiptAmount: number | bigint;
+  /** Reward checkpoints, one per reward stream slot of the pool */
+  rewards: Array<UserRewardArgs_>;
+  /** Tracks the settled amount, pending slashes only show up after settlement */
+  stakeHistory: StakeHistoryArgs_;
+  /** Receives the rent back when the account is closed */
+  rentPayer: Address;
+  /** Deposited amount not yet withdrawn, counted against max_per_user */
+  principal: number | bigint;
+  activation: StakeActivationArgs_;
+  /** Lets anyone compound rewards in the stake mint into the stake */
+  autoCompound: boolean;
+  /**
+   * Amount held before the position first grew after the announcement at
+   * vote_snapshot_time, stake added later does not vote on it
+   */
+  voteSnapshotAmount: number | bigint;
+  voteSnapshotTime: number | bigint;
 };
 
 /** Gets the encoder for {@link UserStakeAccountArgs_} account data. */
@@ -74,6 +136,16 @@ export function getUserStakeAccountEncoder(): FixedSizeEncoder<UserStakeAccountA
       ['user', getAddressEncoder()],
       ['amount', getU64Encoder()],
       ['lastDepositTimestamp', getU64Encoder()],
+      ['slashFactor', getU128Encoder()],
+      ['receiptAmount', getU64Encoder()],
+      ['rewards', getArrayEncoder(getUserRewardEncoder(), { size: 8 })],
+      ['stakeHistory', getStakeHistoryEncoder()],
+      ['rentPayer', getAddressEncoder()],
+      ['principal', getU64Encoder()],
+      ['activation', getStakeActivationEncoder()],
+      ['autoCompound', getBooleanEncoder()],
+      ['voteSnapshotAmount', getU64Encoder()],
+      ['voteSnapshotTime', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: USER_STAKE_ACCOUNT_DISCRIMINATOR })
   );
@@ -88,6 +160,16 @@ export function getUserStakeAccountDecoder(): FixedSizeDecoder<UserStakeAccount>
     ['user', getAddressDecoder()],
     ['amount', getU64Decoder()],
     ['lastDepositTimestamp', getU64Decoder()],
+    ['slashFactor', getU128Decoder()],
+    ['receiptAmount', getU64Decoder()],
+    ['rewards', getArrayDecoder(getUserRewardDecoder(), { size: 8 })],
+    ['stakeHistory', getStakeHistoryDecoder()],
+    ['rentPayer', getAddressDecoder()],
+    ['principal', getU64Decoder()],
+    ['activation', getStakeActivationDecoder()],
+    ['autoCompound', getBooleanDecoder()],
+    ['voteSnapshotAmount', getU64Decoder()],
+    ['voteSnapshotTime', getU64Decoder()],
   ]);
 }
 
@@ -166,5 +248,5 @@ export async function fetchAllMaybeUserStakeAccount(
 }
 
 export function getUserStakeAccountSize(): number {
-  return 120;
+  return 713;
 }
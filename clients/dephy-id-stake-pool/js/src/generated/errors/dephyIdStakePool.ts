@@ -38,8 +38,84 @@ export const DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_NOT_ACTIVE = 0x1779; // 6009
 export const DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_IS_ACTIVE = 0x177a; // 6010
 /** InvalidCommisionRate: Invalid commision rate */
 export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_COMMISION_RATE = 0x177b; // 6011
+/** InvalidSlashRate: Invalid slash rate */
+export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_SLASH_RATE = 0x177c; // 6012
+/** AlreadyMigrated: Account is already migrated */
+export const DEPHY_ID_STAKE_POOL_ERROR__ALREADY_MIGRATED = 0x177d; // 6013
+/** InvalidScore: Invalid score */
+export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_SCORE = 0x177e; // 6014
+/** StaleScore: Score is stale */
+export const DEPHY_ID_STAKE_POOL_ERROR__STALE_SCORE = 0x177f; // 6015
+/** AlreadyClaimed: Already claimed */
+export const DEPHY_ID_STAKE_POOL_ERROR__ALREADY_CLAIMED = 0x1780; // 6016
+/** InvalidProof: Invalid merkle proof */
+export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_PROOF = 0x1781; // 6017
+/** ClaimPeriodEnded: Claim period has ended */
+export const DEPHY_ID_STAKE_POOL_ERROR__CLAIM_PERIOD_ENDED = 0x1782; // 6018
+/** ReceiptMintExists: Receipt mint already exists */
+export const DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_MINT_EXISTS = 0x1783; // 6019
+/** ReceiptAccountsRequired: Receipt token accounts are required */
+export const DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED = 0x1784; // 6020
+/** StakePoolSunset: Stake pool is sunset */
+export const DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_SUNSET = 0x1785; // 6021
+/** StakePoolNotSunset: Stake pool is not sunset */
+export const DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_SUNSET = 0x1786; // 6022
+/** StakePoolNotEmpty: Stake pool still has stakes or vaults */
+export const DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_EMPTY = 0x1787; // 6023
+/** NftStakeActive: Nft stake is still active */
+export const DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE = 0x1788; // 6024
+/** UnsupportedMintExtension: Unsupported mint extension */
+export const DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_MINT_EXTENSION = 0x1789; // 6025
+/** RewardStreamsFull: No reward stream slot available */
+export const DEPHY_ID_STAKE_POOL_ERROR__REWARD_STREAMS_FULL = 0x178a; // 6026
+/** MissingRewardStream: Reward stream accounts are missing */
+export const DEPHY_ID_STAKE_POOL_ERROR__MISSING_REWARD_STREAM = 0x178b; // 6027
+/** InvalidRewardSchedule: Invalid reward schedule */
+export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_REWARD_SCHEDULE = 0x178c; // 6028
+/** RewardsNotClaimed: Rewards must be claimed before closing the stake */
+export const DEPHY_ID_STAKE_POOL_ERROR__REWARDS_NOT_CLAIMED = 0x178d; // 6029
+/** InvalidStakeWindow: Invalid stake average window */
+export const DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_WINDOW = 0x178e; // 6030
+/** StakePoolNotClosed: Stake pool is not closed */
+export const DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_CLOSED = 0x178f; // 6031
+/** FeesNotClaimed: Fees must be claimed first */
+export const DEPHY_ID_STAKE_POOL_ERROR__FEES_NOT_CLAIMED = 0x1790; // 6032
+/** StakeNotDeactivated: Active stake must be deactivated before it can be withdrawn */
+export const DEPHY_ID_STAKE_POOL_ERROR__STAKE_NOT_DEACTIVATED = 0x1791; // 6033
+/** IneligibleAsset: Asset attributes do not match the pool rules */
+export const DEPHY_ID_STAKE_POOL_ERROR__INELIGIBLE_ASSET = 0x1792; // 6034
+/** AssetAlreadyLocked: Asset is already staked in this pool */
+export const DEPHY_ID_STAKE_POOL_ERROR__ASSET_ALREADY_LOCKED = 0x1793; // 6035
+/** AssetLocksFull: Asset is staked in too many pools */
+export const DEPHY_ID_STAKE_POOL_ERROR__ASSET_LOCKS_FULL = 0x1794; // 6036
+/** UnsupportedAsset: Unsupported asset standard */
+export const DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_ASSET = 0x1795; // 6037
+/** GovernanceDisabled: Config governance is disabled */
+export const DEPHY_ID_STAKE_POOL_ERROR__GOVERNANCE_DISABLED = 0x1796; // 6038
+/** VotingEnded: Voting on the announced config has ended */
+export const DEPHY_ID_STAKE_POOL_ERROR__VOTING_ENDED = 0x1797; // 6039
+/** AlreadyVoted: Already voted on the announced config */
+export const DEPHY_ID_STAKE_POOL_ERROR__ALREADY_VOTED = 0x1798; // 6040
+/** VoteIneligible: Only stake held at the announcement can vote */
+export const DEPHY_ID_STAKE_POOL_ERROR__VOTE_INELIGIBLE = 0x1799; // 6041
+/** QuorumNotReached: Votes did not reach the quorum */
+export const DEPHY_ID_STAKE_POOL_ERROR__QUORUM_NOT_REACHED = 0x179a; // 6042
+/** AutoCompoundDisabled: Auto compound is not enabled */
+export const DEPHY_ID_STAKE_POOL_ERROR__AUTO_COMPOUND_DISABLED = 0x179b; // 6043
+/** NftStakeMigrating: Nft stake is still being migrated */
+export const DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_MIGRATING = 0x179c; // 6044
 
 export type DephyIdStakePoolError =
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__ALREADY_CLAIMED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__ALREADY_MIGRATED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__ALREADY_VOTED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__ASSET_ALREADY_LOCKED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__ASSET_LOCKS_FULL
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__AUTO_COMPOUND_DISABLED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__CLAIM_PERIOD_ENDED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__FEES_NOT_CLAIMED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__GOVERNANCE_DISABLED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INELIGIBLE_ASSET
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_ACCOUNT
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY
@@ -47,17 +123,50 @@ export type DephyIdStakePoolError =
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_COMMISION_RATE
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_CONFIG
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_MPL_CORE_PROGRAM
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_PROOF
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_REWARD_SCHEDULE
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_SCORE
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_SLASH_RATE
   | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_TOKEN
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_WINDOW
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__MISSING_REWARD_STREAM
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE
   | typeof DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_IS_ACTIVE
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_MIGRATING
   | typeof DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_NOT_ACTIVE
   | typeof DEPHY_ID_STAKE_POOL_ERROR__NOT_READY_YET
-  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_NON_EMPTY;
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__QUORUM_NOT_REACHED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_MINT_EXISTS
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__REWARD_STREAMS_FULL
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__REWARDS_NOT_CLAIMED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_NON_EMPTY
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_NOT_DEACTIVATED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_CLOSED
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_EMPTY
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_SUNSET
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_SUNSET
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__STALE_SCORE
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_ASSET
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_MINT_EXTENSION
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__VOTE_INELIGIBLE
+  | typeof DEPHY_ID_STAKE_POOL_ERROR__VOTING_ENDED;
 
 let dephyIdStakePoolErrorMessages:
   | Record<DephyIdStakePoolError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   dephyIdStakePoolErrorMessages = {
+    [DEPHY_ID_STAKE_POOL_ERROR__ALREADY_CLAIMED]: `Already claimed`,
+    [DEPHY_ID_STAKE_POOL_ERROR__ALREADY_MIGRATED]: `Account is already migrated`,
+    [DEPHY_ID_STAKE_POOL_ERROR__ALREADY_VOTED]: `Already voted on the announced config`,
+    [DEPHY_ID_STAKE_POOL_ERROR__ASSET_ALREADY_LOCKED]: `Asset is already staked in this pool`,
+    [DEPHY_ID_STAKE_POOL_ERROR__ASSET_LOCKS_FULL]: `Asset is staked in too many pools`,
+    [DEPHY_ID_STAKE_POOL_ERROR__AUTO_COMPOUND_DISABLED]: `Auto compound is not enabled`,
+    [DEPHY_ID_STAKE_POOL_ERROR__CLAIM_PERIOD_ENDED]: `Claim period has ended`,
+    [DEPHY_ID_STAKE_POOL_ERROR__FEES_NOT_CLAIMED]: `Fees must be claimed first`,
+    [DEPHY_ID_STAKE_POOL_ERROR__GOVERNANCE_DISABLED]: `Config governance is disabled`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INELIGIBLE_ASSET]: `Asset attributes do not match the pool rules`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_ACCOUNT]: `Invalid account`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT]: `Invalid amount`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY]: `The provided authority is invalid`,
@@ -65,11 +174,34 @@ if (process.env.NODE_ENV !== 'production') {
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_COMMISION_RATE]: `Invalid commision rate`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_CONFIG]: `The config is invalid`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_MPL_CORE_PROGRAM]: `Invalid mpl core program`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INVALID_PROOF]: `Invalid merkle proof`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INVALID_REWARD_SCHEDULE]: `Invalid reward schedule`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INVALID_SCORE]: `Invalid score`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INVALID_SLASH_RATE]: `Invalid slash rate`,
     [DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_TOKEN]: `The stake token is invalid`,
+    [DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_WINDOW]: `Invalid stake average window`,
+    [DEPHY_ID_STAKE_POOL_ERROR__MISSING_REWARD_STREAM]: `Reward stream accounts are missing`,
+    [DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE]: `Nft stake is still active`,
     [DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_IS_ACTIVE]: `Nft stake is active`,
+    [DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_MIGRATING]: `Nft stake is still being migrated`,
     [DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_NOT_ACTIVE]: `Nft stake is not active`,
     [DEPHY_ID_STAKE_POOL_ERROR__NOT_READY_YET]: `Not ready yet`,
+    [DEPHY_ID_STAKE_POOL_ERROR__QUORUM_NOT_REACHED]: `Votes did not reach the quorum`,
+    [DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED]: `Receipt token accounts are required`,
+    [DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_MINT_EXISTS]: `Receipt mint already exists`,
+    [DEPHY_ID_STAKE_POOL_ERROR__REWARD_STREAMS_FULL]: `No reward stream slot available`,
+    [DEPHY_ID_STAKE_POOL_ERROR__REWARDS_NOT_CLAIMED]: `Rewards must be claimed before closing the stake`,
     [DEPHY_ID_STAKE_POOL_ERROR__STAKE_NON_EMPTY]: `Stake not empty`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STAKE_NOT_DEACTIVATED]: `Active stake must be deactivated before it can be withdrawn`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_CLOSED]: `Stake pool is not closed`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_EMPTY]: `Stake pool still has stakes or vaults`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_SUNSET]: `Stake pool is not sunset`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_SUNSET]: `Stake pool is sunset`,
+    [DEPHY_ID_STAKE_POOL_ERROR__STALE_SCORE]: `Score is stale`,
+    [DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_ASSET]: `Unsupported asset standard`,
+    [DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_MINT_EXTENSION]: `Unsupported mint extension`,
+    [DEPHY_ID_STAKE_POOL_ERROR__VOTE_INELIGIBLE]: `Only stake held at the announcement can vote`,
+    [DEPHY_ID_STAKE_POOL_ERROR__VOTING_ENDED]: `Voting on the announced config has ended`,
   };
 }
 
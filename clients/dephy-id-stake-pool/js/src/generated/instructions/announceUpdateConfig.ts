@@ -11,6 +11,8 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -20,9 +22,9 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -40,10 +42,10 @@ import {
   type ResolvedAccount,
 } from '../shared';
 import {
-  getStakePoolConfigArgsDecoder,
-  getStakePoolConfigArgsEncoder,
-  type StakePoolConfigArgs,
-  type StakePoolConfigArgsArgs_,
+  getConfigChangeDecoder,
+  getConfigChangeEncoder,
+  type ConfigChange,
+  type ConfigChangeArgs_,
 } from '../types';
 
 export const ANNOUNCE_UPDATE_CONFIG_DISCRIMINATOR = new Uint8Array([
@@ -92,18 +94,18 @@ export type AnnounceUpdateConfigInstruction<
 
 export type AnnounceUpdateConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
-  args: StakePoolConfigArgs;
+  changes: Array<ConfigChange>;
 };
 
 export type AnnounceUpdateConfigInstructionDataArgs_ = {
-  args: StakePoolConfigArgsArgs_;
+  changes: Array<ConfigChangeArgs_>;
 };
 
-export function getAnnounceUpdateConfigInstructionDataEncoder(): FixedSizeEncoder<AnnounceUpdateConfigInstructionDataArgs_> {
+export function getAnnounceUpdateConfigInstructionDataEncoder(): Encoder<AnnounceUpdateConfigInstructionDataArgs_> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['args', getStakePoolConfigArgsEncoder()],
+      ['changes', getArrayEncoder(getConfigChangeEncoder())],
     ]),
     (value) => ({
       ...value,
@@ -112,14 +114,14 @@ export function getAnnounceUpdateConfigInstructionDataEncoder(): FixedSizeEncode
   );
 }
 
-export function getAnnounceUpdateConfigInstructionDataDecoder(): FixedSizeDecoder<AnnounceUpdateConfigInstructionData> {
+export function getAnnounceUpdateConfigInstructionDataDecoder(): Decoder<AnnounceUpdateConfigInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['args', getStakePoolConfigArgsDecoder()],
+    ['changes', getArrayDecoder(getConfigChangeDecoder())],
   ]);
 }
 
-export function getAnnounceUpdateConfigInstructionDataCodec(): FixedSizeCodec<
+export function getAnnounceUpdateConfigInstructionDataCodec(): Codec<
   AnnounceUpdateConfigInstructionDataArgs_,
   AnnounceUpdateConfigInstructionData
 > {
@@ -141,7 +143,7 @@ export type AnnounceUpdateConfigAsyncInput<
   announcedConfig?: Address<TAccountAnnouncedConfig>;
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
-  args: AnnounceUpdateConfigInstructionDataArgs_['args'];
+  changes: AnnounceUpdateConfigInstructionDataArgs_['changes'];
 };
 
 export async function getAnnounceUpdateConfigInstructionAsync<
@@ -244,7 +246,7 @@ export type AnnounceUpdateConfigInput<
   announcedConfig: Address<TAccountAnnouncedConfig>;
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
-  args: AnnounceUpdateConfigInstructionDataArgs_['args'];
+  changes: AnnounceUpdateConfigInstructionDataArgs_['changes'];
 };
 
 export function getAnnounceUpdateConfigInstruction<
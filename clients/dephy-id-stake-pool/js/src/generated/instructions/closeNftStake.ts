@@ -31,7 +31,6 @@ import {
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -56,7 +55,7 @@ export type CloseNftStakeInstruction<
   TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountStakeAuthority extends string | AccountMeta<string> = string,
   TAccountPoolWallet extends string | AccountMeta<string> = string,
-  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountRentPayer extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -77,10 +76,9 @@ export type CloseNftStakeInstruction<
       TAccountPoolWallet extends string
         ? ReadonlyAccount<TAccountPoolWallet>
         : TAccountPoolWallet,
-      TAccountPayer extends string
-        ? WritableSignerAccount<TAccountPayer> &
-            AccountSignerMeta<TAccountPayer>
-        : TAccountPayer,
+      TAccountRentPayer extends string
+        ? WritableAccount<TAccountRentPayer>
+        : TAccountRentPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -122,14 +120,14 @@ export type CloseNftStakeAsyncInput<
   TAccountStakePool extends string = string,
   TAccountStakeAuthority extends string = string,
   TAccountPoolWallet extends string = string,
-  TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   nftStake: Address<TAccountNftStake>;
   stakePool: Address<TAccountStakePool>;
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   poolWallet?: Address<TAccountPoolWallet>;
-  payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -138,7 +136,7 @@ export async function getCloseNftStakeInstructionAsync<
   TAccountStakePool extends string,
   TAccountStakeAuthority extends string,
   TAccountPoolWallet extends string,
-  TAccountPayer extends string,
+  TAccountRentPayer extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
@@ -147,7 +145,7 @@ export async function getCloseNftStakeInstructionAsync<
     TAccountStakePool,
     TAccountStakeAuthority,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -158,7 +156,7 @@ export async function getCloseNftStakeInstructionAsync<
     TAccountStakePool,
     TAccountStakeAuthority,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
     TAccountSystemProgram
   >
 > {
@@ -172,7 +170,7 @@ export async function getCloseNftStakeInstructionAsync<
     stakePool: { value: input.stakePool ?? null, isWritable: false },
     stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
-    payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -204,7 +202,7 @@ export async function getCloseNftStakeInstructionAsync<
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.stakeAuthority),
       getAccountMeta(accounts.poolWallet),
-      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getCloseNftStakeInstructionDataEncoder().encode({}),
@@ -215,7 +213,7 @@ export async function getCloseNftStakeInstructionAsync<
     TAccountStakePool,
     TAccountStakeAuthority,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
     TAccountSystemProgram
   >);
 }
@@ -225,14 +223,14 @@ export type CloseNftStakeInput<
   TAccountStakePool extends string = string,
   TAccountStakeAuthority extends string = string,
   TAccountPoolWallet extends string = string,
-  TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   nftStake: Address<TAccountNftStake>;
   stakePool: Address<TAccountStakePool>;
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   poolWallet: Address<TAccountPoolWallet>;
-  payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -241,7 +239,7 @@ export function getCloseNftStakeInstruction<
   TAccountStakePool extends string,
   TAccountStakeAuthority extends string,
   TAccountPoolWallet extends string,
-  TAccountPayer extends string,
+  TAccountRentPayer extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
@@ -250,7 +248,7 @@ export function getCloseNftStakeInstruction<
     TAccountStakePool,
     TAccountStakeAuthority,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -260,7 +258,7 @@ export function getCloseNftStakeInstruction<
   TAccountStakePool,
   TAccountStakeAuthority,
   TAccountPoolWallet,
-  TAccountPayer,
+  TAccountRentPayer,
   TAccountSystemProgram
 > {
   // Program address.
@@ -273,7 +271,7 @@ export function getCloseNftStakeInstruction<
     stakePool: { value: input.stakePool ?? null, isWritable: false },
     stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
-    payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -294,7 +292,7 @@ export function getCloseNftStakeInstruction<
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.stakeAuthority),
       getAccountMeta(accounts.poolWallet),
-      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getCloseNftStakeInstructionDataEncoder().encode({}),
@@ -305,7 +303,7 @@ export function getCloseNftStakeInstruction<
     TAccountStakePool,
     TAccountStakeAuthority,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
     TAccountSystemProgram
   >);
 }
@@ -320,7 +318,7 @@ export type ParsedCloseNftStakeInstruction<
     stakePool: TAccountMetas[1];
     stakeAuthority: TAccountMetas[2];
     poolWallet: TAccountMetas[3];
-    payer: TAccountMetas[4];
+    rentPayer: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
   };
   data: CloseNftStakeInstructionData;
@@ -351,7 +349,7 @@ export function parseCloseNftStakeInstruction<
       stakePool: getNextAccount(),
       stakeAuthority: getNextAccount(),
       poolWallet: getNextAccount(),
-      payer: getNextAccount(),
+      rentPayer: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getCloseNftStakeInstructionDataDecoder().decode(instruction.data),
@@ -26,6 +26,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -54,7 +55,10 @@ export type ConfirmUpdateConfigInstruction<
   TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountAnnouncedConfig extends string | AccountMeta<string> = string,
+  TAccountConfigChange extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -70,10 +74,16 @@ export type ConfirmUpdateConfigInstruction<
       TAccountAnnouncedConfig extends string
         ? WritableAccount<TAccountAnnouncedConfig>
         : TAccountAnnouncedConfig,
+      TAccountConfigChange extends string
+        ? WritableAccount<TAccountConfigChange>
+        : TAccountConfigChange,
       TAccountPayer extends string
         ? WritableSignerAccount<TAccountPayer> &
             AccountSignerMeta<TAccountPayer>
         : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -114,26 +124,34 @@ export type ConfirmUpdateConfigAsyncInput<
   TAccountStakePool extends string = string,
   TAccountAuthority extends string = string,
   TAccountAnnouncedConfig extends string = string,
+  TAccountConfigChange extends string = string,
   TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   stakePool: Address<TAccountStakePool>;
   authority: TransactionSigner<TAccountAuthority>;
   announcedConfig?: Address<TAccountAnnouncedConfig>;
+  configChange: Address<TAccountConfigChange>;
   payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export async function getConfirmUpdateConfigInstructionAsync<
   TAccountStakePool extends string,
   TAccountAuthority extends string,
   TAccountAnnouncedConfig extends string,
+  TAccountConfigChange extends string,
   TAccountPayer extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: ConfirmUpdateConfigAsyncInput<
     TAccountStakePool,
     TAccountAuthority,
     TAccountAnnouncedConfig,
-    TAccountPayer
+    TAccountConfigChange,
+    TAccountPayer,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -142,7 +160,9 @@ export async function getConfirmUpdateConfigInstructionAsync<
     TAccountStakePool,
     TAccountAuthority,
     TAccountAnnouncedConfig,
-    TAccountPayer
+    TAccountConfigChange,
+    TAccountPayer,
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -154,7 +174,9 @@ export async function getConfirmUpdateConfigInstructionAsync<
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     announcedConfig: { value: input.announcedConfig ?? null, isWritable: true },
+    configChange: { value: input.configChange ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -175,6 +197,10 @@ export async function getConfirmUpdateConfigInstructionAsync<
       ],
     });
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -182,7 +208,9 @@ export async function getConfirmUpdateConfigInstructionAsync<
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.announcedConfig),
+      getAccountMeta(accounts.configChange),
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getConfirmUpdateConfigInstructionDataEncoder().encode({}),
     programAddress,
@@ -191,7 +219,9 @@ export async function getConfirmUpdateConfigInstructionAsync<
     TAccountStakePool,
     TAccountAuthority,
     TAccountAnnouncedConfig,
-    TAccountPayer
+    TAccountConfigChange,
+    TAccountPayer,
+    TAccountSystemProgram
   >);
 }
 
@@ -199,26 +229,34 @@ export type ConfirmUpdateConfigInput<
   TAccountStakePool extends string = string,
   TAccountAuthority extends string = string,
   TAccountAnnouncedConfig extends string = string,
+  TAccountConfigChange extends string = string,
   TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   stakePool: Address<TAccountStakePool>;
   authority: TransactionSigner<TAccountAuthority>;
   announcedConfig: Address<TAccountAnnouncedConfig>;
+  configChange: Address<TAccountConfigChange>;
   payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getConfirmUpdateConfigInstruction<
   TAccountStakePool extends string,
   TAccountAuthority extends string,
   TAccountAnnouncedConfig extends string,
+  TAccountConfigChange extends string,
   TAccountPayer extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: ConfirmUpdateConfigInput<
     TAccountStakePool,
     TAccountAuthority,
     TAccountAnnouncedConfig,
-    TAccountPayer
+    TAccountConfigChange,
+    TAccountPayer,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ConfirmUpdateConfigInstruction<
@@ -226,7 +264,9 @@ export function getConfirmUpdateConfigInstruction<
   TAccountStakePool,
   TAccountAuthority,
   TAccountAnnouncedConfig,
-  TAccountPayer
+  TAccountConfigChange,
+  TAccountPayer,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -237,20 +277,30 @@ export function getConfirmUpdateConfigInstruction<
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     announcedConfig: { value: input.announcedConfig ?? null, isWritable: true },
+    configChange: { value: input.configChange ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.announcedConfig),
+      getAccountMeta(accounts.configChange),
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getConfirmUpdateConfigInstructionDataEncoder().encode({}),
     programAddress,
@@ -259,7 +309,9 @@ export function getConfirmUpdateConfigInstruction<
     TAccountStakePool,
     TAccountAuthority,
     TAccountAnnouncedConfig,
-    TAccountPayer
+    TAccountConfigChange,
+    TAccountPayer,
+    TAccountSystemProgram
   >);
 }
 
@@ -272,7 +324,9 @@ export type ParsedConfirmUpdateConfigInstruction<
     stakePool: TAccountMetas[0];
     authority: TAccountMetas[1];
     announcedConfig: TAccountMetas[2];
-    payer: TAccountMetas[3];
+    configChange: TAccountMetas[3];
+    payer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: ConfirmUpdateConfigInstructionData;
 };
@@ -285,7 +339,7 @@ export function parseConfirmUpdateConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedConfirmUpdateConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -301,7 +355,9 @@ export function parseConfirmUpdateConfigInstruction<
       stakePool: getNextAccount(),
       authority: getNextAccount(),
       announcedConfig: getNextAccount(),
+      configChange: getNextAccount(),
       payer: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getConfirmUpdateConfigInstructionDataDecoder().decode(
       instruction.data
@@ -60,7 +60,7 @@ export type CreateNftStakeInstruction<
   TAccountDepositAuthority extends string | AccountMeta<string> = string,
   TAccountMplCoreAsset extends string | AccountMeta<string> = string,
   TAccountMplCoreCollection extends string | AccountMeta<string> = string,
-  TAccountPoolWallet extends string | AccountMeta<string> = string,
+  TAccountAssetLock extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
@@ -91,9 +91,9 @@ export type CreateNftStakeInstruction<
       TAccountMplCoreCollection extends string
         ? WritableAccount<TAccountMplCoreCollection>
         : TAccountMplCoreCollection,
-      TAccountPoolWallet extends string
-        ? ReadonlyAccount<TAccountPoolWallet>
-        : TAccountPoolWallet,
+      TAccountAssetLock extends string
+        ? WritableAccount<TAccountAssetLock>
+        : TAccountAssetLock,
       TAccountPayer extends string
         ? WritableSignerAccount<TAccountPayer> &
             AccountSignerMeta<TAccountPayer>
@@ -149,7 +149,7 @@ export type CreateNftStakeAsyncInput<
   TAccountDepositAuthority extends string = string,
   TAccountMplCoreAsset extends string = string,
   TAccountMplCoreCollection extends string = string,
-  TAccountPoolWallet extends string = string,
+  TAccountAssetLock extends string = string,
   TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -160,7 +160,7 @@ export type CreateNftStakeAsyncInput<
   depositAuthority: Address<TAccountDepositAuthority>;
   mplCoreAsset: Address<TAccountMplCoreAsset>;
   mplCoreCollection: Address<TAccountMplCoreCollection>;
-  poolWallet?: Address<TAccountPoolWallet>;
+  assetLock?: Address<TAccountAssetLock>;
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
@@ -174,7 +174,7 @@ export async function getCreateNftStakeInstructionAsync<
   TAccountDepositAuthority extends string,
   TAccountMplCoreAsset extends string,
   TAccountMplCoreCollection extends string,
-  TAccountPoolWallet extends string,
+  TAccountAssetLock extends string,
   TAccountPayer extends string,
   TAccountSystemProgram extends string,
   TAccountMplCoreProgram extends string,
@@ -187,7 +187,7 @@ export async function getCreateNftStakeInstructionAsync<
     TAccountDepositAuthority,
     TAccountMplCoreAsset,
     TAccountMplCoreCollection,
-    TAccountPoolWallet,
+    TAccountAssetLock,
     TAccountPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
@@ -202,7 +202,7 @@ export async function getCreateNftStakeInstructionAsync<
     TAccountDepositAuthority,
     TAccountMplCoreAsset,
     TAccountMplCoreCollection,
-    TAccountPoolWallet,
+    TAccountAssetLock,
     TAccountPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
@@ -226,7 +226,7 @@ export async function getCreateNftStakeInstructionAsync<
       value: input.mplCoreCollection ?? null,
       isWritable: true,
     },
-    poolWallet: { value: input.poolWallet ?? null, isWritable: false },
+    assetLock: { value: input.assetLock ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -240,14 +240,14 @@ export async function getCreateNftStakeInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.poolWallet.value) {
-    accounts.poolWallet.value = await getProgramDerivedAddress({
+  if (!accounts.assetLock.value) {
+    accounts.assetLock.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
         getBytesEncoder().encode(
-          new Uint8Array([80, 79, 79, 76, 95, 87, 65, 76, 76, 69, 84])
+          new Uint8Array([65, 83, 83, 69, 84, 95, 76, 79, 67, 75])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.mplCoreAsset.value)),
       ],
     });
   }
@@ -269,7 +269,7 @@ export async function getCreateNftStakeInstructionAsync<
       getAccountMeta(accounts.depositAuthority),
       getAccountMeta(accounts.mplCoreAsset),
       getAccountMeta(accounts.mplCoreCollection),
-      getAccountMeta(accounts.poolWallet),
+      getAccountMeta(accounts.assetLock),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.mplCoreProgram),
@@ -286,7 +286,7 @@ export async function getCreateNftStakeInstructionAsync<
     TAccountDepositAuthority,
     TAccountMplCoreAsset,
     TAccountMplCoreCollection,
-    TAccountPoolWallet,
+    TAccountAssetLock,
     TAccountPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
@@ -300,7 +300,7 @@ export type CreateNftStakeInput<
   TAccountDepositAuthority extends string = string,
   TAccountMplCoreAsset extends string = string,
   TAccountMplCoreCollection extends string = string,
-  TAccountPoolWallet extends string = string,
+  TAccountAssetLock extends string = string,
   TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -311,7 +311,7 @@ export type CreateNftStakeInput<
   depositAuthority: Address<TAccountDepositAuthority>;
   mplCoreAsset: Address<TAccountMplCoreAsset>;
   mplCoreCollection: Address<TAccountMplCoreCollection>;
-  poolWallet: Address<TAccountPoolWallet>;
+  assetLock: Address<TAccountAssetLock>;
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
@@ -325,7 +325,7 @@ export function getCreateNftStakeInstruction<
   TAccountDepositAuthority extends string,
   TAccountMplCoreAsset extends string,
   TAccountMplCoreCollection extends string,
-  TAccountPoolWallet extends string,
+  TAccountAssetLock extends string,
   TAccountPayer extends string,
   TAccountSystemProgram extends string,
   TAccountMplCoreProgram extends string,
@@ -338,7 +338,7 @@ export function getCreateNftStakeInstruction<
     TAccountDepositAuthority,
     TAccountMplCoreAsset,
     TAccountMplCoreCollection,
-    TAccountPoolWallet,
+    TAccountAssetLock,
     TAccountPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
@@ -352,7 +352,7 @@ export function getCreateNftStakeInstruction<
   TAccountDepositAuthority,
   TAccountMplCoreAsset,
   TAccountMplCoreCollection,
-  TAccountPoolWallet,
+  TAccountAssetLock,
   TAccountPayer,
   TAccountSystemProgram,
   TAccountMplCoreProgram
@@ -375,7 +375,7 @@ export function getCreateNftStakeInstruction<
       value: input.mplCoreCollection ?? null,
       isWritable: true,
     },
-    poolWallet: { value: input.poolWallet ?? null, isWritable: false },
+    assetLock: { value: input.assetLock ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -407,7 +407,7 @@ export function getCreateNftStakeInstruction<
       getAccountMeta(accounts.depositAuthority),
       getAccountMeta(accounts.mplCoreAsset),
       getAccountMeta(accounts.mplCoreCollection),
-      getAccountMeta(accounts.poolWallet),
+      getAccountMeta(accounts.assetLock),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.mplCoreProgram),
@@ -424,7 +424,7 @@ export function getCreateNftStakeInstruction<
     TAccountDepositAuthority,
     TAccountMplCoreAsset,
     TAccountMplCoreCollection,
-    TAccountPoolWallet,
+    TAccountAssetLock,
     TAccountPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
@@ -443,7 +443,7 @@ export type ParsedCreateNftStakeInstruction<
     depositAuthority: TAccountMetas[3];
     mplCoreAsset: TAccountMetas[4];
     mplCoreCollection: TAccountMetas[5];
-    poolWallet: TAccountMetas[6];
+    assetLock: TAccountMetas[6];
     payer: TAccountMetas[7];
     systemProgram: TAccountMetas[8];
     mplCoreProgram: TAccountMetas[9];
@@ -478,7 +478,7 @@ export function parseCreateNftStakeInstruction<
       depositAuthority: getNextAccount(),
       mplCoreAsset: getNextAccount(),
       mplCoreCollection: getNextAccount(),
-      poolWallet: getNextAccount(),
+      assetLock: getNextAccount(),
       payer: getNextAccount(),
       systemProgram: getNextAccount(),
       mplCoreProgram: getNextAccount(),
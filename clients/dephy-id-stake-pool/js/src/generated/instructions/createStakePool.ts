@@ -10,22 +10,31 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -39,12 +48,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
-import {
-  getStakePoolConfigArgsDecoder,
-  getStakePoolConfigArgsEncoder,
-  type StakePoolConfigArgs,
-  type StakePoolConfigArgsArgs_,
-} from '../types';
 
 export const CREATE_STAKE_POOL_DISCRIMINATOR = new Uint8Array([
   198, 175, 88, 63, 128, 43, 8, 214,
@@ -117,31 +120,87 @@ export type CreateStakePoolInstruction<
 
 export type CreateStakePoolInstructionData = {
   discriminator: ReadonlyUint8Array;
-  args: StakePoolConfigArgs;
+  configReviewTime: bigint;
+  maxStakeAmount: bigint;
+  slasher: Option<Address>;
+  scoreOracle: Option<Address>;
+  maxScoreAge: bigint;
+  maxTotalAmount: Option<bigint>;
+  maxPerUser: Option<bigint>;
+  minDepositAmount: bigint;
+  depositFeeRate: number;
+  withdrawFeeRate: number;
+  rewardFeeRate: number;
+  earlyExitPenaltyRate: number;
+  earlyExitPeriod: bigint;
+  epochDuration: bigint;
+  governanceQuorumRate: number;
 };
 
 export type CreateStakePoolInstructionDataArgs_ = {
-  args: StakePoolConfigArgsArgs_;
+  configReviewTime: number | bigint;
+  maxStakeAmount: number | bigint;
+  slasher: OptionOrNullable<Address>;
+  scoreOracle: OptionOrNullable<Address>;
+  maxScoreAge: number | bigint;
+  maxTotalAmount: OptionOrNullable<number | bigint>;
+  maxPerUser: OptionOrNullable<number | bigint>;
+  minDepositAmount: number | bigint;
+  depositFeeRate: number;
+  withdrawFeeRate: number;
+  rewardFeeRate: number;
+  earlyExitPenaltyRate: number;
+  earlyExitPeriod: number | bigint;
+  epochDuration: number | bigint;
+  governanceQuorumRate: number;
 };
 
-export function getCreateStakePoolInstructionDataEncoder(): FixedSizeEncoder<CreateStakePoolInstructionDataArgs_> {
+export function getCreateStakePoolInstructionDataEncoder(): Encoder<CreateStakePoolInstructionDataArgs_> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['args', getStakePoolConfigArgsEncoder()],
+      ['configReviewTime', getU64Encoder()],
+      ['maxStakeAmount', getU64Encoder()],
+      ['slasher', getOptionEncoder(getAddressEncoder())],
+      ['scoreOracle', getOptionEncoder(getAddressEncoder())],
+      ['maxScoreAge', getU64Encoder()],
+      ['maxTotalAmount', getOptionEncoder(getU64Encoder())],
+      ['maxPerUser', getOptionEncoder(getU64Encoder())],
+      ['minDepositAmount', getU64Encoder()],
+      ['depositFeeRate', getU16Encoder()],
+      ['withdrawFeeRate', getU16Encoder()],
+      ['rewardFeeRate', getU16Encoder()],
+      ['earlyExitPenaltyRate', getU16Encoder()],
+      ['earlyExitPeriod', getU64Encoder()],
+      ['epochDuration', getU64Encoder()],
+      ['governanceQuorumRate', getU16Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_STAKE_POOL_DISCRIMINATOR })
   );
 }
 
-export function getCreateStakePoolInstructionDataDecoder(): FixedSizeDecoder<CreateStakePoolInstructionData> {
+export function getCreateStakePoolInstructionDataDecoder(): Decoder<CreateStakePoolInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['args', getStakePoolConfigArgsDecoder()],
+    ['configReviewTime', getU64Decoder()],
+    ['maxStakeAmount', getU64Decoder()],
+    ['slasher', getOptionDecoder(getAddressDecoder())],
+    ['scoreOracle', getOptionDecoder(getAddressDecoder())],
+    ['maxScoreAge', getU64Decoder()],
+    ['maxTotalAmount', getOptionDecoder(getU64Decoder())],
+    ['maxPerUser', getOptionDecoder(getU64Decoder())],
+    ['minDepositAmount', getU64Decoder()],
+    ['depositFeeRate', getU16Decoder()],
+    ['withdrawFeeRate', getU16Decoder()],
+    ['rewardFeeRate', getU16Decoder()],
+    ['earlyExitPenaltyRate', getU16Decoder()],
+    ['earlyExitPeriod', getU64Decoder()],
+    ['epochDuration', getU64Decoder()],
+    ['governanceQuorumRate', getU16Decoder()],
   ]);
 }
 
-export function getCreateStakePoolInstructionDataCodec(): FixedSizeCodec<
+export function getCreateStakePoolInstructionDataCodec(): Codec<
   CreateStakePoolInstructionDataArgs_,
   CreateStakePoolInstructionData
 > {
@@ -175,7 +234,21 @@ export type CreateStakePoolAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   stakeTokenProgram: Address<TAccountStakeTokenProgram>;
-  args: CreateStakePoolInstructionDataArgs_['args'];
+  configReviewTime: CreateStakePoolInstructionDataArgs_['configReviewTime'];
+  maxStakeAmount: CreateStakePoolInstructionDataArgs_['maxStakeAmount'];
+  slasher: CreateStakePoolInstructionDataArgs_['slasher'];
+  scoreOracle: CreateStakePoolInstructionDataArgs_['scoreOracle'];
+  maxScoreAge: CreateStakePoolInstructionDataArgs_['maxScoreAge'];
+  maxTotalAmount: CreateStakePoolInstructionDataArgs_['maxTotalAmount'];
+  maxPerUser: CreateStakePoolInstructionDataArgs_['maxPerUser'];
+  minDepositAmount: CreateStakePoolInstructionDataArgs_['minDepositAmount'];
+  depositFeeRate: CreateStakePoolInstructionDataArgs_['depositFeeRate'];
+  withdrawFeeRate: CreateStakePoolInstructionDataArgs_['withdrawFeeRate'];
+  rewardFeeRate: CreateStakePoolInstructionDataArgs_['rewardFeeRate'];
+  earlyExitPenaltyRate: CreateStakePoolInstructionDataArgs_['earlyExitPenaltyRate'];
+  earlyExitPeriod: CreateStakePoolInstructionDataArgs_['earlyExitPeriod'];
+  epochDuration: CreateStakePoolInstructionDataArgs_['epochDuration'];
+  governanceQuorumRate: CreateStakePoolInstructionDataArgs_['governanceQuorumRate'];
 };
 
 export async function getCreateStakePoolInstructionAsync<
@@ -350,7 +423,21 @@ export type CreateStakePoolInput<
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   stakeTokenProgram: Address<TAccountStakeTokenProgram>;
-  args: CreateStakePoolInstructionDataArgs_['args'];
+  configReviewTime: CreateStakePoolInstructionDataArgs_['configReviewTime'];
+  maxStakeAmount: CreateStakePoolInstructionDataArgs_['maxStakeAmount'];
+  slasher: CreateStakePoolInstructionDataArgs_['slasher'];
+  scoreOracle: CreateStakePoolInstructionDataArgs_['scoreOracle'];
+  maxScoreAge: CreateStakePoolInstructionDataArgs_['maxScoreAge'];
+  maxTotalAmount: CreateStakePoolInstructionDataArgs_['maxTotalAmount'];
+  maxPerUser: CreateStakePoolInstructionDataArgs_['maxPerUser'];
+  minDepositAmount: CreateStakePoolInstructionDataArgs_['minDepositAmount'];
+  depositFeeRate: CreateStakePoolInstructionDataArgs_['depositFeeRate'];
+  withdrawFeeRate: CreateStakePoolInstructionDataArgs_['withdrawFeeRate'];
+  rewardFeeRate: CreateStakePoolInstructionDataArgs_['rewardFeeRate'];
+  earlyExitPenaltyRate: CreateStakePoolInstructionDataArgs_['earlyExitPenaltyRate'];
+  earlyExitPeriod: CreateStakePoolInstructionDataArgs_['earlyExitPeriod'];
+  epochDuration: CreateStakePoolInstructionDataArgs_['epochDuration'];
+  governanceQuorumRate: CreateStakePoolInstructionDataArgs_['governanceQuorumRate'];
 };
 
 export function getCreateStakePoolInstruction<
@@ -58,10 +58,12 @@ export function getDepositTokenDiscriminatorBytes() {
 
 export type DepositTokenInstruction<
   TProgram extends string = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
+  TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountNftStake extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountUser extends string | AccountMeta<string> = string,
   TAccountUserStakeAccount extends string | AccountMeta<string> = string,
+  TAccountUserPoolStakeAccount extends string | AccountMeta<string> = string,
   TAccountStakeTokenMint extends string | AccountMeta<string> = string,
   TAccountStakeTokenAccount extends string | AccountMeta<string> = string,
   TAccountUserStakeTokenAccount extends string | AccountMeta<string> = string,
@@ -71,11 +73,18 @@ export type DepositTokenInstruction<
     'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountReceiptMint extends string | AccountMeta<string> = string,
+  TAccountUserReceiptTokenAccount extends string | AccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | AccountMeta<string> =
+    'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
+      TAccountAdmin extends string
+        ? ReadonlyAccount<TAccountAdmin>
+        : TAccountAdmin,
       TAccountNftStake extends string
         ? WritableAccount<TAccountNftStake>
         : TAccountNftStake,
@@ -88,6 +97,9 @@ export type DepositTokenInstruction<
       TAccountUserStakeAccount extends string
         ? WritableAccount<TAccountUserStakeAccount>
         : TAccountUserStakeAccount,
+      TAccountUserPoolStakeAccount extends string
+        ? WritableAccount<TAccountUserPoolStakeAccount>
+        : TAccountUserPoolStakeAccount,
       TAccountStakeTokenMint extends string
         ? ReadonlyAccount<TAccountStakeTokenMint>
         : TAccountStakeTokenMint,
@@ -110,6 +122,15 @@ export type DepositTokenInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountUserReceiptTokenAccount extends string
+        ? WritableAccount<TAccountUserReceiptTokenAccount>
+        : TAccountUserReceiptTokenAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -151,10 +172,12 @@ export function getDepositTokenInstructionDataCodec(): Codec<
 }
 
 export type DepositTokenAsyncInput<
+  TAccountAdmin extends string = string,
   TAccountNftStake extends string = string,
   TAccountStakePool extends string = string,
   TAccountUser extends string = string,
   TAccountUserStakeAccount extends string = string,
+  TAccountUserPoolStakeAccount extends string = string,
   TAccountStakeTokenMint extends string = string,
   TAccountStakeTokenAccount extends string = string,
   TAccountUserStakeTokenAccount extends string = string,
@@ -162,11 +185,16 @@ export type DepositTokenAsyncInput<
   TAccountPayer extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountUserReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
 > = {
+  admin?: Address<TAccountAdmin>;
   nftStake: Address<TAccountNftStake>;
   stakePool: Address<TAccountStakePool>;
   user: TransactionSigner<TAccountUser>;
   userStakeAccount?: Address<TAccountUserStakeAccount>;
+  userPoolStakeAccount?: Address<TAccountUserPoolStakeAccount>;
   stakeTokenMint: Address<TAccountStakeTokenMint>;
   stakeTokenAccount: Address<TAccountStakeTokenAccount>;
   userStakeTokenAccount: Address<TAccountUserStakeTokenAccount>;
@@ -174,14 +202,20 @@ export type DepositTokenAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the pool has a receipt mint */
+  receiptMint?: Address<TAccountReceiptMint>;
+  userReceiptTokenAccount?: Address<TAccountUserReceiptTokenAccount>;
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   amount: DepositTokenInstructionDataArgs_['amount'];
 };
 
 export async function getDepositTokenInstructionAsync<
+  TAccountAdmin extends string,
   TAccountNftStake extends string,
   TAccountStakePool extends string,
   TAccountUser extends string,
   TAccountUserStakeAccount extends string,
+  TAccountUserPoolStakeAccount extends string,
   TAccountStakeTokenMint extends string,
   TAccountStakeTokenAccount extends string,
   TAccountUserStakeTokenAccount extends string,
@@ -189,36 +223,49 @@ export async function getDepositTokenInstructionAsync<
   TAccountPayer extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountUserReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: DepositTokenAsyncInput<
+    TAccountAdmin,
     TAccountNftStake,
     TAccountStakePool,
     TAccountUser,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
     TAccountPayer,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReceiptMint,
+    TAccountUserReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   DepositTokenInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountNftStake,
     TAccountStakePool,
     TAccountUser,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
     TAccountPayer,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReceiptMint,
+    TAccountUserReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >
 > {
   // Program address.
@@ -227,6 +274,7 @@ export async function getDepositTokenInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: false },
     nftStake: { value: input.nftStake ?? null, isWritable: true },
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
@@ -234,6 +282,10 @@ export async function getDepositTokenInstructionAsync<
       value: input.userStakeAccount ?? null,
       isWritable: true,
     },
+    userPoolStakeAccount: {
+      value: input.userPoolStakeAccount ?? null,
+      isWritable: true,
+    },
     stakeTokenMint: { value: input.stakeTokenMint ?? null, isWritable: false },
     stakeTokenAccount: {
       value: input.stakeTokenAccount ?? null,
@@ -247,6 +299,15 @@ export async function getDepositTokenInstructionAsync<
     payer: { value: input.payer ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    userReceiptTokenAccount: {
+      value: input.userReceiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -257,6 +318,12 @@ export async function getDepositTokenInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.admin.value) {
+    accounts.admin.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([65, 68, 77, 73, 78]))],
+    });
+  }
   if (!accounts.userStakeAccount.value) {
     accounts.userStakeAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -269,6 +336,20 @@ export async function getDepositTokenInstructionAsync<
       ],
     });
   }
+  if (!accounts.userPoolStakeAccount.value) {
+    accounts.userPoolStakeAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            85, 83, 69, 82, 95, 80, 79, 79, 76, 95, 83, 84, 65, 75, 69,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.user.value)),
+      ],
+    });
+  }
   if (!accounts.poolWallet.value) {
     accounts.poolWallet.value = await getProgramDerivedAddress({
       programAddress,
@@ -288,14 +369,20 @@ export async function getDepositTokenInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.receiptTokenProgram.value) {
+    accounts.receiptTokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
+      getAccountMeta(accounts.admin),
       getAccountMeta(accounts.nftStake),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.userStakeAccount),
+      getAccountMeta(accounts.userPoolStakeAccount),
       getAccountMeta(accounts.stakeTokenMint),
       getAccountMeta(accounts.stakeTokenAccount),
       getAccountMeta(accounts.userStakeTokenAccount),
@@ -303,6 +390,9 @@ export async function getDepositTokenInstructionAsync<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.userReceiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
     ],
     data: getDepositTokenInstructionDataEncoder().encode(
       args as DepositTokenInstructionDataArgs_
@@ -310,25 +400,32 @@ export async function getDepositTokenInstructionAsync<
     programAddress,
   } as DepositTokenInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountNftStake,
     TAccountStakePool,
     TAccountUser,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
     TAccountPayer,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReceiptMint,
+    TAccountUserReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >);
 }
 
 export type DepositTokenInput<
+  TAccountAdmin extends string = string,
   TAccountNftStake extends string = string,
   TAccountStakePool extends string = string,
   TAccountUser extends string = string,
   TAccountUserStakeAccount extends string = string,
+  TAccountUserPoolStakeAccount extends string = string,
   TAccountStakeTokenMint extends string = string,
   TAccountStakeTokenAccount extends string = string,
   TAccountUserStakeTokenAccount extends string = string,
@@ -336,11 +433,16 @@ export type DepositTokenInput<
   TAccountPayer extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountUserReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
 > = {
+  admin: Address<TAccountAdmin>;
   nftStake: Address<TAccountNftStake>;
   stakePool: Address<TAccountStakePool>;
   user: TransactionSigner<TAccountUser>;
   userStakeAccount: Address<TAccountUserStakeAccount>;
+  userPoolStakeAccount: Address<TAccountUserPoolStakeAccount>;
   stakeTokenMint: Address<TAccountStakeTokenMint>;
   stakeTokenAccount: Address<TAccountStakeTokenAccount>;
   userStakeTokenAccount: Address<TAccountUserStakeTokenAccount>;
@@ -348,14 +450,20 @@ export type DepositTokenInput<
   payer: TransactionSigner<TAccountPayer>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the pool has a receipt mint */
+  receiptMint?: Address<TAccountReceiptMint>;
+  userReceiptTokenAccount?: Address<TAccountUserReceiptTokenAccount>;
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   amount: DepositTokenInstructionDataArgs_['amount'];
 };
 
 export function getDepositTokenInstruction<
+  TAccountAdmin extends string,
   TAccountNftStake extends string,
   TAccountStakePool extends string,
   TAccountUser extends string,
   TAccountUserStakeAccount extends string,
+  TAccountUserPoolStakeAccount extends string,
   TAccountStakeTokenMint extends string,
   TAccountStakeTokenAccount extends string,
   TAccountUserStakeTokenAccount extends string,
@@ -363,35 +471,48 @@ export function getDepositTokenInstruction<
   TAccountPayer extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountUserReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: DepositTokenInput<
+    TAccountAdmin,
     TAccountNftStake,
     TAccountStakePool,
     TAccountUser,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
     TAccountPayer,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReceiptMint,
+    TAccountUserReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): DepositTokenInstruction<
   TProgramAddress,
+  TAccountAdmin,
   TAccountNftStake,
   TAccountStakePool,
   TAccountUser,
   TAccountUserStakeAccount,
+  TAccountUserPoolStakeAccount,
   TAccountStakeTokenMint,
   TAccountStakeTokenAccount,
   TAccountUserStakeTokenAccount,
   TAccountPoolWallet,
   TAccountPayer,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountReceiptMint,
+  TAccountUserReceiptTokenAccount,
+  TAccountReceiptTokenProgram
 > {
   // Program address.
   const programAddress =
@@ -399,6 +520,7 @@ export function getDepositTokenInstruction<
 
   // Original accounts.
   const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: false },
     nftStake: { value: input.nftStake ?? null, isWritable: true },
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
@@ -406,6 +528,10 @@ export function getDepositTokenInstruction<
       value: input.userStakeAccount ?? null,
       isWritable: true,
     },
+    userPoolStakeAccount: {
+      value: input.userPoolStakeAccount ?? null,
+      isWritable: true,
+    },
     stakeTokenMint: { value: input.stakeTokenMint ?? null, isWritable: false },
     stakeTokenAccount: {
       value: input.stakeTokenAccount ?? null,
@@ -419,6 +545,15 @@ export function getDepositTokenInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    userReceiptTokenAccount: {
+      value: input.userReceiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -437,14 +572,20 @@ export function getDepositTokenInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.receiptTokenProgram.value) {
+    accounts.receiptTokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
+      getAccountMeta(accounts.admin),
       getAccountMeta(accounts.nftStake),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.userStakeAccount),
+      getAccountMeta(accounts.userPoolStakeAccount),
       getAccountMeta(accounts.stakeTokenMint),
       getAccountMeta(accounts.stakeTokenAccount),
       getAccountMeta(accounts.userStakeTokenAccount),
@@ -452,6 +593,9 @@ export function getDepositTokenInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.userReceiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
     ],
     data: getDepositTokenInstructionDataEncoder().encode(
       args as DepositTokenInstructionDataArgs_
@@ -459,17 +603,22 @@ export function getDepositTokenInstruction<
     programAddress,
   } as DepositTokenInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountNftStake,
     TAccountStakePool,
     TAccountUser,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
     TAccountPayer,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountReceiptMint,
+    TAccountUserReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >);
 }
 
@@ -479,17 +628,23 @@ export type ParsedDepositTokenInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    nftStake: TAccountMetas[0];
-    stakePool: TAccountMetas[1];
-    user: TAccountMetas[2];
-    userStakeAccount: TAccountMetas[3];
-    stakeTokenMint: TAccountMetas[4];
-    stakeTokenAccount: TAccountMetas[5];
-    userStakeTokenAccount: TAccountMetas[6];
-    poolWallet: TAccountMetas[7];
-    payer: TAccountMetas[8];
-    tokenProgram: TAccountMetas[9];
-    systemProgram: TAccountMetas[10];
+    admin: TAccountMetas[0];
+    nftStake: TAccountMetas[1];
+    stakePool: TAccountMetas[2];
+    user: TAccountMetas[3];
+    userStakeAccount: TAccountMetas[4];
+    userPoolStakeAccount: TAccountMetas[5];
+    stakeTokenMint: TAccountMetas[6];
+    stakeTokenAccount: TAccountMetas[7];
+    userStakeTokenAccount: TAccountMetas[8];
+    poolWallet: TAccountMetas[9];
+    payer: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[12];
+    /** Required when the pool has a receipt mint */
+    receiptMint?: TAccountMetas[13] | undefined;
+    userReceiptTokenAccount?: TAccountMetas[14] | undefined;
+    receiptTokenProgram?: TAccountMetas[15] | undefined;
   };
   data: DepositTokenInstructionData;
 };
@@ -502,7 +657,7 @@ export function parseDepositTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedDepositTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 16) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -512,13 +667,21 @@ export function parseDepositTokenInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
+      admin: getNextAccount(),
       nftStake: getNextAccount(),
       stakePool: getNextAccount(),
       user: getNextAccount(),
       userStakeAccount: getNextAccount(),
+      userPoolStakeAccount: getNextAccount(),
       stakeTokenMint: getNextAccount(),
       stakeTokenAccount: getNextAccount(),
       userStakeTokenAccount: getNextAccount(),
@@ -526,6 +689,9 @@ export function parseDepositTokenInstruction<
       payer: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      receiptMint: getNextOptionalAccount(),
+      userReceiptTokenAccount: getNextOptionalAccount(),
+      receiptTokenProgram: getNextOptionalAccount(),
     },
     data: getDepositTokenInstructionDataDecoder().decode(instruction.data),
   };
@@ -8,11 +8,49 @@
 
 export * from './announceUpdateConfig';
 export * from './cancelUpdateConfig';
+export * from './claimDistribution';
+export * from './claimPoolFees';
+export * from './claimProtocolFees';
+export * from './claimRewards';
+export * from './closeConfigChange';
+export * from './closeDeviceScore';
+export * from './closeDistributor';
 export * from './closeNftStake';
+export * from './closeRewardStream';
+export * from './closeStakePool';
+export * from './closeVoteRecord';
+export * from './compoundRewards';
 export * from './confirmUpdateConfig';
+export * from './createDistributor';
 export * from './createNftStake';
+export * from './createReceiptMint';
+export * from './createRewardStream';
 export * from './createStakePool';
+export * from './createTokenNftStake';
+export * from './deactivateStake';
+export * from './depositFor';
 export * from './depositToken';
+export * from './finalizeNftStakeMigration';
+export * from './forceUnstakeNft';
+export * from './forceUnstakeTokenNft';
+export * from './getDeviceScore';
+export * from './getStakeAverage';
 export * from './initialize';
+export * from './migrateAdmin';
+export * from './migrateNftStake';
+export * from './migrateStakePool';
+export * from './migrateUserStake';
+export * from './postDeviceScore';
+export * from './redelegate';
+export * from './refundOrphanedStake';
+export * from './setAutoCompound';
+export * from './setProtocolFee';
+export * from './slashNftStake';
+export * from './sunsetStakePool';
+export * from './transferNftStake';
 export * from './unstakeNft';
+export * from './unstakeTokenNft';
+export * from './vetoUpdateConfig';
+export * from './voteConfig';
 export * from './withdraw';
+export * from './withdrawTreasury';
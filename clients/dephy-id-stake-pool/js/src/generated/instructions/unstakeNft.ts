@@ -55,8 +55,11 @@ export type UnstakeNftInstruction<
   TAccountStakeAuthority extends string | AccountMeta<string> = string,
   TAccountMplCoreCollection extends string | AccountMeta<string> = string,
   TAccountMplCoreAsset extends string | AccountMeta<string> = string,
+  TAccountAssetLock extends string | AccountMeta<string> = string,
   TAccountPoolWallet extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountRentPayer extends string | AccountMeta<string> = string,
+  TAccountAssetLockRentPayer extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
   TAccountMplCoreProgram extends string | AccountMeta<string> =
@@ -70,7 +73,7 @@ export type UnstakeNftInstruction<
         ? WritableAccount<TAccountNftStake>
         : TAccountNftStake,
       TAccountStakePool extends string
-        ? ReadonlyAccount<TAccountStakePool>
+        ? WritableAccount<TAccountStakePool>
         : TAccountStakePool,
       TAccountStakeAuthority extends string
         ? ReadonlySignerAccount<TAccountStakeAuthority> &
@@ -82,6 +85,9 @@ export type UnstakeNftInstruction<
       TAccountMplCoreAsset extends string
         ? WritableAccount<TAccountMplCoreAsset>
         : TAccountMplCoreAsset,
+      TAccountAssetLock extends string
+        ? WritableAccount<TAccountAssetLock>
+        : TAccountAssetLock,
       TAccountPoolWallet extends string
         ? ReadonlyAccount<TAccountPoolWallet>
         : TAccountPoolWallet,
@@ -89,6 +95,12 @@ export type UnstakeNftInstruction<
         ? WritableSignerAccount<TAccountPayer> &
             AccountSignerMeta<TAccountPayer>
         : TAccountPayer,
+      TAccountRentPayer extends string
+        ? WritableAccount<TAccountRentPayer>
+        : TAccountRentPayer,
+      TAccountAssetLockRentPayer extends string
+        ? WritableAccount<TAccountAssetLockRentPayer>
+        : TAccountAssetLockRentPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -132,8 +144,11 @@ export type UnstakeNftAsyncInput<
   TAccountStakeAuthority extends string = string,
   TAccountMplCoreCollection extends string = string,
   TAccountMplCoreAsset extends string = string,
+  TAccountAssetLock extends string = string,
   TAccountPoolWallet extends string = string,
   TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountAssetLockRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
 > = {
@@ -142,8 +157,12 @@ export type UnstakeNftAsyncInput<
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   mplCoreCollection: Address<TAccountMplCoreCollection>;
   mplCoreAsset: Address<TAccountMplCoreAsset>;
+  /** Omitted for assets frozen by the pool wallet before asset locks existed */
+  assetLock?: Address<TAccountAssetLock>;
   poolWallet?: Address<TAccountPoolWallet>;
   payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
+  assetLockRentPayer?: Address<TAccountAssetLockRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
 };
@@ -154,8 +173,11 @@ export async function getUnstakeNftInstructionAsync<
   TAccountStakeAuthority extends string,
   TAccountMplCoreCollection extends string,
   TAccountMplCoreAsset extends string,
+  TAccountAssetLock extends string,
   TAccountPoolWallet extends string,
   TAccountPayer extends string,
+  TAccountRentPayer extends string,
+  TAccountAssetLockRentPayer extends string,
   TAccountSystemProgram extends string,
   TAccountMplCoreProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
@@ -166,8 +188,11 @@ export async function getUnstakeNftInstructionAsync<
     TAccountStakeAuthority,
     TAccountMplCoreCollection,
     TAccountMplCoreAsset,
+    TAccountAssetLock,
     TAccountPoolWallet,
     TAccountPayer,
+    TAccountRentPayer,
+    TAccountAssetLockRentPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
   >,
@@ -180,8 +205,11 @@ export async function getUnstakeNftInstructionAsync<
     TAccountStakeAuthority,
     TAccountMplCoreCollection,
     TAccountMplCoreAsset,
+    TAccountAssetLock,
     TAccountPoolWallet,
     TAccountPayer,
+    TAccountRentPayer,
+    TAccountAssetLockRentPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
   >
@@ -193,15 +221,21 @@ export async function getUnstakeNftInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     nftStake: { value: input.nftStake ?? null, isWritable: true },
-    stakePool: { value: input.stakePool ?? null, isWritable: false },
+    stakePool: { value: input.stakePool ?? null, isWritable: true },
     stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
     mplCoreCollection: {
       value: input.mplCoreCollection ?? null,
       isWritable: true,
     },
     mplCoreAsset: { value: input.mplCoreAsset ?? null, isWritable: true },
+    assetLock: { value: input.assetLock ?? null, isWritable: true },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    assetLockRentPayer: {
+      value: input.assetLockRentPayer ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
   };
@@ -211,6 +245,17 @@ export async function getUnstakeNftInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.assetLock.value) {
+    accounts.assetLock.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([65, 83, 83, 69, 84, 95, 76, 79, 67, 75])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mplCoreAsset.value)),
+      ],
+    });
+  }
   if (!accounts.poolWallet.value) {
     accounts.poolWallet.value = await getProgramDerivedAddress({
       programAddress,
@@ -239,8 +284,11 @@ export async function getUnstakeNftInstructionAsync<
       getAccountMeta(accounts.stakeAuthority),
       getAccountMeta(accounts.mplCoreCollection),
       getAccountMeta(accounts.mplCoreAsset),
+      getAccountMeta(accounts.assetLock),
       getAccountMeta(accounts.poolWallet),
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.assetLockRentPayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.mplCoreProgram),
     ],
@@ -253,8 +301,11 @@ export async function getUnstakeNftInstructionAsync<
     TAccountStakeAuthority,
     TAccountMplCoreCollection,
     TAccountMplCoreAsset,
+    TAccountAssetLock,
     TAccountPoolWallet,
     TAccountPayer,
+    TAccountRentPayer,
+    TAccountAssetLockRentPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
   >);
@@ -266,8 +317,11 @@ export type UnstakeNftInput<
   TAccountStakeAuthority extends string = string,
   TAccountMplCoreCollection extends string = string,
   TAccountMplCoreAsset extends string = string,
+  TAccountAssetLock extends string = string,
   TAccountPoolWallet extends string = string,
   TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountAssetLockRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
 > = {
@@ -276,8 +330,12 @@ export type UnstakeNftInput<
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   mplCoreCollection: Address<TAccountMplCoreCollection>;
   mplCoreAsset: Address<TAccountMplCoreAsset>;
+  /** Omitted for assets frozen by the pool wallet before asset locks existed */
+  assetLock?: Address<TAccountAssetLock>;
   poolWallet: Address<TAccountPoolWallet>;
   payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
+  assetLockRentPayer?: Address<TAccountAssetLockRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
 };
@@ -288,8 +346,11 @@ export function getUnstakeNftInstruction<
   TAccountStakeAuthority extends string,
   TAccountMplCoreCollection extends string,
   TAccountMplCoreAsset extends string,
+  TAccountAssetLock extends string,
   TAccountPoolWallet extends string,
   TAccountPayer extends string,
+  TAccountRentPayer extends string,
+  TAccountAssetLockRentPayer extends string,
   TAccountSystemProgram extends string,
   TAccountMplCoreProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
@@ -300,8 +361,11 @@ export function getUnstakeNftInstruction<
     TAccountStakeAuthority,
     TAccountMplCoreCollection,
     TAccountMplCoreAsset,
+    TAccountAssetLock,
     TAccountPoolWallet,
     TAccountPayer,
+    TAccountRentPayer,
+    TAccountAssetLockRentPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
   >,
@@ -313,8 +377,11 @@ export function getUnstakeNftInstruction<
   TAccountStakeAuthority,
   TAccountMplCoreCollection,
   TAccountMplCoreAsset,
+  TAccountAssetLock,
   TAccountPoolWallet,
   TAccountPayer,
+  TAccountRentPayer,
+  TAccountAssetLockRentPayer,
   TAccountSystemProgram,
   TAccountMplCoreProgram
 > {
@@ -325,15 +392,21 @@ export function getUnstakeNftInstruction<
   // Original accounts.
   const originalAccounts = {
     nftStake: { value: input.nftStake ?? null, isWritable: true },
-    stakePool: { value: input.stakePool ?? null, isWritable: false },
+    stakePool: { value: input.stakePool ?? null, isWritable: true },
     stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
     mplCoreCollection: {
       value: input.mplCoreCollection ?? null,
       isWritable: true,
     },
     mplCoreAsset: { value: input.mplCoreAsset ?? null, isWritable: true },
+    assetLock: { value: input.assetLock ?? null, isWritable: true },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    assetLockRentPayer: {
+      value: input.assetLockRentPayer ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
   };
@@ -360,8 +433,11 @@ export function getUnstakeNftInstruction<
       getAccountMeta(accounts.stakeAuthority),
       getAccountMeta(accounts.mplCoreCollection),
       getAccountMeta(accounts.mplCoreAsset),
+      getAccountMeta(accounts.assetLock),
       getAccountMeta(accounts.poolWallet),
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.assetLockRentPayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.mplCoreProgram),
     ],
@@ -374,8 +450,11 @@ export function getUnstakeNftInstruction<
     TAccountStakeAuthority,
     TAccountMplCoreCollection,
     TAccountMplCoreAsset,
+    TAccountAssetLock,
     TAccountPoolWallet,
     TAccountPayer,
+    TAccountRentPayer,
+    TAccountAssetLockRentPayer,
     TAccountSystemProgram,
     TAccountMplCoreProgram
   >);
@@ -392,10 +471,14 @@ export type ParsedUnstakeNftInstruction<
     stakeAuthority: TAccountMetas[2];
     mplCoreCollection: TAccountMetas[3];
     mplCoreAsset: TAccountMetas[4];
-    poolWallet: TAccountMetas[5];
-    payer: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
-    mplCoreProgram: TAccountMetas[8];
+    /** Omitted for assets frozen by the pool wallet before asset locks existed */
+    assetLock?: TAccountMetas[5] | undefined;
+    poolWallet: TAccountMetas[6];
+    payer: TAccountMetas[7];
+    rentPayer: TAccountMetas[8];
+    assetLockRentPayer?: TAccountMetas[9] | undefined;
+    systemProgram: TAccountMetas[10];
+    mplCoreProgram: TAccountMetas[11];
   };
   data: UnstakeNftInstructionData;
 };
@@ -408,7 +491,7 @@ export function parseUnstakeNftInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnstakeNftInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -418,6 +501,12 @@ export function parseUnstakeNftInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -426,8 +515,11 @@ export function parseUnstakeNftInstruction<
       stakeAuthority: getNextAccount(),
       mplCoreCollection: getNextAccount(),
       mplCoreAsset: getNextAccount(),
+      assetLock: getNextOptionalAccount(),
       poolWallet: getNextAccount(),
       payer: getNextAccount(),
+      rentPayer: getNextAccount(),
+      assetLockRentPayer: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       mplCoreProgram: getNextAccount(),
     },
@@ -37,7 +37,6 @@ import {
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -56,24 +55,35 @@ export function getWithdrawDiscriminatorBytes() {
 
 export type WithdrawInstruction<
   TProgram extends string = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
+  TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountNftStake extends string | AccountMeta<string> = string,
   TAccountUser extends string | AccountMeta<string> = string,
+  TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountUserStakeAccount extends string | AccountMeta<string> = string,
+  TAccountUserPoolStakeAccount extends string | AccountMeta<string> = string,
   TAccountStakeTokenMint extends string | AccountMeta<string> = string,
   TAccountStakeTokenAccount extends string | AccountMeta<string> = string,
   TAccountUserStakeTokenAccount extends string | AccountMeta<string> = string,
   TAccountPoolWallet extends string | AccountMeta<string> = string,
-  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountRentPayer extends string | AccountMeta<string> = string,
+  TAccountUserPoolStakeRentPayer extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountReceiptMint extends string | AccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | AccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | AccountMeta<string> =
+    'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
+      TAccountAdmin extends string
+        ? ReadonlyAccount<TAccountAdmin>
+        : TAccountAdmin,
       TAccountStakePool extends string
         ? WritableAccount<TAccountStakePool>
         : TAccountStakePool,
@@ -81,11 +91,18 @@ export type WithdrawInstruction<
         ? WritableAccount<TAccountNftStake>
         : TAccountNftStake,
       TAccountUser extends string
-        ? ReadonlySignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
+        ? ReadonlyAccount<TAccountUser>
         : TAccountUser,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            AccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
       TAccountUserStakeAccount extends string
         ? WritableAccount<TAccountUserStakeAccount>
         : TAccountUserStakeAccount,
+      TAccountUserPoolStakeAccount extends string
+        ? WritableAccount<TAccountUserPoolStakeAccount>
+        : TAccountUserPoolStakeAccount,
       TAccountStakeTokenMint extends string
         ? ReadonlyAccount<TAccountStakeTokenMint>
         : TAccountStakeTokenMint,
@@ -98,16 +115,27 @@ export type WithdrawInstruction<
       TAccountPoolWallet extends string
         ? ReadonlyAccount<TAccountPoolWallet>
         : TAccountPoolWallet,
-      TAccountPayer extends string
-        ? WritableSignerAccount<TAccountPayer> &
-            AccountSignerMeta<TAccountPayer>
-        : TAccountPayer,
+      TAccountRentPayer extends string
+        ? WritableAccount<TAccountRentPayer>
+        : TAccountRentPayer,
+      TAccountUserPoolStakeRentPayer extends string
+        ? WritableAccount<TAccountUserPoolStakeRentPayer>
+        : TAccountUserPoolStakeRentPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? WritableAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -149,74 +177,117 @@ export function getWithdrawInstructionDataCodec(): Codec<
 }
 
 export type WithdrawAsyncInput<
+  TAccountAdmin extends string = string,
   TAccountStakePool extends string = string,
   TAccountNftStake extends string = string,
   TAccountUser extends string = string,
+  TAccountAuthority extends string = string,
   TAccountUserStakeAccount extends string = string,
+  TAccountUserPoolStakeAccount extends string = string,
   TAccountStakeTokenMint extends string = string,
   TAccountStakeTokenAccount extends string = string,
   TAccountUserStakeTokenAccount extends string = string,
   TAccountPoolWallet extends string = string,
-  TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountUserPoolStakeRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
 > = {
+  admin?: Address<TAccountAdmin>;
   stakePool: Address<TAccountStakePool>;
   nftStake: Address<TAccountNftStake>;
-  user: TransactionSigner<TAccountUser>;
+  user: Address<TAccountUser>;
+  /**
+   * The owner of the position, or a holder of the pool's receipts redeeming the stake
+   * the position backs with receipts
+   */
+  authority: TransactionSigner<TAccountAuthority>;
   userStakeAccount?: Address<TAccountUserStakeAccount>;
+  /** Can be left out once the user's principal in the pool is gone and the account closed */
+  userPoolStakeAccount?: Address<TAccountUserPoolStakeAccount>;
   stakeTokenMint: Address<TAccountStakeTokenMint>;
   stakeTokenAccount: Address<TAccountStakeTokenAccount>;
+  /** Any token account of the stake mint can receive the withdrawal */
   userStakeTokenAccount: Address<TAccountUserStakeTokenAccount>;
   poolWallet?: Address<TAccountPoolWallet>;
-  payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
+  userPoolStakeRentPayer?: Address<TAccountUserPoolStakeRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Required when the position holds receipt tokens */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Receipts are burned from the signer's own account */
+  receiptTokenAccount?: Address<TAccountReceiptTokenAccount>;
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   amount: WithdrawInstructionDataArgs_['amount'];
 };
 
 export async function getWithdrawInstructionAsync<
+  TAccountAdmin extends string,
   TAccountStakePool extends string,
   TAccountNftStake extends string,
   TAccountUser extends string,
+  TAccountAuthority extends string,
   TAccountUserStakeAccount extends string,
+  TAccountUserPoolStakeAccount extends string,
   TAccountStakeTokenMint extends string,
   TAccountStakeTokenAccount extends string,
   TAccountUserStakeTokenAccount extends string,
   TAccountPoolWallet extends string,
-  TAccountPayer extends string,
+  TAccountRentPayer extends string,
+  TAccountUserPoolStakeRentPayer extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: WithdrawAsyncInput<
+    TAccountAdmin,
     TAccountStakePool,
     TAccountNftStake,
     TAccountUser,
+    TAccountAuthority,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
+    TAccountUserPoolStakeRentPayer,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   WithdrawInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountStakePool,
     TAccountNftStake,
     TAccountUser,
+    TAccountAuthority,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
+    TAccountUserPoolStakeRentPayer,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >
 > {
   // Program address.
@@ -225,13 +296,19 @@ export async function getWithdrawInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     nftStake: { value: input.nftStake ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
     userStakeAccount: {
       value: input.userStakeAccount ?? null,
       isWritable: true,
     },
+    userPoolStakeAccount: {
+      value: input.userPoolStakeAccount ?? null,
+      isWritable: true,
+    },
     stakeTokenMint: { value: input.stakeTokenMint ?? null, isWritable: false },
     stakeTokenAccount: {
       value: input.stakeTokenAccount ?? null,
@@ -242,9 +319,22 @@ export async function getWithdrawInstructionAsync<
       isWritable: true,
     },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
-    payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    userPoolStakeRentPayer: {
+      value: input.userPoolStakeRentPayer ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -255,6 +345,12 @@ export async function getWithdrawInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.admin.value) {
+    accounts.admin.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([65, 68, 77, 73, 78]))],
+    });
+  }
   if (!accounts.userStakeAccount.value) {
     accounts.userStakeAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -267,6 +363,20 @@ export async function getWithdrawInstructionAsync<
       ],
     });
   }
+  if (!accounts.userPoolStakeAccount.value) {
+    accounts.userPoolStakeAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            85, 83, 69, 82, 95, 80, 79, 79, 76, 95, 83, 84, 65, 75, 69,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.user.value)),
+      ],
+    });
+  }
   if (!accounts.poolWallet.value) {
     accounts.poolWallet.value = await getProgramDerivedAddress({
       programAddress,
@@ -286,21 +396,32 @@ export async function getWithdrawInstructionAsync<
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
   }
+  if (!accounts.receiptTokenProgram.value) {
+    accounts.receiptTokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
+      getAccountMeta(accounts.admin),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.nftStake),
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.authority),
       getAccountMeta(accounts.userStakeAccount),
+      getAccountMeta(accounts.userPoolStakeAccount),
       getAccountMeta(accounts.stakeTokenMint),
       getAccountMeta(accounts.stakeTokenAccount),
       getAccountMeta(accounts.userStakeTokenAccount),
       getAccountMeta(accounts.poolWallet),
-      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.userPoolStakeRentPayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
     ],
     data: getWithdrawInstructionDataEncoder().encode(
       args as WithdrawInstructionDataArgs_
@@ -308,88 +429,138 @@ export async function getWithdrawInstructionAsync<
     programAddress,
   } as WithdrawInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountStakePool,
     TAccountNftStake,
     TAccountUser,
+    TAccountAuthority,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
+    TAccountUserPoolStakeRentPayer,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >);
 }
 
 export type WithdrawInput<
+  TAccountAdmin extends string = string,
   TAccountStakePool extends string = string,
   TAccountNftStake extends string = string,
   TAccountUser extends string = string,
+  TAccountAuthority extends string = string,
   TAccountUserStakeAccount extends string = string,
+  TAccountUserPoolStakeAccount extends string = string,
   TAccountStakeTokenMint extends string = string,
   TAccountStakeTokenAccount extends string = string,
   TAccountUserStakeTokenAccount extends string = string,
   TAccountPoolWallet extends string = string,
-  TAccountPayer extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountUserPoolStakeRentPayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
 > = {
+  admin: Address<TAccountAdmin>;
   stakePool: Address<TAccountStakePool>;
   nftStake: Address<TAccountNftStake>;
-  user: TransactionSigner<TAccountUser>;
+  user: Address<TAccountUser>;
+  /**
+   * The owner of the position, or a holder of the pool's receipts redeeming the stake
+   * the position backs with receipts
+   */
+  authority: TransactionSigner<TAccountAuthority>;
   userStakeAccount: Address<TAccountUserStakeAccount>;
+  /** Can be left out once the user's principal in the pool is gone and the account closed */
+  userPoolStakeAccount?: Address<TAccountUserPoolStakeAccount>;
   stakeTokenMint: Address<TAccountStakeTokenMint>;
   stakeTokenAccount: Address<TAccountStakeTokenAccount>;
+  /** Any token account of the stake mint can receive the withdrawal */
   userStakeTokenAccount: Address<TAccountUserStakeTokenAccount>;
   poolWallet: Address<TAccountPoolWallet>;
-  payer: TransactionSigner<TAccountPayer>;
+  rentPayer: Address<TAccountRentPayer>;
+  userPoolStakeRentPayer?: Address<TAccountUserPoolStakeRentPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Required when the position holds receipt tokens */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Receipts are burned from the signer's own account */
+  receiptTokenAccount?: Address<TAccountReceiptTokenAccount>;
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   amount: WithdrawInstructionDataArgs_['amount'];
 };
 
 export function getWithdrawInstruction<
+  TAccountAdmin extends string,
   TAccountStakePool extends string,
   TAccountNftStake extends string,
   TAccountUser extends string,
+  TAccountAuthority extends string,
   TAccountUserStakeAccount extends string,
+  TAccountUserPoolStakeAccount extends string,
   TAccountStakeTokenMint extends string,
   TAccountStakeTokenAccount extends string,
   TAccountUserStakeTokenAccount extends string,
   TAccountPoolWallet extends string,
-  TAccountPayer extends string,
+  TAccountRentPayer extends string,
+  TAccountUserPoolStakeRentPayer extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptTokenProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: WithdrawInput<
+    TAccountAdmin,
     TAccountStakePool,
     TAccountNftStake,
     TAccountUser,
+    TAccountAuthority,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
+    TAccountUserPoolStakeRentPayer,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawInstruction<
   TProgramAddress,
+  TAccountAdmin,
   TAccountStakePool,
   TAccountNftStake,
   TAccountUser,
+  TAccountAuthority,
   TAccountUserStakeAccount,
+  TAccountUserPoolStakeAccount,
   TAccountStakeTokenMint,
   TAccountStakeTokenAccount,
   TAccountUserStakeTokenAccount,
   TAccountPoolWallet,
-  TAccountPayer,
+  TAccountRentPayer,
+  TAccountUserPoolStakeRentPayer,
   TAccountSystemProgram,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptTokenProgram
 > {
   // Program address.
   const programAddress =
@@ -397,13 +568,19 @@ export function getWithdrawInstruction<
 
   // Original accounts.
   const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: true },
     nftStake: { value: input.nftStake ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
     userStakeAccount: {
       value: input.userStakeAccount ?? null,
       isWritable: true,
     },
+    userPoolStakeAccount: {
+      value: input.userPoolStakeAccount ?? null,
+      isWritable: true,
+    },
     stakeTokenMint: { value: input.stakeTokenMint ?? null, isWritable: false },
     stakeTokenAccount: {
       value: input.stakeTokenAccount ?? null,
@@ -414,9 +591,22 @@ export function getWithdrawInstruction<
       isWritable: true,
     },
     poolWallet: { value: input.poolWallet ?? null, isWritable: false },
-    payer: { value: input.payer ?? null, isWritable: true },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    userPoolStakeRentPayer: {
+      value: input.userPoolStakeRentPayer ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -435,21 +625,32 @@ export function getWithdrawInstruction<
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
   }
+  if (!accounts.receiptTokenProgram.value) {
+    accounts.receiptTokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
+      getAccountMeta(accounts.admin),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.nftStake),
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.authority),
       getAccountMeta(accounts.userStakeAccount),
+      getAccountMeta(accounts.userPoolStakeAccount),
       getAccountMeta(accounts.stakeTokenMint),
       getAccountMeta(accounts.stakeTokenAccount),
       getAccountMeta(accounts.userStakeTokenAccount),
       getAccountMeta(accounts.poolWallet),
-      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.userPoolStakeRentPayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
     ],
     data: getWithdrawInstructionDataEncoder().encode(
       args as WithdrawInstructionDataArgs_
@@ -457,17 +658,24 @@ export function getWithdrawInstruction<
     programAddress,
   } as WithdrawInstruction<
     TProgramAddress,
+    TAccountAdmin,
     TAccountStakePool,
     TAccountNftStake,
     TAccountUser,
+    TAccountAuthority,
     TAccountUserStakeAccount,
+    TAccountUserPoolStakeAccount,
     TAccountStakeTokenMint,
     TAccountStakeTokenAccount,
     TAccountUserStakeTokenAccount,
     TAccountPoolWallet,
-    TAccountPayer,
+    TAccountRentPayer,
+    TAccountUserPoolStakeRentPayer,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptTokenProgram
   >);
 }
 
@@ -477,17 +685,32 @@ export type ParsedWithdrawInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    stakePool: TAccountMetas[0];
-    nftStake: TAccountMetas[1];
-    user: TAccountMetas[2];
-    userStakeAccount: TAccountMetas[3];
-    stakeTokenMint: TAccountMetas[4];
-    stakeTokenAccount: TAccountMetas[5];
-    userStakeTokenAccount: TAccountMetas[6];
-    poolWallet: TAccountMetas[7];
-    payer: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
-    tokenProgram: TAccountMetas[10];
+    admin: TAccountMetas[0];
+    stakePool: TAccountMetas[1];
+    nftStake: TAccountMetas[2];
+    user: TAccountMetas[3];
+    /**
+     * The owner of the position, or a holder of the pool's receipts redeeming the stake
+     * the position backs with receipts
+     */
+    authority: TAccountMetas[4];
+    userStakeAccount: TAccountMetas[5];
+    /** Can be left out once the user's principal in the pool is gone and the account closed */
+    userPoolStakeAccount?: TAccountMetas[6] | undefined;
+    stakeTokenMint: TAccountMetas[7];
+    stakeTokenAccount: TAccountMetas[8];
+    /** Any token account of the stake mint can receive the withdrawal */
+    userStakeTokenAccount: TAccountMetas[9];
+    poolWallet: TAccountMetas[10];
+    rentPayer: TAccountMetas[11];
+    userPoolStakeRentPayer?: TAccountMetas[12] | undefined;
+    systemProgram: TAccountMetas[13];
+    tokenProgram: TAccountMetas[14];
+    /** Required when the position holds receipt tokens */
+    receiptMint?: TAccountMetas[15] | undefined;
+    /** Receipts are burned from the signer's own account */
+    receiptTokenAccount?: TAccountMetas[16] | undefined;
+    receiptTokenProgram?: TAccountMetas[17] | undefined;
   };
   data: WithdrawInstructionData;
 };
@@ -500,7 +723,7 @@ export function parseWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 18) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -510,20 +733,33 @@ export function parseWithdrawInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
+      admin: getNextAccount(),
       stakePool: getNextAccount(),
       nftStake: getNextAccount(),
       user: getNextAccount(),
+      authority: getNextAccount(),
       userStakeAccount: getNextAccount(),
+      userPoolStakeAccount: getNextOptionalAccount(),
       stakeTokenMint: getNextAccount(),
       stakeTokenAccount: getNextAccount(),
       userStakeTokenAccount: getNextAccount(),
       poolWallet: getNextAccount(),
-      payer: getNextAccount(),
+      rentPayer: getNextAccount(),
+      userPoolStakeRentPayer: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
+      receiptMint: getNextOptionalAccount(),
+      receiptTokenAccount: getNextOptionalAccount(),
+      receiptTokenProgram: getNextOptionalAccount(),
     },
     data: getWithdrawInstructionDataDecoder().decode(instruction.data),
   };
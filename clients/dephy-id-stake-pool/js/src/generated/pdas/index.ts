@@ -8,6 +8,18 @@
 
 export * from './adminAccount';
 export * from './announcedConfig';
+export * from './assetLock';
+export * from './configChange';
+export * from './deviceScore';
+export * from './distributor';
+export * from './distributorVault';
+export * from './nftEscrow';
 export * from './poolWallet';
+export * from './receiptMint';
+export * from './rewardStream';
+export * from './rewardVault';
 export * from './stakeTokenAccount';
+export * from './treasury';
+export * from './userPoolStake';
 export * from './userStakeAccount';
+export * from './voteRecord';
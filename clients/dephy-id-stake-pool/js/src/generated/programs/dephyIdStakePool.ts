@@ -16,14 +16,52 @@ import {
 import {
   type ParsedAnnounceUpdateConfigInstruction,
   type ParsedCancelUpdateConfigInstruction,
+  type ParsedClaimDistributionInstruction,
+  type ParsedClaimPoolFeesInstruction,
+  type ParsedClaimProtocolFeesInstruction,
+  type ParsedClaimRewardsInstruction,
+  type ParsedCloseConfigChangeInstruction,
+  type ParsedCloseDeviceScoreInstruction,
+  type ParsedCloseDistributorInstruction,
   type ParsedCloseNftStakeInstruction,
+  type ParsedCloseRewardStreamInstruction,
+  type ParsedCloseStakePoolInstruction,
+  type ParsedCloseVoteRecordInstruction,
+  type ParsedCompoundRewardsInstruction,
   type ParsedConfirmUpdateConfigInstruction,
+  type ParsedCreateDistributorInstruction,
   type ParsedCreateNftStakeInstruction,
+  type ParsedCreateReceiptMintInstruction,
+  type ParsedCreateRewardStreamInstruction,
   type ParsedCreateStakePoolInstruction,
+  type ParsedCreateTokenNftStakeInstruction,
+  type ParsedDeactivateStakeInstruction,
+  type ParsedDepositForInstruction,
   type ParsedDepositTokenInstruction,
+  type ParsedFinalizeNftStakeMigrationInstruction,
+  type ParsedForceUnstakeNftInstruction,
+  type ParsedForceUnstakeTokenNftInstruction,
+  type ParsedGetDeviceScoreInstruction,
+  type ParsedGetStakeAverageInstruction,
   type ParsedInitializeInstruction,
+  type ParsedMigrateAdminInstruction,
+  type ParsedMigrateNftStakeInstruction,
+  type ParsedMigrateStakePoolInstruction,
+  type ParsedMigrateUserStakeInstruction,
+  type ParsedPostDeviceScoreInstruction,
+  type ParsedRedelegateInstruction,
+  type ParsedRefundOrphanedStakeInstruction,
+  type ParsedSetAutoCompoundInstruction,
+  type ParsedSetProtocolFeeInstruction,
+  type ParsedSlashNftStakeInstruction,
+  type ParsedSunsetStakePoolInstruction,
+  type ParsedTransferNftStakeInstruction,
   type ParsedUnstakeNftInstruction,
+  type ParsedUnstakeTokenNftInstruction,
+  type ParsedVetoUpdateConfigInstruction,
+  type ParsedVoteConfigInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawTreasuryInstruction,
 } from '../instructions';
 
 export const DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS =
@@ -32,9 +70,16 @@ export const DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS =
 export enum DephyIdStakePoolAccount {
   AdminAccount,
   AnnouncedConfigAccount,
+  AssetLockAccount,
+  ConfigChangeAccount,
+  DeviceScoreAccount,
+  DistributorAccount,
   NftStakeAccount,
+  RewardStreamAccount,
   StakePoolAccount,
+  UserPoolStakeAccount,
   UserStakeAccount,
+  VoteRecordAccount,
 }
 
 export function identifyDephyIdStakePoolAccount(
@@ -63,6 +108,50 @@ export function identifyDephyIdStakePoolAccount(
   ) {
     return DephyIdStakePoolAccount.AnnouncedConfigAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([249, 173, 247, 214, 19, 11, 162, 89])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.AssetLockAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([129, 174, 3, 37, 118, 239, 100, 72])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.ConfigChangeAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([133, 55, 198, 157, 247, 225, 213, 135])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.DeviceScoreAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([38, 14, 200, 38, 27, 205, 123, 134])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.DistributorAccount;
+  }
   if (
     containsBytes(
       data,
@@ -74,6 +163,17 @@ export function identifyDephyIdStakePoolAccount(
   ) {
     return DephyIdStakePoolAccount.NftStakeAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 110, 252, 150, 117, 185, 18, 166])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.RewardStreamAccount;
+  }
   if (
     containsBytes(
       data,
@@ -85,6 +185,17 @@ export function identifyDephyIdStakePoolAccount(
   ) {
     return DephyIdStakePoolAccount.StakePoolAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 19, 10, 37, 208, 134, 106, 188])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.UserPoolStakeAccount;
+  }
   if (
     containsBytes(
       data,
@@ -96,6 +207,17 @@ export function identifyDephyIdStakePoolAccount(
   ) {
     return DephyIdStakePoolAccount.UserStakeAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([136, 117, 40, 53, 223, 194, 59, 14])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolAccount.VoteRecordAccount;
+  }
   throw new Error(
     'The provided account could not be identified as a dephyIdStakePool account.'
   );
@@ -104,14 +226,52 @@ export function identifyDephyIdStakePoolAccount(
 export enum DephyIdStakePoolInstruction {
   AnnounceUpdateConfig,
   CancelUpdateConfig,
+  ClaimDistribution,
+  ClaimPoolFees,
+  ClaimProtocolFees,
+  ClaimRewards,
+  CloseConfigChange,
+  CloseDeviceScore,
+  CloseDistributor,
   CloseNftStake,
+  CloseRewardStream,
+  CloseStakePool,
+  CloseVoteRecord,
+  CompoundRewards,
   ConfirmUpdateConfig,
+  CreateDistributor,
   CreateNftStake,
+  CreateReceiptMint,
+  CreateRewardStream,
   CreateStakePool,
+  CreateTokenNftStake,
+  DeactivateStake,
+  DepositFor,
   DepositToken,
+  FinalizeNftStakeMigration,
+  ForceUnstakeNft,
+  ForceUnstakeTokenNft,
+  GetDeviceScore,
+  GetStakeAverage,
   Initialize,
+  MigrateAdmin,
+  MigrateNftStake,
+  MigrateStakePool,
+  MigrateUserStake,
+  PostDeviceScore,
+  Redelegate,
+  RefundOrphanedStake,
+  SetAutoCompound,
+  SetProtocolFee,
+  SlashNftStake,
+  SunsetStakePool,
+  TransferNftStake,
   UnstakeNft,
+  UnstakeTokenNft,
+  VetoUpdateConfig,
+  VoteConfig,
   Withdraw,
+  WithdrawTreasury,
 }
 
 export function identifyDephyIdStakePoolInstruction(
@@ -140,6 +300,83 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.CancelUpdateConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([204, 156, 94, 85, 2, 125, 232, 180])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ClaimDistribution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([33, 187, 125, 186, 41, 247, 236, 89])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ClaimPoolFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([34, 142, 219, 112, 109, 54, 133, 23])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ClaimProtocolFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 144, 132, 71, 116, 23, 151, 80])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ClaimRewards;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([203, 100, 86, 208, 138, 19, 107, 23])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 82, 101, 170, 147, 92, 140, 234])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseDeviceScore;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([202, 56, 180, 143, 46, 104, 106, 112])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseDistributor;
+  }
   if (
     containsBytes(
       data,
@@ -151,6 +388,50 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.CloseNftStake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([167, 94, 6, 6, 2, 184, 121, 160])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseRewardStream;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([247, 11, 104, 96, 182, 127, 246, 3])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseStakePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 137, 198, 76, 80, 223, 157, 10])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CloseVoteRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([254, 191, 226, 120, 82, 115, 5, 87])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CompoundRewards;
+  }
   if (
     containsBytes(
       data,
@@ -162,6 +443,17 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.ConfirmUpdateConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([184, 103, 26, 71, 141, 64, 49, 177])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CreateDistributor;
+  }
   if (
     containsBytes(
       data,
@@ -173,6 +465,28 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.CreateNftStake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 60, 150, 99, 245, 244, 209, 133])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CreateReceiptMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 194, 100, 89, 62, 241, 211, 85])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CreateRewardStream;
+  }
   if (
     containsBytes(
       data,
@@ -184,6 +498,39 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.CreateStakePool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 146, 177, 34, 53, 64, 28, 151])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.CreateTokenNftStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([165, 158, 229, 97, 168, 220, 187, 225])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.DeactivateStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([193, 39, 228, 88, 160, 254, 92, 53])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.DepositFor;
+  }
   if (
     containsBytes(
       data,
@@ -195,6 +542,61 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.DepositToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([60, 219, 119, 228, 207, 77, 42, 116])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.FinalizeNftStakeMigration;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([46, 241, 174, 239, 194, 204, 42, 46])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ForceUnstakeNft;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([131, 188, 86, 115, 129, 166, 170, 134])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.ForceUnstakeTokenNft;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 130, 42, 231, 189, 168, 66, 112])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.GetDeviceScore;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 243, 34, 164, 35, 156, 222, 28])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.GetStakeAverage;
+  }
   if (
     containsBytes(
       data,
@@ -206,6 +608,138 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.Initialize;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 155, 172, 213, 161, 86, 231, 120])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.MigrateAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([39, 177, 251, 170, 147, 15, 181, 166])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.MigrateNftStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([39, 252, 23, 252, 94, 19, 44, 64])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.MigrateStakePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 38, 213, 47, 115, 141, 135, 106])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.MigrateUserStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([226, 109, 120, 46, 188, 119, 172, 72])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.PostDeviceScore;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([212, 82, 51, 160, 228, 80, 116, 35])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.Redelegate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([157, 71, 241, 110, 53, 45, 246, 128])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.RefundOrphanedStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 22, 46, 161, 189, 86, 250, 244])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.SetAutoCompound;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 239, 83, 242, 136, 43, 144, 217])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.SetProtocolFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 94, 90, 212, 228, 139, 194, 80])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.SlashNftStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 69, 148, 148, 172, 153, 161, 170])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.SunsetStakePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 6, 111, 135, 14, 119, 196, 216])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.TransferNftStake;
+  }
   if (
     containsBytes(
       data,
@@ -217,6 +751,39 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.UnstakeNft;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([77, 32, 112, 41, 33, 104, 202, 67])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.UnstakeTokenNft;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([116, 172, 234, 36, 252, 95, 56, 144])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.VetoUpdateConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 174, 118, 63, 186, 198, 80, 1])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.VoteConfig;
+  }
   if (
     containsBytes(
       data,
@@ -228,6 +795,17 @@ export function identifyDephyIdStakePoolInstruction(
   ) {
     return DephyIdStakePoolInstruction.Withdraw;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([40, 63, 122, 158, 144, 216, 83, 96])
+      ),
+      0
+    )
+  ) {
+    return DephyIdStakePoolInstruction.WithdrawTreasury;
+  }
   throw new Error(
     'The provided instruction could not be identified as a dephyIdStakePool instruction.'
   );
@@ -242,27 +820,141 @@ export type ParsedDephyIdStakePoolInstruction<
   | ({
       instructionType: DephyIdStakePoolInstruction.CancelUpdateConfig;
     } & ParsedCancelUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ClaimDistribution;
+    } & ParsedClaimDistributionInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ClaimPoolFees;
+    } & ParsedClaimPoolFeesInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ClaimProtocolFees;
+    } & ParsedClaimProtocolFeesInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ClaimRewards;
+    } & ParsedClaimRewardsInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseConfigChange;
+    } & ParsedCloseConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseDeviceScore;
+    } & ParsedCloseDeviceScoreInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseDistributor;
+    } & ParsedCloseDistributorInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.CloseNftStake;
     } & ParsedCloseNftStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseRewardStream;
+    } & ParsedCloseRewardStreamInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseStakePool;
+    } & ParsedCloseStakePoolInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CloseVoteRecord;
+    } & ParsedCloseVoteRecordInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CompoundRewards;
+    } & ParsedCompoundRewardsInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.ConfirmUpdateConfig;
     } & ParsedConfirmUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CreateDistributor;
+    } & ParsedCreateDistributorInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.CreateNftStake;
     } & ParsedCreateNftStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CreateReceiptMint;
+    } & ParsedCreateReceiptMintInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CreateRewardStream;
+    } & ParsedCreateRewardStreamInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.CreateStakePool;
     } & ParsedCreateStakePoolInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.CreateTokenNftStake;
+    } & ParsedCreateTokenNftStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.DeactivateStake;
+    } & ParsedDeactivateStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.DepositFor;
+    } & ParsedDepositForInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.DepositToken;
     } & ParsedDepositTokenInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.FinalizeNftStakeMigration;
+    } & ParsedFinalizeNftStakeMigrationInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ForceUnstakeNft;
+    } & ParsedForceUnstakeNftInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.ForceUnstakeTokenNft;
+    } & ParsedForceUnstakeTokenNftInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.GetDeviceScore;
+    } & ParsedGetDeviceScoreInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.GetStakeAverage;
+    } & ParsedGetStakeAverageInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.MigrateAdmin;
+    } & ParsedMigrateAdminInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.MigrateNftStake;
+    } & ParsedMigrateNftStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.MigrateStakePool;
+    } & ParsedMigrateStakePoolInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.MigrateUserStake;
+    } & ParsedMigrateUserStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.PostDeviceScore;
+    } & ParsedPostDeviceScoreInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.Redelegate;
+    } & ParsedRedelegateInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.RefundOrphanedStake;
+    } & ParsedRefundOrphanedStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.SetAutoCompound;
+    } & ParsedSetAutoCompoundInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.SetProtocolFee;
+    } & ParsedSetProtocolFeeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.SlashNftStake;
+    } & ParsedSlashNftStakeInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.SunsetStakePool;
+    } & ParsedSunsetStakePoolInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.TransferNftStake;
+    } & ParsedTransferNftStakeInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.UnstakeNft;
     } & ParsedUnstakeNftInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.UnstakeTokenNft;
+    } & ParsedUnstakeTokenNftInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.VetoUpdateConfig;
+    } & ParsedVetoUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.VoteConfig;
+    } & ParsedVoteConfigInstruction<TProgram>)
   | ({
       instructionType: DephyIdStakePoolInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: DephyIdStakePoolInstruction.WithdrawTreasury;
+    } & ParsedWithdrawTreasuryInstruction<TProgram>);
@@ -6,5 +6,15 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './assetStandard';
+export * from './attributeCondition';
+export * from './attributeRule';
+export * from './configChange';
+export * from './eligibleCollection';
+export * from './rewardCheckpoint';
+export * from './stakeActivation';
+export * from './stakeAverage';
+export * from './stakeCheckpoint';
+export * from './stakeHistory';
 export * from './stakePoolConfig';
-export * from './stakePoolConfigArgs';
+export * from './userReward';
@@ -10,21 +10,63 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
+import {
+  getAttributeRuleDecoder,
+  getAttributeRuleEncoder,
+  getEligibleCollectionDecoder,
+  getEligibleCollectionEncoder,
+  type AttributeRule,
+  type AttributeRuleArgs_,
+  type EligibleCollection,
+  type EligibleCollectionArgs_,
+} from '.';
 
 export type StakePoolConfig = {
   collection: Address;
   stakeTokenMint: Address;
   maxStakeAmount: bigint;
   configReviewTime: bigint;
+  slasher: Option<Address>;
+  scoreOracle: Option<Address>;
+  maxScoreAge: bigint;
+  maxTotalAmount: Option<bigint>;
+  /** Limits the principal a user has in the pool across all positions */
+  maxPerUser: Option<bigint>;
+  minDepositAmount: bigint;
+  /** Fees in basis points, split with the protocol by AdminAccount.protocol_fee_rate */
+  depositFeeRate: number;
+  withdrawFeeRate: number;
+  rewardFeeRate: number;
+  /** Penalty in basis points, decaying to zero over early_exit_period after the last deposit */
+  earlyExitPenaltyRate: number;
+  earlyExitPeriod: bigint;
+  /** Deposits activate at the next multiple of epoch_duration, 0 activates them at once */
+  epochDuration: bigint;
+  extraCollections: Array<Option<EligibleCollection>>;
+  /** Every rule must match the asset Attributes plugin, checked when an nft stake is created */
+  attributeRules: Array<AttributeRule>;
+  /**
+   * Announced configs need votes of this share of the active stake in basis points,
+   * 0 leaves config changes to the pool authority
+   */
+  governanceQuorumRate: number;
 };
 
 export type StakePoolConfigArgs_ = {
@@ -32,27 +74,91 @@ export type StakePoolConfigArgs_ = {
   stakeTokenMint: Address;
   maxStakeAmount: number | bigint;
   configReviewTime: number | bigint;
+  slasher: OptionOrNullable<Address>;
+  scoreOracle: OptionOrNullable<Address>;
+  maxScoreAge: number | bigint;
+  maxTotalAmount: OptionOrNullable<number | bigint>;
+  /** Limits the principal a user has in the pool across all positions */
+  maxPerUser: OptionOrNullable<number | bigint>;
+  minDepositAmount: number | bigint;
+  /** Fees in basis points, split with the protocol by AdminAccount.protocol_fee_rate */
+  depositFeeRate: number;
+  withdrawFeeRate: number;
+  rewardFeeRate: number;
+  /** Penalty in basis points, decaying to zero over early_exit_period after the last deposit */
+  earlyExitPenaltyRate: number;
+  earlyExitPeriod: number | bigint;
+  /** Deposits activate at the next multiple of epoch_duration, 0 activates them at once */
+  epochDuration: number | bigint;
+  extraCollections: Array<OptionOrNullable<EligibleCollectionArgs_>>;
+  /** Every rule must match the asset Attributes plugin, checked when an nft stake is created */
+  attributeRules: Array<AttributeRuleArgs_>;
+  /**
+   * Announced configs need votes of this share of the active stake in basis points,
+   * 0 leaves config changes to the pool authority
+   */
+  governanceQuorumRate: number;
 };
 
-export function getStakePoolConfigEncoder(): FixedSizeEncoder<StakePoolConfigArgs_> {
+export function getStakePoolConfigEncoder(): Encoder<StakePoolConfigArgs_> {
   return getStructEncoder([
     ['collection', getAddressEncoder()],
     ['stakeTokenMint', getAddressEncoder()],
     ['maxStakeAmount', getU64Encoder()],
     ['configReviewTime', getU64Encoder()],
+    ['slasher', getOptionEncoder(getAddressEncoder())],
+    ['scoreOracle', getOptionEncoder(getAddressEncoder())],
+    ['maxScoreAge', getU64Encoder()],
+    ['maxTotalAmount', getOptionEncoder(getU64Encoder())],
+    ['maxPerUser', getOptionEncoder(getU64Encoder())],
+    ['minDepositAmount', getU64Encoder()],
+    ['depositFeeRate', getU16Encoder()],
+    ['withdrawFeeRate', getU16Encoder()],
+    ['rewardFeeRate', getU16Encoder()],
+    ['earlyExitPenaltyRate', getU16Encoder()],
+    ['earlyExitPeriod', getU64Encoder()],
+    ['epochDuration', getU64Encoder()],
+    [
+      'extraCollections',
+      getArrayEncoder(getOptionEncoder(getEligibleCollectionEncoder()), {
+        size: 8,
+      }),
+    ],
+    ['attributeRules', getArrayEncoder(getAttributeRuleEncoder())],
+    ['governanceQuorumRate', getU16Encoder()],
   ]);
 }
 
-export function getStakePoolConfigDecoder(): FixedSizeDecoder<StakePoolConfig> {
+export function getStakePoolConfigDecoder(): Decoder<StakePoolConfig> {
   return getStructDecoder([
     ['collection', getAddressDecoder()],
     ['stakeTokenMint', getAddressDecoder()],
     ['maxStakeAmount', getU64Decoder()],
     ['configReviewTime', getU64Decoder()],
+    ['slasher', getOptionDecoder(getAddressDecoder())],
+    ['scoreOracle', getOptionDecoder(getAddressDecoder())],
+    ['maxScoreAge', getU64Decoder()],
+    ['maxTotalAmount', getOptionDecoder(getU64Decoder())],
+    ['maxPerUser', getOptionDecoder(getU64Decoder())],
+    ['minDepositAmount', getU64Decoder()],
+    ['depositFeeRate', getU16Decoder()],
+    ['withdrawFeeRate', getU16Decoder()],
+    ['rewardFeeRate', getU16Decoder()],
+    ['earlyExitPenaltyRate', getU16Decoder()],
+    ['earlyExitPeriod', getU64Decoder()],
+    ['epochDuration', getU64Decoder()],
+    [
+      'extraCollections',
+      getArrayDecoder(getOptionDecoder(getEligibleCollectionDecoder()), {
+        size: 8,
+      }),
+    ],
+    ['attributeRules', getArrayDecoder(getAttributeRuleDecoder())],
+    ['governanceQuorumRate', getU16Decoder()],
   ]);
 }
 
-export function getStakePoolConfigCodec(): FixedSizeCodec<
+export function getStakePoolConfigCodec(): Codec<
   StakePoolConfigArgs_,
   StakePoolConfig
 > {
@@ -1,52 +0,0 @@
-/**
- * This code was AUTOGENERATED using the Codama library.
- * Please DO NOT EDIT THIS FILE, instead use visitors
- * to add features, then rerun Codama to update it.
- *
- * @see https://github.com/codama-idl/codama
- */
-
-import {
-  combineCodec,
-  getStructDecoder,
-  getStructEncoder,
-  getU64Decoder,
-  getU64Encoder,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
-} from '@solana/kit';
-
-export type StakePoolConfigArgs = {
-  configReviewTime: bigint;
-  maxStakeAmount: bigint;
-};
-
-export type StakePoolConfigArgsArgs_ = {
-  configReviewTime: number | bigint;
-  maxStakeAmount: number | bigint;
-};
-
-export function getStakePoolConfigArgsEncoder(): FixedSizeEncoder<StakePoolConfigArgsArgs_> {
-  return getStructEncoder([
-    ['configReviewTime', getU64Encoder()],
-    ['maxStakeAmount', getU64Encoder()],
-  ]);
-}
-
-export function getStakePoolConfigArgsDecoder(): FixedSizeDecoder<StakePoolConfigArgs> {
-  return getStructDecoder([
-    ['configReviewTime', getU64Decoder()],
-    ['maxStakeAmount', getU64Decoder()],
-  ]);
-}
-
-export function getStakePoolConfigArgsCodec(): FixedSizeCodec<
-  StakePoolConfigArgsArgs_,
-  StakePoolConfigArgs
-> {
-  return combineCodec(
-    getStakePoolConfigArgsEncoder(),
-    getStakePoolConfigArgsDecoder()
-  );
-}
@@ -1,4 +1,4 @@
-import { addPdasVisitor, constantPdaSeedNodeFromString, updateProgramsVisitor, variablePdaSeedNode, publicKeyTypeNode } from 'codama';
+import { addPdasVisitor, constantPdaSeedNodeFromString, updateProgramsVisitor, variablePdaSeedNode, publicKeyTypeNode, numberTypeNode } from 'codama';
 
 export const program = updateProgramsVisitor({
   dephyIoDephyIdStakePool: { name: 'dephyIdStakePool' },
@@ -20,6 +20,42 @@ export const pdas = addPdasVisitor({
   }, {
     name: 'announcedConfig',
     seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'ANNOUNCED_CONFIG')],
+  }, {
+    name: 'treasury',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'TREASURY')],
+  }, {
+    name: 'deviceScore',
+    seeds: [variablePdaSeedNode('nftStake', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'DEVICE_SCORE')],
+  }, {
+    name: 'distributor',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'DISTRIBUTOR'), variablePdaSeedNode('epoch', numberTypeNode('u64'))],
+  }, {
+    name: 'distributorVault',
+    seeds: [variablePdaSeedNode('distributor', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'DISTRIBUTOR_VAULT')],
+  }, {
+    name: 'receiptMint',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'RECEIPT_MINT')],
+  }, {
+    name: 'rewardStream',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'REWARD_STREAM'), variablePdaSeedNode('streamId', numberTypeNode('u64'))],
+  }, {
+    name: 'rewardVault',
+    seeds: [variablePdaSeedNode('rewardStream', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'REWARD_VAULT')],
+  }, {
+    name: 'userPoolStake',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'USER_POOL_STAKE'), variablePdaSeedNode('user', publicKeyTypeNode())],
+  }, {
+    name: 'configChange',
+    seeds: [variablePdaSeedNode('stakePool', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'CONFIG_CHANGE'), variablePdaSeedNode('configVersion', numberTypeNode('u64'))],
+  }, {
+    name: 'assetLock',
+    seeds: [constantPdaSeedNodeFromString('utf8', 'ASSET_LOCK'), variablePdaSeedNode('asset', publicKeyTypeNode())],
+  }, {
+    name: 'nftEscrow',
+    seeds: [variablePdaSeedNode('nftStake', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'NFT_ESCROW')],
+  }, {
+    name: 'voteRecord',
+    seeds: [variablePdaSeedNode('userStake', publicKeyTypeNode()), constantPdaSeedNodeFromString('utf8', 'VOTE_RECORD')],
   }]
 })
 
@@ -3,9 +3,18 @@ use anchor_lang::prelude::constant;
 #[constant]
 pub const ADMIN_SEED: &[u8] = b"ADMIN";
 
+#[constant]
+pub const ASSET_LOCK_SEED: &[u8] = b"ASSET_LOCK";
+
 #[constant]
 pub const ANNOUNCED_CONFIG_SEED: &[u8] = b"ANNOUNCED_CONFIG";
 
+#[constant]
+pub const CONFIG_CHANGE_SEED: &[u8] = b"CONFIG_CHANGE";
+
+#[constant]
+pub const NFT_ESCROW_SEED: &[u8] = b"NFT_ESCROW";
+
 #[constant]
 pub const POOL_WALLET_SEED: &[u8] = b"POOL_WALLET";
 
@@ -14,3 +23,82 @@ pub const STAKE_TOKEN_SEED: &[u8] = b"STAKE_TOKEN";
 
 #[constant]
 pub const USER_STAKE_SEED: &[u8] = b"USER_STAKE";
+
+#[constant]
+pub const USER_POOL_STAKE_SEED: &[u8] = b"USER_POOL_STAKE";
+
+#[constant]
+pub const RECEIPT_MINT_SEED: &[u8] = b"RECEIPT_MINT";
+
+#[constant]
+pub const REWARD_STREAM_SEED: &[u8] = b"REWARD_STREAM";
+
+#[constant]
+pub const REWARD_VAULT_SEED: &[u8] = b"REWARD_VAULT";
+
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"TREASURY";
+
+#[constant]
+pub const VOTE_RECORD_SEED: &[u8] = b"VOTE_RECORD";
+
+#[constant]
+pub const SLASH_FACTOR_PRECISION: u128 = 1_000_000_000_000_000_000_u128;
+
+/// Redistributed penalties stop growing the slash factor here,
+/// keeping amount * slash_factor within u128
+pub const MAX_SLASH_FACTOR: u128 = 16 * SLASH_FACTOR_PRECISION;
+
+#[constant]
+pub const DEVICE_SCORE_SEED: &[u8] = b"DEVICE_SCORE";
+
+#[constant]
+pub const DISTRIBUTOR_SEED: &[u8] = b"DISTRIBUTOR";
+
+#[constant]
+pub const DISTRIBUTOR_VAULT_SEED: &[u8] = b"DISTRIBUTOR_VAULT";
+
+#[constant]
+pub const MAX_UPTIME: u16 = 10_000;
+
+#[constant]
+pub const MAX_FEE_RATE: u16 = 10_000;
+
+/// Share of compounded rewards paid to the cranker, in basis points
+#[constant]
+pub const COMPOUND_BOUNTY_RATE: u16 = 10;
+
+#[constant]
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000_000_000_u128;
+
+pub const MAX_REWARD_STREAMS: usize = 8;
+
+/// Time users have to claim from an ended reward stream before it can be closed
+#[constant]
+pub const REWARD_CLAIM_PERIOD: u64 = 30 * 86_400;
+
+/// Reward per token snapshots a stream keeps at the pool's latest epoch boundaries
+pub const REWARD_CHECKPOINTS: usize = 8;
+
+pub const MAX_CONFIG_CHANGES: usize = 16;
+
+/// Eligible collections besides StakePoolConfig.collection
+pub const MAX_EXTRA_COLLECTIONS: usize = 8;
+
+#[constant]
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Daily checkpoints kept by StakeHistory, averages can span one day less
+pub const STAKE_HISTORY_DAYS: usize = 8;
+
+/// Pools an asset can be staked in at the same time
+pub const MAX_ASSET_LOCKS: usize = 8;
+
+/// Asset attribute rules a pool can require
+pub const MAX_ATTRIBUTE_RULES: usize = 4;
+
+/// Values of an in-set attribute rule
+pub const MAX_ATTRIBUTE_VALUES: usize = 4;
+
+/// Bytes of an attribute key or value in a rule
+pub const MAX_ATTRIBUTE_LEN: usize = 32;
@@ -26,4 +26,70 @@ pub enum ErrorCode {
     NftStakeIsActive,
     #[msg("Invalid commision rate")]
     InvalidCommisionRate,
+    #[msg("Invalid slash rate")]
+    InvalidSlashRate,
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
+    #[msg("Invalid score")]
+    InvalidScore,
+    #[msg("Score is stale")]
+    StaleScore,
+    #[msg("Already claimed")]
+    AlreadyClaimed,
+    #[msg("Invalid merkle proof")]
+    InvalidProof,
+    #[msg("Claim period has ended")]
+    ClaimPeriodEnded,
+    #[msg("Receipt mint already exists")]
+    ReceiptMintExists,
+    #[msg("Receipt token accounts are required")]
+    ReceiptAccountsRequired,
+    #[msg("Stake pool is sunset")]
+    StakePoolSunset,
+    #[msg("Stake pool is not sunset")]
+    StakePoolNotSunset,
+    #[msg("Stake pool still has stakes or vaults")]
+    StakePoolNotEmpty,
+    #[msg("Nft stake is still active")]
+    NftStakeActive,
+    #[msg("Unsupported mint extension")]
+    UnsupportedMintExtension,
+    #[msg("No reward stream slot available")]
+    RewardStreamsFull,
+    #[msg("Reward stream accounts are missing")]
+    MissingRewardStream,
+    #[msg("Invalid reward schedule")]
+    InvalidRewardSchedule,
+    #[msg("Rewards must be claimed before closing the stake")]
+    RewardsNotClaimed,
+    #[msg("Invalid stake average window")]
+    InvalidStakeWindow,
+    #[msg("Stake pool is not closed")]
+    StakePoolNotClosed,
+    #[msg("Fees must be claimed first")]
+    FeesNotClaimed,
+    #[msg("Active stake must be deactivated before it can be withdrawn")]
+    StakeNotDeactivated,
+    #[msg("Asset attributes do not match the pool rules")]
+    IneligibleAsset,
+    #[msg("Asset is already staked in this pool")]
+    AssetAlreadyLocked,
+    #[msg("Asset is staked in too many pools")]
+    AssetLocksFull,
+    #[msg("Unsupported asset standard")]
+    UnsupportedAsset,
+    #[msg("Config governance is disabled")]
+    GovernanceDisabled,
+    #[msg("Voting on the announced config has ended")]
+    VotingEnded,
+    #[msg("Already voted on the announced config")]
+    AlreadyVoted,
+    #[msg("Only stake held at the announcement can vote")]
+    VoteIneligible,
+    #[msg("Votes did not reach the quorum")]
+    QuorumNotReached,
+    #[msg("Auto compound is not enabled")]
+    AutoCompoundDisabled,
+    #[msg("Nft stake is still being migrated")]
+    NftStakeMigrating,
 }
@@ -1,7 +1,7 @@
 use crate::{
     constants::ANNOUNCED_CONFIG_SEED,
     error::ErrorCode,
-    state::{AnnouncedConfigAccount, StakePoolAccount, StakePoolConfigArgs},
+    state::{AnnounceUpdateConfigArgs, AnnouncedConfigAccount, StakePoolAccount},
 };
 use anchor_lang::prelude::*;
 
@@ -23,14 +23,21 @@ pub struct AnnounceUpdateConfig<'info> {
 
 pub fn process_announce_update_config(
     ctx: Context<AnnounceUpdateConfig>,
-    args: StakePoolConfigArgs,
+    args: AnnounceUpdateConfigArgs,
 ) -> Result<()> {
-    msg!("announce update config");
-
-    require_gt!(args.config_review_time, 0, ErrorCode::InvalidConfig);
-    require_gt!(args.max_stake_amount, 0, ErrorCode::InvalidConfig);
+    msg!("announce update config, {} changes", args.changes.len());
 
     let stake_pool = &mut ctx.accounts.stake_pool;
+
+    require!(!args.changes.is_empty(), ErrorCode::InvalidConfig);
+
+    // changes apply in order, the result has to be a valid config
+    let mut config = stake_pool.config.clone();
+    for change in args.changes.iter() {
+        config.apply(change)?;
+    }
+    config.validate()?;
+
     stake_pool.announced_config = Some(ctx.accounts.announced_config.key());
 
     let clock = Clock::get()?;
@@ -39,8 +46,15 @@ pub fn process_announce_update_config(
     let announced_config = &mut ctx.accounts.announced_config;
     announced_config.stake_pool = stake_pool.key();
     announced_config.authority = ctx.accounts.authority.key();
-    announced_config.config = args;
+    announced_config.config_version = stake_pool.config_version;
+    announced_config.changes = args.changes;
     announced_config.timestamp = now;
+    stake_pool.announced_at = now;
+    announced_config.quorum_rate = stake_pool.config.governance_quorum_rate;
+    announced_config.total_stake = stake_pool.active_amount(now);
+    announced_config.yes_votes = 0;
+    announced_config.no_votes = 0;
+    announced_config.rent_payer = ctx.accounts.payer.key();
 
     Ok(())
 }
@@ -0,0 +1,88 @@
+use crate::{
+    constants::POOL_WALLET_SEED,
+    error::ErrorCode,
+    state::{ClaimDistributionArgs, DistributorAccount},
+    utils::{distribution_leaf, verify_merkle_proof},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(mut)]
+    pub distributor: Account<'info, DistributorAccount>,
+    pub user: Signer<'info>,
+    #[account(
+        address = distributor.reward_mint @ ErrorCode::InvalidAccount,
+        mint::token_program = token_program
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = distributor.vault @ ErrorCode::InvalidAccount)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [distributor.stake_pool.as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    args: ClaimDistributionArgs,
+) -> Result<()> {
+    msg!("claim distribution {} {}", args.index, args.amount);
+
+    let distributor = &mut ctx.accounts.distributor;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+    require_gt!(distributor.claim_deadline, now, ErrorCode::ClaimPeriodEnded);
+    require_gt!(distributor.num_nodes, args.index, ErrorCode::InvalidProof);
+    require!(
+        !distributor.is_claimed(args.index),
+        ErrorCode::AlreadyClaimed
+    );
+
+    let leaf = distribution_leaf(args.index, ctx.accounts.user.key, args.amount);
+    require!(
+        verify_merkle_proof(&args.proof, &distributor.merkle_root, leaf),
+        ErrorCode::InvalidProof
+    );
+
+    require_gte!(
+        distributor.total_amount,
+        distributor.claimed_amount + args.amount,
+        ErrorCode::InvalidAmount
+    );
+
+    distributor.set_claimed(args.index);
+    distributor.claimed_amount += args.amount;
+    distributor.claimed_count += 1;
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            &[&[
+                distributor.stake_pool.as_ref(),
+                POOL_WALLET_SEED,
+                &[ctx.bumps.pool_wallet],
+            ]],
+        ),
+        args.amount,
+        ctx.accounts.reward_mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::{
+    constants::{ADMIN_SEED, POOL_WALLET_SEED},
+    error::ErrorCode,
+    state::{AdminAccount, RewardStreamAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Claims deposit and withdraw fees from stake_token_account,
+/// or reward fees from a reward stream vault when reward_stream is given
+#[derive(Accounts)]
+pub struct ClaimFees<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    pub authority: Signer<'info>,
+    #[account(mut, has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub reward_stream: Option<Account<'info, RewardStreamAccount>>,
+    #[account(mint::token_program = token_program)]
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_claim_pool_fees(ctx: Context<ClaimFees>) -> Result<()> {
+    msg!("claim pool fees");
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.stake_pool.authority,
+        ErrorCode::InvalidAuthority
+    );
+
+    let amount = match ctx.accounts.reward_stream.as_mut() {
+        Some(reward_stream) => std::mem::take(&mut reward_stream.pool_fees),
+        None => std::mem::take(&mut ctx.accounts.stake_pool.pool_fees),
+    };
+
+    transfer_fees(ctx, amount)
+}
+
+pub fn process_claim_protocol_fees(ctx: Context<ClaimFees>) -> Result<()> {
+    msg!("claim protocol fees");
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.admin.protocol_fee_authority,
+        ErrorCode::InvalidAuthority
+    );
+
+    let amount = match ctx.accounts.reward_stream.as_mut() {
+        Some(reward_stream) => std::mem::take(&mut reward_stream.protocol_fees),
+        None => std::mem::take(&mut ctx.accounts.stake_pool.protocol_fees),
+    };
+
+    transfer_fees(ctx, amount)
+}
+
+fn transfer_fees(ctx: Context<ClaimFees>, amount: u64) -> Result<()> {
+    msg!("transfer {} fees", amount);
+
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+
+    let (fee_token_account, fee_mint) = match &ctx.accounts.reward_stream {
+        Some(reward_stream) => (reward_stream.vault, reward_stream.reward_mint),
+        None => (
+            ctx.accounts.stake_pool.stake_token_account,
+            ctx.accounts.stake_pool.config.stake_token_mint,
+        ),
+    };
+    require_keys_eq!(
+        ctx.accounts.fee_token_account.key(),
+        fee_token_account,
+        ErrorCode::InvalidAccount
+    );
+    require_keys_eq!(
+        ctx.accounts.fee_mint.key(),
+        fee_mint,
+        ErrorCode::InvalidAccount
+    );
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_token_account.to_account_info(),
+                mint: ctx.accounts.fee_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            &[&[
+                ctx.accounts.stake_pool.key().as_ref(),
+                POOL_WALLET_SEED,
+                &[ctx.bumps.pool_wallet],
+            ]],
+        ),
+        amount,
+        ctx.accounts.fee_mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use crate::{
+    constants::{ADMIN_SEED, POOL_WALLET_SEED, USER_STAKE_SEED},
+    error::ErrorCode,
+    state::{AdminAccount, RewardStreamAccount, StakePoolAccount, UserStakeAccount},
+    utils::load_nft_stake,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{transfer_checked, Mint, TokenInterface, TransferChecked},
+};
+
+/// Remaining accounts, for each stream to claim:
+/// reward_stream (mut), vault (mut), reward_mint, user_reward_token_account (mut)
+///
+/// Streams in a mint of the other token program are skipped and stay claimable
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    /// CHECK: nft_stake could be unstaked already
+    #[account(address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: UncheckedAccount<'info>,
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_claim_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimRewards<'info>>,
+) -> Result<()> {
+    msg!("claim rewards");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let user_stake = &mut ctx.accounts.user_stake_account;
+    user_stake.accumulate_stake(now);
+    if let Some(nft_stake) = load_nft_stake(&ctx.accounts.nft_stake, ctx.program_id)? {
+        user_stake.settle_slash(&nft_stake);
+    }
+
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        ErrorCode::MissingRewardStream
+    );
+
+    let accounts = ctx.accounts;
+    claim_rewards(
+        ClaimAccounts {
+            admin: &accounts.admin,
+            stake_pool: &accounts.stake_pool,
+            user_stake: &mut accounts.user_stake_account,
+            recipient: None,
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            token_program: accounts.token_program.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )
+}
+
+/// Accounts shared by claim_rewards and refund_orphaned_stake to pay out pending rewards
+pub(crate) struct ClaimAccounts<'a, 'info> {
+    pub admin: &'a AdminAccount,
+    pub stake_pool: &'a Account<'info, StakePoolAccount>,
+    pub user_stake: &'a mut UserStakeAccount,
+    /// Owner the reward token accounts must be associated with, any account when None
+    pub recipient: Option<Pubkey>,
+    pub pool_wallet: AccountInfo<'info>,
+    pub pool_wallet_bump: u8,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Pay the pending rewards of the streams passed in groups of four:
+/// reward_stream (mut), vault (mut), reward_mint, user_reward_token_account (mut),
+/// rewards of a stream the token program can not pay are kept pending
+pub(crate) fn claim_rewards<'info>(
+    accounts: ClaimAccounts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    now: u64,
+) -> Result<()> {
+    let stake_pool = accounts.stake_pool;
+    let user_stake = accounts.user_stake;
+
+    let stake_pool_key = stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[accounts.pool_wallet_bump],
+    ]];
+
+    for reward_accounts in remaining_accounts.chunks(4) {
+        let [reward_stream_info, vault, reward_mint, user_reward_token_account] = reward_accounts
+        else {
+            return Err(ErrorCode::MissingRewardStream.into());
+        };
+
+        require_keys_eq!(
+            *reward_stream_info.owner,
+            *program_id,
+            ErrorCode::InvalidAccount
+        );
+        let mut reward_stream =
+            RewardStreamAccount::try_deserialize(&mut reward_stream_info.data.borrow().as_ref())?;
+
+        let index = reward_stream.index as usize;
+        require!(
+            stake_pool.reward_streams[index] == Some(reward_stream_info.key()),
+            ErrorCode::InvalidAccount
+        );
+        require_keys_eq!(vault.key(), reward_stream.vault, ErrorCode::InvalidAccount);
+        require_keys_eq!(
+            reward_mint.key(),
+            reward_stream.reward_mint,
+            ErrorCode::InvalidAccount
+        );
+        if reward_mint.owner != accounts.token_program.key {
+            continue;
+        }
+        if let Some(recipient) = accounts.recipient {
+            require_keys_eq!(
+                user_reward_token_account.key(),
+                get_associated_token_address_with_program_id(
+                    &recipient,
+                    reward_mint.key,
+                    accounts.token_program.key
+                ),
+                ErrorCode::InvalidAccount
+            );
+        }
+
+        // pending stake is not rolled here, as not every stream is updated
+        reward_stream.update(&stake_pool.activation, stake_pool.total_amount, now);
+        user_stake.accrue_rewards(index, &reward_stream, now);
+
+        let pending = user_stake.rewards[index].pending;
+        user_stake.rewards[index].pending = 0;
+
+        // the fee stays in the vault until claimed by the pool and protocol
+        let amount =
+            reward_stream.claim(accounts.admin, pending, stake_pool.config.reward_fee_rate)?;
+        reward_stream.try_serialize(&mut reward_stream_info.data.borrow_mut().as_mut())?;
+
+        if amount == 0 {
+            continue;
+        }
+
+        msg!("claim {} from stream {}", amount, reward_stream.id);
+
+        let decimals = Mint::try_deserialize(&mut reward_mint.data.borrow().as_ref())?.decimals;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                TransferChecked {
+                    from: vault.clone(),
+                    mint: reward_mint.clone(),
+                    to: user_reward_token_account.clone(),
+                    authority: accounts.pool_wallet.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{error::ErrorCode, state::ConfigChangeAccount};
+use anchor_lang::{prelude::*, solana_program::system_program};
+
+#[derive(Accounts)]
+pub struct CloseConfigChange<'info> {
+    #[account(mut, close = rent_payer)]
+    pub config_change: Account<'info, ConfigChangeAccount>,
+    /// CHECK: has to be closed already
+    #[account(address = config_change.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: UncheckedAccount<'info>,
+    /// CHECK: checked against config_change
+    #[account(mut, address = config_change.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// The change history lives as long as the pool, anyone can close it once the pool is closed
+pub fn process_close_config_change(ctx: Context<CloseConfigChange>) -> Result<()> {
+    msg!("close config change");
+
+    require_keys_eq!(
+        *ctx.accounts.stake_pool.owner,
+        system_program::ID,
+        ErrorCode::StakePoolNotClosed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::{error::ErrorCode, state::DeviceScoreAccount, utils::load_nft_stake};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseDeviceScore<'info> {
+    #[account(mut, close = rent_payer)]
+    pub device_score: Account<'info, DeviceScoreAccount>,
+    /// CHECK: could be closed already
+    #[account(address = device_score.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: UncheckedAccount<'info>,
+    /// CHECK: checked against device_score
+    #[account(mut, address = device_score.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Anyone can close the score of an unstaked asset, the rent goes back to its payer
+pub fn process_close_device_score(ctx: Context<CloseDeviceScore>) -> Result<()> {
+    msg!("close device score");
+
+    if let Some(nft_stake) = load_nft_stake(&ctx.accounts.nft_stake, ctx.program_id)? {
+        require!(nft_stake.unstaked, ErrorCode::NftStakeActive);
+    }
+
+    Ok(())
+}
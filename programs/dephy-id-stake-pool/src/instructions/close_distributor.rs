@@ -0,0 +1,90 @@
+use crate::{
+    constants::POOL_WALLET_SEED,
+    error::ErrorCode,
+    state::{DistributorAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct CloseDistributor<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(mut, close = payer, has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub distributor: Account<'info, DistributorAccount>,
+    #[account(
+        address = distributor.reward_mint @ ErrorCode::InvalidAccount,
+        mint::token_program = token_program
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = distributor.vault @ ErrorCode::InvalidAccount)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
+    msg!("close distributor");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+    require_gte!(
+        now,
+        ctx.accounts.distributor.claim_deadline,
+        ErrorCode::NotReadyYet
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[ctx.bumps.pool_wallet],
+    ]];
+
+    // return unclaimed rewards
+    let remaining = ctx.accounts.vault.amount;
+    if remaining > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.refund_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_wallet.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining,
+            ctx.accounts.reward_mint.decimals,
+        )?;
+    }
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.pool_wallet.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    ctx.accounts.stake_pool.reward_vault_count -= 1;
+
+    Ok(())
+}
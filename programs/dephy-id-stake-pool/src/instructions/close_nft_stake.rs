@@ -1,22 +1,20 @@
-use crate::{
-    constants::POOL_WALLET_SEED,
-    error::ErrorCode,
-    state::{NftStakeAccount, StakePoolAccount},
-};
+use crate::{constants::POOL_WALLET_SEED, error::ErrorCode, state::NftStakeAccount};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct CloseNftStake<'info> {
-    #[account(mut, close = payer)]
+    #[account(mut, close = rent_payer)]
     pub nft_stake: Account<'info, NftStakeAccount>,
+    /// CHECK: could be closed already once the asset was unstaked
     #[account(address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
-    pub stake_pool: Account<'info, StakePoolAccount>,
+    pub stake_pool: UncheckedAccount<'info>,
     #[account(address = nft_stake.stake_authority @ ErrorCode::InvalidAuthority)]
     pub stake_authority: Signer<'info>,
     #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
     pub pool_wallet: SystemAccount<'info>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    /// CHECK: gets the rent back
+    #[account(mut, address = nft_stake.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -26,6 +24,10 @@ pub fn process_close_nft_stake(ctx: Context<CloseNftStake>) -> Result<()> {
     let nft_stake = &ctx.accounts.nft_stake;
 
     require_eq!(nft_stake.amount, 0, ErrorCode::StakeNonEmpty);
+    require_eq!(nft_stake.depositor_count, 0, ErrorCode::StakeNonEmpty);
+    // the asset is released by unstaking only, a frozen mpl core asset would keep its
+    // asset lock entry and an escrowed token nft would be stranded
+    require!(nft_stake.unstaked, ErrorCode::NftStakeActive);
 
     Ok(())
 }
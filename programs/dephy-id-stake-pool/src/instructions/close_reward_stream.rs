@@ -0,0 +1,108 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, REWARD_CLAIM_PERIOD},
+    error::ErrorCode,
+    state::{RewardStreamAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct CloseRewardStream<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(mut, close = payer, has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub reward_stream: Account<'info, RewardStreamAccount>,
+    #[account(
+        address = reward_stream.reward_mint @ ErrorCode::InvalidAccount,
+        mint::token_program = token_program
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = reward_stream.vault @ ErrorCode::InvalidAccount)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_close_reward_stream(ctx: Context<CloseRewardStream>) -> Result<()> {
+    msg!("close reward stream");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let reward_stream = &mut ctx.accounts.reward_stream;
+    require_gte!(now, reward_stream.end_time, ErrorCode::NotReadyYet);
+
+    // undistributed rewards go back once the stream has ended, rewards owed to
+    // stakers only after they had the claim period to take them
+    let stake_pool = &ctx.accounts.stake_pool;
+    reward_stream.update(&stake_pool.activation, stake_pool.total_amount, now);
+    if reward_stream.outstanding_amount() > 0 {
+        require_gte!(
+            now,
+            reward_stream.end_time + REWARD_CLAIM_PERIOD,
+            ErrorCode::NotReadyYet
+        );
+    }
+
+    // pool fees go out with the refund
+    require_eq!(
+        ctx.accounts.reward_stream.protocol_fees,
+        0,
+        ErrorCode::FeesNotClaimed
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[ctx.bumps.pool_wallet],
+    ]];
+
+    let remaining = ctx.accounts.vault.amount;
+    if remaining > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.refund_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_wallet.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining,
+            ctx.accounts.reward_mint.decimals,
+        )?;
+    }
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.pool_wallet.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.reward_streams[ctx.accounts.reward_stream.index as usize] = None;
+    stake_pool.reward_vault_count -= 1;
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, STAKE_TOKEN_SEED, TREASURY_SEED},
+    error::ErrorCode,
+    state::StakePoolAccount,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CloseStakePool<'info> {
+    #[account(mut, close = payer)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(
+        address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
+        mint::token_program = token_program
+    )]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken,
+        seeds = [stake_pool.key().as_ref(), STAKE_TOKEN_SEED], bump,
+    )]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: only exists after a slash, closed if present
+    #[account(mut, seeds = [stake_pool.key().as_ref(), TREASURY_SEED], bump)]
+    pub treasury_token_account: UncheckedAccount<'info>,
+    /// Receives tokens left in the pool, e.g. rounding dust
+    #[account(
+        mut,
+        token::mint = stake_token_mint,
+        token::token_program = token_program
+    )]
+    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Required when the pool has a receipt mint, it is closed with the pool
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+}
+
+pub fn process_close_stake_pool(ctx: Context<CloseStakePool>) -> Result<()> {
+    msg!("close stake pool");
+
+    let stake_pool = &ctx.accounts.stake_pool;
+
+    require!(stake_pool.sunset, ErrorCode::StakePoolNotSunset);
+    require_eq!(stake_pool.total_amount, 0, ErrorCode::StakePoolNotEmpty);
+    require_eq!(stake_pool.nft_stake_count, 0, ErrorCode::StakePoolNotEmpty);
+    require_eq!(
+        stake_pool.reward_vault_count,
+        0,
+        ErrorCode::StakePoolNotEmpty
+    );
+    require!(
+        stake_pool.announced_config.is_none(),
+        ErrorCode::StakePoolNotEmpty
+    );
+    // pool fees go out with the refund
+    require_eq!(stake_pool.protocol_fees, 0, ErrorCode::FeesNotClaimed);
+
+    let bump = ctx.bumps.pool_wallet;
+    let accounts = &ctx.accounts;
+
+    accounts.close_token_account(
+        accounts.stake_token_account.to_account_info(),
+        accounts.stake_token_account.amount,
+        bump,
+    )?;
+
+    if !accounts.treasury_token_account.data_is_empty() {
+        let treasury_token_account = accounts.treasury_token_account.to_account_info();
+        let treasury_amount =
+            TokenAccount::try_deserialize(&mut treasury_token_account.data.borrow().as_ref())?
+                .amount;
+        accounts.close_token_account(treasury_token_account, treasury_amount, bump)?;
+    }
+
+    if let Some(receipt_mint_key) = accounts.stake_pool.receipt_mint {
+        let (Some(receipt_mint), Some(receipt_token_program)) =
+            (&accounts.receipt_mint, &accounts.receipt_token_program)
+        else {
+            return Err(ErrorCode::ReceiptAccountsRequired.into());
+        };
+
+        require_keys_eq!(
+            receipt_mint.key(),
+            receipt_mint_key,
+            ErrorCode::InvalidAccount
+        );
+        require_eq!(receipt_mint.supply, 0, ErrorCode::StakePoolNotEmpty);
+
+        let stake_pool_key = accounts.stake_pool.key();
+        close_account(CpiContext::new_with_signer(
+            receipt_token_program.to_account_info(),
+            CloseAccount {
+                account: receipt_mint.to_account_info(),
+                destination: accounts.payer.to_account_info(),
+                authority: accounts.pool_wallet.to_account_info(),
+            },
+            &[&[stake_pool_key.as_ref(), POOL_WALLET_SEED, &[bump]]],
+        ))?;
+    }
+
+    Ok(())
+}
+
+impl<'info> CloseStakePool<'info> {
+    /// Sweep the balance to the refund account and return rent to payer
+    fn close_token_account(
+        &self,
+        token_account: AccountInfo<'info>,
+        amount: u64,
+        pool_wallet_bump: u8,
+    ) -> Result<()> {
+        let stake_pool_key = self.stake_pool.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            stake_pool_key.as_ref(),
+            POOL_WALLET_SEED,
+            &[pool_wallet_bump],
+        ]];
+
+        if amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: token_account.clone(),
+                        mint: self.stake_token_mint.to_account_info(),
+                        to: self.refund_token_account.to_account_info(),
+                        authority: self.pool_wallet.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                self.stake_token_mint.decimals,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: token_account,
+                destination: self.payer.to_account_info(),
+                authority: self.pool_wallet.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    error::ErrorCode,
+    state::{AnnouncedConfigAccount, VoteRecordAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    #[account(mut, close = rent_payer)]
+    pub vote_record: Account<'info, VoteRecordAccount>,
+    /// CHECK: closed or reused by a later announcement once the vote is over
+    #[account(address = vote_record.announced_config @ ErrorCode::InvalidAccount)]
+    pub announced_config: UncheckedAccount<'info>,
+    /// CHECK: gets the rent back
+    #[account(mut, address = vote_record.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Return the rent of a vote record once the vote is over, anyone can call it
+pub fn process_close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    msg!("close vote record");
+
+    let announced_config_info = &ctx.accounts.announced_config;
+    if announced_config_info.owner == ctx.program_id {
+        let announced_config = AnnouncedConfigAccount::try_deserialize(
+            &mut announced_config_info.data.borrow().as_ref(),
+        )?;
+        require_neq!(
+            announced_config.timestamp,
+            ctx.accounts.vote_record.announced_at,
+            ErrorCode::NotReadyYet
+        );
+    }
+
+    Ok(())
+}
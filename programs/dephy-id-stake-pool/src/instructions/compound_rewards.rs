@@ -0,0 +1,218 @@
+use crate::{
+    constants::{
+        ADMIN_SEED, COMPOUND_BOUNTY_RATE, MAX_FEE_RATE, POOL_WALLET_SEED, USER_STAKE_SEED,
+    },
+    error::ErrorCode,
+    state::{
+        AdminAccount, NftStakeAccount, RewardStreamAccount, StakePoolAccount, UserStakeAccount,
+    },
+    utils::{mul_div, update_rewards},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Remaining accounts: every reward stream of the pool (mut)
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(mut, address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(mut, address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    /// CHECK: the owner of the position, who does not sign
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: UncheckedAccount<'info>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+    /// CHECK: a reward stream in the stake mint, also passed in remaining accounts, checked in the handler
+    #[account(mut)]
+    pub reward_stream: UncheckedAccount<'info>,
+    /// CHECK: vault of the reward stream, checked in the handler
+    #[account(mut)]
+    pub reward_vault: UncheckedAccount<'info>,
+    #[account(
+        address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
+        mint::token_program = token_program
+    )]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken,
+        token::mint = stake_token_mint,
+        token::authority = pool_wallet,
+        token::token_program = token_program
+    )]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub cranker: Signer<'info>,
+    /// Receives the bounty
+    #[account(
+        mut,
+        token::mint = stake_token_mint,
+        token::authority = cranker,
+        token::token_program = token_program
+    )]
+    pub cranker_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim a position's rewards in the stake mint into its stake, anyone can crank it for
+/// a bounty. Only what fits under max_stake_amount is compounded, the rest stays pending.
+pub fn process_compound_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, CompoundRewards<'info>>,
+) -> Result<()> {
+    msg!("compound rewards");
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let nft_stake = &mut ctx.accounts.nft_stake;
+    let user_stake = &mut ctx.accounts.user_stake_account;
+
+    require!(user_stake.auto_compound, ErrorCode::AutoCompoundDisabled);
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+    require!(
+        !nft_stake.unstaked && nft_stake.slash_factor > 0,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    user_stake.accumulate_stake(now);
+    nft_stake.accumulate_stake(now);
+    user_stake.settle_slash(nft_stake);
+    user_stake.snapshot_vote(stake_pool);
+
+    update_rewards(
+        stake_pool,
+        &mut [&mut **user_stake],
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    let reward_stream_info = &ctx.accounts.reward_stream;
+    require_keys_eq!(
+        *reward_stream_info.owner,
+        *ctx.program_id,
+        ErrorCode::InvalidAccount
+    );
+    let mut reward_stream =
+        RewardStreamAccount::try_deserialize(&mut reward_stream_info.data.borrow().as_ref())?;
+
+    let index = reward_stream.index as usize;
+    require!(
+        stake_pool.reward_streams[index] == Some(reward_stream_info.key()),
+        ErrorCode::InvalidAccount
+    );
+    require_keys_eq!(
+        reward_stream.reward_mint,
+        stake_pool.config.stake_token_mint,
+        ErrorCode::InvalidStakeToken
+    );
+    require_keys_eq!(
+        ctx.accounts.reward_vault.key(),
+        reward_stream.vault,
+        ErrorCode::InvalidAccount
+    );
+
+    let max_stake_amount = stake_pool
+        .config
+        .max_stake_amount_for(&nft_stake.collection)
+        .ok_or(ErrorCode::InvalidCollection)?;
+    let mut room = max_stake_amount.saturating_sub(nft_stake.amount);
+    if let Some(max_total_amount) = stake_pool.config.max_total_amount {
+        room = room.min(max_total_amount.saturating_sub(stake_pool.total_amount));
+    }
+
+    // claim only the share of pending rewards that still fits in the stake
+    let reward_fee_rate = stake_pool.config.reward_fee_rate;
+    let pending = user_stake.rewards[index].pending;
+    let net = pending - mul_div(pending, reward_fee_rate as u64, MAX_FEE_RATE as u64)?;
+    let net = net - mul_div(net, COMPOUND_BOUNTY_RATE as u64, MAX_FEE_RATE as u64)?;
+    let claim_amount = if net > room {
+        mul_div(pending, room, net)?
+    } else {
+        pending
+    };
+    require_gt!(claim_amount, 0, ErrorCode::InvalidAmount);
+
+    user_stake.rewards[index].pending -= claim_amount;
+
+    let amount = reward_stream.claim(&ctx.accounts.admin, claim_amount, reward_fee_rate)?;
+    reward_stream.try_serialize(&mut reward_stream_info.data.borrow_mut().as_mut())?;
+
+    // rounding leftovers go to the cranker
+    let stake_amount =
+        (amount - mul_div(amount, COMPOUND_BOUNTY_RATE as u64, MAX_FEE_RATE as u64)?).min(room);
+    let bounty = amount - stake_amount;
+
+    msg!("compound {}, bounty {}", stake_amount, bounty);
+
+    let stake_pool_key = stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[ctx.bumps.pool_wallet],
+    ]];
+
+    let vault_amount_before = ctx.accounts.stake_token_account.amount;
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                mint: ctx.accounts.stake_token_mint.to_account_info(),
+                to: ctx.accounts.stake_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        stake_amount,
+        ctx.accounts.stake_token_mint.decimals,
+    )?;
+
+    if bounty > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    mint: ctx.accounts.stake_token_mint.to_account_info(),
+                    to: ctx.accounts.cranker_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_wallet.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            bounty,
+            ctx.accounts.stake_token_mint.decimals,
+        )?;
+    }
+
+    // a transfer fee makes the pool receive less than stake_amount
+    ctx.accounts.stake_token_account.reload()?;
+    let amount = ctx.accounts.stake_token_account.amount - vault_amount_before;
+
+    // compounded rewards activate and count like a deposit, but are no principal and keep
+    // the last deposit timestamp
+    let activation_time = stake_pool.config.activation_time(now);
+    user_stake.activation.roll(now);
+    user_stake.activation.add(amount, activation_time);
+    nft_stake.activation.roll(now);
+    nft_stake.activation.add(amount, activation_time);
+    stake_pool.activation.roll(now);
+    stake_pool.activation.add(amount, activation_time);
+
+    user_stake.amount += amount;
+    nft_stake.amount += amount;
+    nft_stake.total_deposited += amount;
+    nft_stake.last_activity_timestamp = now;
+    stake_pool.total_amount += amount;
+    stake_pool.total_deposited += amount;
+    stake_pool.last_activity_timestamp = now;
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
-    constants::ANNOUNCED_CONFIG_SEED,
+    constants::{ANNOUNCED_CONFIG_SEED, CONFIG_CHANGE_SEED},
     error::ErrorCode,
-    state::{AnnouncedConfigAccount, StakePoolAccount},
+    state::{AnnouncedConfigAccount, ConfigChangeAccount, StakePoolAccount},
 };
 use anchor_lang::prelude::*;
 
@@ -13,8 +13,19 @@ pub struct ConfirmUpdateConfig<'info> {
     pub authority: Signer<'info>,
     #[account(mut, close = payer, seeds = [stake_pool.key().as_ref(), ANNOUNCED_CONFIG_SEED], bump)]
     pub announced_config: Account<'info, AnnouncedConfigAccount>,
+    #[account(init, payer = payer,
+        space = ConfigChangeAccount::DISCRIMINATOR.len() + ConfigChangeAccount::INIT_SPACE,
+        seeds = [
+            stake_pool.key().as_ref(),
+            CONFIG_CHANGE_SEED,
+            &(stake_pool.config_version + 1).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub config_change: Account<'info, ConfigChangeAccount>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn process_confirm_update_config(ctx: Context<ConfirmUpdateConfig>) -> Result<()> {
@@ -44,13 +55,24 @@ pub fn process_confirm_update_config(ctx: Context<ConfirmUpdateConfig>) -> Resul
         return Err(ErrorCode::NotReadyYet.into());
     }
 
+    require!(announced_config.is_approved()?, ErrorCode::QuorumNotReached);
+
     stake_pool.announced_config = None;
 
-    let config = &mut stake_pool.config;
-    let new_config = &announced_config.config;
+    for change in announced_config.changes.iter() {
+        stake_pool.config.apply(change)?;
+    }
+    stake_pool.config.validate()?;
+    stake_pool.config_version += 1;
 
-    config.max_stake_amount = new_config.max_stake_amount;
-    config.config_review_time = new_config.config_review_time;
+    let config_change = &mut ctx.accounts.config_change;
+    config_change.stake_pool = stake_pool.key();
+    config_change.config_version = stake_pool.config_version;
+    config_change.authority = announced_config.authority;
+    config_change.announced_at = announced_config.timestamp;
+    config_change.applied_at = now;
+    config_change.changes = announced_config.changes.clone();
+    config_change.rent_payer = ctx.accounts.payer.key();
 
     Ok(())
 }
@@ -0,0 +1,103 @@
+use crate::{
+    constants::{DISTRIBUTOR_SEED, DISTRIBUTOR_VAULT_SEED, POOL_WALLET_SEED},
+    error::ErrorCode,
+    state::{CreateDistributorArgs, DistributorAccount, StakePoolAccount},
+    utils::check_reward_mint,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+#[instruction(args: CreateDistributorArgs)]
+pub struct CreateDistributor<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(init, payer = payer,
+        space = DistributorAccount::space(args.num_nodes),
+        seeds = [stake_pool.key().as_ref(), DISTRIBUTOR_SEED, &args.epoch.to_le_bytes()], bump
+    )]
+    pub distributor: Account<'info, DistributorAccount>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init, payer = payer,
+        token::mint = reward_mint,
+        token::authority = pool_wallet,
+        token::token_program = token_program,
+        seeds = [distributor.key().as_ref(), DISTRIBUTOR_VAULT_SEED],
+        bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_create_distributor(
+    ctx: Context<CreateDistributor>,
+    args: CreateDistributorArgs,
+) -> Result<()> {
+    msg!("create distributor epoch {}", args.epoch);
+
+    require_gt!(args.total_amount, 0, ErrorCode::InvalidAmount);
+    require_gt!(args.num_nodes, 0, ErrorCode::InvalidConfig);
+
+    check_reward_mint(&ctx.accounts.reward_mint.to_account_info())?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+    require_gt!(args.claim_deadline, now, ErrorCode::InvalidConfig);
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.stake_pool = ctx.accounts.stake_pool.key();
+    distributor.epoch = args.epoch;
+    distributor.merkle_root = args.merkle_root;
+    distributor.reward_mint = ctx.accounts.reward_mint.key();
+    distributor.vault = ctx.accounts.vault.key();
+    distributor.total_amount = args.total_amount;
+    distributor.claimed_amount = 0;
+    distributor.num_nodes = args.num_nodes;
+    distributor.claimed_count = 0;
+    distributor.claim_deadline = args.claim_deadline;
+    distributor.claimed_bitmap = vec![0; args.num_nodes.div_ceil(8) as usize];
+
+    ctx.accounts.stake_pool.reward_vault_count += 1;
+
+    // Fund the vault
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        args.total_amount,
+        ctx.accounts.reward_mint.decimals,
+    )?;
+
+    ctx.accounts.vault.reload()?;
+    require_eq!(
+        ctx.accounts.vault.amount,
+        args.total_amount,
+        ErrorCode::InvalidAmount
+    );
+
+    Ok(())
+}
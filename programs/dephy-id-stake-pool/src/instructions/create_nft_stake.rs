@@ -1,7 +1,9 @@
 use crate::{
-    constants::POOL_WALLET_SEED,
+    constants::{ASSET_LOCK_SEED, SLASH_FACTOR_PRECISION},
     error::ErrorCode,
-    state::{CreateNftStakeArgs, NftStakeAccount, StakePoolAccount},
+    state::{
+        AssetLockAccount, AssetStandard, CreateNftStakeArgs, NftStakeAccount, StakePoolAccount,
+    },
 };
 use anchor_lang::prelude::*;
 
@@ -22,8 +24,11 @@ pub struct CreateNftStake<'info> {
     /// CHECK:
     #[account(mut)]
     pub mpl_core_collection: UncheckedAccount<'info>,
-    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
-    pub pool_wallet: SystemAccount<'info>,
+    #[account(init_if_needed, payer = payer,
+        space = AssetLockAccount::DISCRIMINATOR.len() + AssetLockAccount::INIT_SPACE,
+        seeds = [ASSET_LOCK_SEED, mpl_core_asset.key().as_ref()], bump
+    )]
+    pub asset_lock: Account<'info, AssetLockAccount>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -42,40 +47,102 @@ pub fn process_create_nft_stake(
 
     let stake_pool = &mut ctx.accounts.stake_pool;
 
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+
+    require!(
+        stake_pool
+            .config
+            .is_eligible(ctx.accounts.mpl_core_collection.key),
+        ErrorCode::InvalidCollection
+    );
+
+    let asset_info = ctx.accounts.mpl_core_asset.to_account_info();
+    require_keys_eq!(*asset_info.owner, mpl_core::ID, ErrorCode::InvalidAccount);
+
+    // mpl core only checks these when the first pool adds the freeze plugin
+    let asset = mpl_core::accounts::BaseAssetV1::try_from(&asset_info)
+        .map_err(|_| ErrorCode::InvalidAccount)?;
     require_keys_eq!(
-        ctx.accounts.mpl_core_collection.key(),
-        stake_pool.config.collection,
+        asset.owner,
+        ctx.accounts.stake_authority.key(),
+        ErrorCode::InvalidAuthority
+    );
+    require!(
+        asset.update_authority
+            == mpl_core::types::UpdateAuthority::Collection(ctx.accounts.mpl_core_collection.key()),
         ErrorCode::InvalidCollection
     );
 
-    mpl_core::instructions::AddPluginV1Cpi::new(
-        &ctx.accounts.mpl_core_program.to_account_info(),
-        mpl_core::instructions::AddPluginV1CpiAccounts {
-            asset: &ctx.accounts.mpl_core_asset.to_account_info(),
-            collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
-            payer: &ctx.accounts.payer.to_account_info(),
-            authority: Some(&ctx.accounts.stake_authority.to_account_info()),
-            system_program: &ctx.accounts.system_program.to_account_info(),
-            log_wrapper: None,
-        },
-        mpl_core::instructions::AddPluginV1InstructionArgs {
-            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
-                frozen: true,
-            }),
-            init_authority: Some(mpl_core::types::PluginAuthority::Address {
-                address: ctx.accounts.pool_wallet.key(),
-            }),
-        },
-    )
-    .invoke()?;
+    if !stake_pool.config.attribute_rules.is_empty() {
+        // An asset without the Attributes plugin has no attributes to match
+        let attributes = match mpl_core::fetch_asset_plugin::<mpl_core::types::Attributes>(
+            &asset_info,
+            mpl_core::types::PluginType::Attributes,
+        ) {
+            Ok((_, attributes, _)) => attributes.attribute_list,
+            Err(_) => vec![],
+        };
+
+        require!(
+            stake_pool.config.attributes_match(&attributes),
+            ErrorCode::IneligibleAsset
+        );
+    }
+
+    let asset_lock = &mut ctx.accounts.asset_lock;
+    if asset_lock.stake_pools.is_empty() {
+        asset_lock.asset = asset_info.key();
+        asset_lock.rent_payer = ctx.accounts.payer.key();
+
+        mpl_core::instructions::AddPluginV1Cpi::new(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            mpl_core::instructions::AddPluginV1CpiAccounts {
+                asset: &asset_info,
+                collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+                payer: &ctx.accounts.payer.to_account_info(),
+                authority: Some(&ctx.accounts.stake_authority.to_account_info()),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                log_wrapper: None,
+            },
+            mpl_core::instructions::AddPluginV1InstructionArgs {
+                plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                    frozen: true,
+                }),
+                init_authority: Some(mpl_core::types::PluginAuthority::Address {
+                    address: asset_lock.key(),
+                }),
+            },
+        )
+        .invoke()?;
+    }
+    asset_lock.lock(stake_pool.key())?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
 
     let nft_stake = &mut ctx.accounts.nft_stake;
     nft_stake.stake_pool = stake_pool.key();
     nft_stake.stake_authority = ctx.accounts.stake_authority.key();
     nft_stake.deposit_authority = ctx.accounts.deposit_authority.key();
     nft_stake.nft_token_account = ctx.accounts.mpl_core_asset.key();
+    nft_stake.collection = ctx.accounts.mpl_core_collection.key();
     nft_stake.amount = 0;
     nft_stake.commision_rate = args.commision_rate;
+    nft_stake.depositor_count = 0;
+    nft_stake.slash_factor = SLASH_FACTOR_PRECISION;
+    nft_stake.unstaked = false;
+    nft_stake.migrating = false;
+    nft_stake.accumulate_stake(now);
+    nft_stake.rent_payer = ctx.accounts.payer.key();
+    nft_stake.asset_standard = AssetStandard::MplCore;
+
+    nft_stake.total_deposited = 0;
+    nft_stake.total_withdrawn = 0;
+    nft_stake.total_slashed = 0;
+    nft_stake.last_activity_timestamp = now;
+
+    stake_pool.nft_stake_count += 1;
+    stake_pool.last_activity_timestamp = now;
 
     Ok(())
 }
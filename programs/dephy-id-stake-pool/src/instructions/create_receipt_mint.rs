@@ -0,0 +1,97 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, RECEIPT_MINT_SEED},
+    error::ErrorCode,
+    state::StakePoolAccount,
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+use anchor_spl::{
+    token_2022::{
+        initialize_mint2, spl_token_2022::extension::ExtensionType, InitializeMint2, Token2022,
+    },
+    token_interface::{
+        find_mint_account_size, mint_close_authority_initialize, Mint, MintCloseAuthorityInitialize,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CreateReceiptMint<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken)]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: created below as a Token-2022 mint the pool wallet can close
+    #[account(mut, seeds = [stake_pool.key().as_ref(), RECEIPT_MINT_SEED], bump)]
+    pub receipt_mint: UncheckedAccount<'info>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub receipt_token_program: Program<'info, Token2022>,
+}
+
+pub fn process_create_receipt_mint(ctx: Context<CreateReceiptMint>) -> Result<()> {
+    msg!("create receipt mint");
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    require!(
+        stake_pool.receipt_mint.is_none(),
+        ErrorCode::ReceiptMintExists
+    );
+
+    let receipt_mint = &ctx.accounts.receipt_mint;
+    let token_program = &ctx.accounts.receipt_token_program;
+
+    let space = find_mint_account_size(Some(&vec![ExtensionType::MintCloseAuthority]))?;
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: receipt_mint.to_account_info(),
+            },
+            &[&[
+                stake_pool_key.as_ref(),
+                RECEIPT_MINT_SEED,
+                &[ctx.bumps.receipt_mint],
+            ]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+
+    mint_close_authority_initialize(
+        CpiContext::new(
+            token_program.to_account_info(),
+            MintCloseAuthorityInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: receipt_mint.to_account_info(),
+            },
+        ),
+        Some(ctx.accounts.pool_wallet.key),
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: receipt_mint.to_account_info(),
+            },
+        ),
+        ctx.accounts.stake_token_mint.decimals,
+        ctx.accounts.pool_wallet.key,
+        None,
+    )?;
+
+    stake_pool.receipt_mint = Some(receipt_mint.key());
+
+    Ok(())
+}
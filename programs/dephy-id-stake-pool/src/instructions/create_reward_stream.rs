@@ -0,0 +1,129 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, REWARD_STREAM_SEED, REWARD_VAULT_SEED},
+    error::ErrorCode,
+    state::{CreateRewardStreamArgs, RewardStreamAccount, StakePoolAccount},
+    utils::check_reward_mint,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct CreateRewardStream<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(init, payer = payer,
+        space = RewardStreamAccount::DISCRIMINATOR.len() + RewardStreamAccount::INIT_SPACE,
+        seeds = [
+            stake_pool.key().as_ref(),
+            REWARD_STREAM_SEED,
+            &stake_pool.next_reward_stream_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub reward_stream: Account<'info, RewardStreamAccount>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init, payer = payer,
+        token::mint = reward_mint,
+        token::authority = pool_wallet,
+        token::token_program = token_program,
+        seeds = [reward_stream.key().as_ref(), REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_create_reward_stream(
+    ctx: Context<CreateRewardStream>,
+    args: CreateRewardStreamArgs,
+) -> Result<()> {
+    msg!("create reward stream");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    require_gt!(args.emission_rate, 0, ErrorCode::InvalidRewardSchedule);
+    require_gte!(args.start_time, now, ErrorCode::InvalidRewardSchedule);
+    require_gt!(
+        args.end_time,
+        args.start_time,
+        ErrorCode::InvalidRewardSchedule
+    );
+
+    check_reward_mint(&ctx.accounts.reward_mint.to_account_info())?;
+
+    let total_rewards = args
+        .emission_rate
+        .checked_mul(args.end_time - args.start_time)
+        .ok_or(ErrorCode::InvalidRewardSchedule)?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+
+    let index = stake_pool
+        .reward_streams
+        .iter()
+        .position(|reward_stream| reward_stream.is_none())
+        .ok_or(ErrorCode::RewardStreamsFull)?;
+
+    let reward_stream = &mut ctx.accounts.reward_stream;
+    reward_stream.stake_pool = stake_pool.key();
+    reward_stream.id = stake_pool.next_reward_stream_id;
+    reward_stream.index = index as u8;
+    reward_stream.reward_mint = ctx.accounts.reward_mint.key();
+    reward_stream.vault = ctx.accounts.vault.key();
+    reward_stream.emission_rate = args.emission_rate;
+    reward_stream.start_time = args.start_time;
+    reward_stream.end_time = args.end_time;
+    reward_stream.last_update_time = args.start_time;
+    reward_stream.reward_per_token = 0;
+    reward_stream.distributed_amount = 0;
+    reward_stream.claimed_amount = 0;
+
+    stake_pool.reward_streams[index] = Some(reward_stream.key());
+    stake_pool.next_reward_stream_id += 1;
+    stake_pool.reward_vault_count += 1;
+
+    // Fund the whole schedule
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        total_rewards,
+        ctx.accounts.reward_mint.decimals,
+    )?;
+
+    ctx.accounts.vault.reload()?;
+    require_eq!(
+        ctx.accounts.vault.amount,
+        total_rewards,
+        ErrorCode::InvalidAmount
+    );
+
+    Ok(())
+}
@@ -1,7 +1,12 @@
 use crate::{
-    constants::{ADMIN_SEED, POOL_WALLET_SEED, STAKE_TOKEN_SEED},
+    constants::{
+        ADMIN_SEED, MAX_EXTRA_COLLECTIONS, MAX_REWARD_STREAMS, POOL_WALLET_SEED, STAKE_TOKEN_SEED,
+    },
     error::ErrorCode,
-    state::{AdminAccount, StakePoolAccount, StakePoolConfig, StakePoolConfigArgs},
+    state::{
+        AdminAccount, StakeActivation, StakePoolAccount, StakePoolConfig, StakePoolConfigArgs,
+    },
+    utils::check_stake_token_mint,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -43,20 +48,51 @@ pub fn process_create_stake_pool(
 ) -> Result<()> {
     msg!("create stake pool");
 
-    require_gt!(args.config_review_time, 0, ErrorCode::InvalidConfig);
-    require_gt!(args.max_stake_amount, 0, ErrorCode::InvalidConfig);
+    check_stake_token_mint(&ctx.accounts.stake_token_mint.to_account_info())?;
 
     let stake_pool = &mut ctx.accounts.stake_pool;
     stake_pool.authority = ctx.accounts.stake_pool_authority.key();
     stake_pool.stake_token_account = ctx.accounts.stake_token_account.key();
     stake_pool.total_amount = 0;
     stake_pool.announced_config = None;
+    stake_pool.receipt_mint = None;
+    stake_pool.sunset = false;
+    stake_pool.nft_stake_count = 0;
+    stake_pool.reward_vault_count = 0;
+    stake_pool.reward_streams = [None; MAX_REWARD_STREAMS];
+    stake_pool.next_reward_stream_id = 1;
+    stake_pool.config_version = 0;
+    stake_pool.pool_fees = 0;
+    stake_pool.protocol_fees = 0;
+    stake_pool.activation = StakeActivation::default();
+    stake_pool.depositor_count = 0;
+    stake_pool.total_deposited = 0;
+    stake_pool.total_withdrawn = 0;
+    stake_pool.total_slashed = 0;
+    stake_pool.last_activity_timestamp = 0;
+    stake_pool.announced_at = 0;
     stake_pool.config = StakePoolConfig {
         collection: ctx.accounts.collection.key(),
         stake_token_mint: ctx.accounts.stake_token_mint.key(),
         max_stake_amount: args.max_stake_amount,
         config_review_time: args.config_review_time,
+        slasher: args.slasher,
+        score_oracle: args.score_oracle,
+        max_score_age: args.max_score_age,
+        max_total_amount: args.max_total_amount,
+        max_per_user: args.max_per_user,
+        min_deposit_amount: args.min_deposit_amount,
+        deposit_fee_rate: args.deposit_fee_rate,
+        withdraw_fee_rate: args.withdraw_fee_rate,
+        reward_fee_rate: args.reward_fee_rate,
+        early_exit_penalty_rate: args.early_exit_penalty_rate,
+        early_exit_period: args.early_exit_period,
+        epoch_duration: args.epoch_duration,
+        extra_collections: [None; MAX_EXTRA_COLLECTIONS],
+        attribute_rules: vec![],
+        governance_quorum_rate: args.governance_quorum_rate,
     };
+    stake_pool.config.validate()?;
 
     Ok(())
 }
@@ -0,0 +1,203 @@
+use crate::{
+    constants::{NFT_ESCROW_SEED, POOL_WALLET_SEED, SLASH_FACTOR_PRECISION},
+    error::ErrorCode,
+    state::{AssetStandard, CreateTokenNftStakeArgs, NftStakeAccount, StakePoolAccount},
+    utils::check_stake_token_mint,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::mpl_token_metadata::{
+        accounts::{MasterEdition, Metadata},
+        types::TokenStandard,
+    },
+    token_2022::spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+    },
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use spl_token_group_interface::state::TokenGroupMember;
+
+#[derive(Accounts)]
+pub struct CreateTokenNftStake<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(init, payer = payer,
+        space = NftStakeAccount::DISCRIMINATOR.len() + NftStakeAccount::INIT_SPACE
+    )]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    pub stake_authority: Signer<'info>,
+    /// CHECK:
+    pub deposit_authority: UncheckedAccount<'info>,
+    #[account(mint::token_program = nft_token_program)]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = stake_authority,
+        token::token_program = nft_token_program
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Token Metadata account of the mint, checked in the handler
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: collection mint or token group, checked against the nft in the handler
+    pub collection: UncheckedAccount<'info>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(
+        init, payer = payer,
+        token::mint = nft_mint,
+        token::authority = pool_wallet,
+        token::token_program = nft_token_program,
+        seeds = [nft_stake.key().as_ref(), NFT_ESCROW_SEED],
+        bump,
+    )]
+    pub nft_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Stake an SPL Token or Token-2022 nft, which is escrowed by the pool wallet until unstaked
+pub fn process_create_token_nft_stake(
+    ctx: Context<CreateTokenNftStake>,
+    args: CreateTokenNftStakeArgs,
+) -> Result<()> {
+    msg!("create token nft stake");
+
+    require_gte!(100, args.commision_rate, ErrorCode::InvalidCommisionRate);
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+
+    let collection_key = ctx.accounts.collection.key();
+    require!(
+        stake_pool.config.is_eligible(&collection_key),
+        ErrorCode::InvalidCollection
+    );
+
+    // token nfts carry no Attributes plugin to match
+    require!(
+        stake_pool.config.attributes_match(&[]),
+        ErrorCode::IneligibleAsset
+    );
+
+    // a live mint authority could mint the nft again and stake the device twice
+    let nft_mint = &ctx.accounts.nft_mint;
+    require!(
+        nft_mint.decimals == 0 && nft_mint.supply == 1,
+        ErrorCode::UnsupportedAsset
+    );
+
+    let mint_info = nft_mint.to_account_info();
+    check_stake_token_mint(&mint_info)?;
+
+    match args.asset_standard {
+        AssetStandard::MplCore => return Err(ErrorCode::UnsupportedAsset.into()),
+        AssetStandard::TokenMetadata => {
+            let Some(metadata_info) = &ctx.accounts.nft_metadata else {
+                return Err(ErrorCode::InvalidAccount.into());
+            };
+            require_keys_eq!(
+                metadata_info.key(),
+                Metadata::find_pda(&nft_mint.key()).0,
+                ErrorCode::InvalidAccount
+            );
+            require_keys_eq!(
+                *metadata_info.owner,
+                anchor_spl::metadata::ID,
+                ErrorCode::InvalidAccount
+            );
+
+            let metadata = Metadata::safe_deserialize(&metadata_info.data.borrow())
+                .map_err(|_| ErrorCode::InvalidAccount)?;
+            // programmable nfts stay frozen in their owner's token account
+            require!(
+                !matches!(
+                    metadata.token_standard,
+                    Some(TokenStandard::ProgrammableNonFungible)
+                        | Some(TokenStandard::ProgrammableNonFungibleEdition)
+                ),
+                ErrorCode::UnsupportedAsset
+            );
+            require!(
+                metadata.collection.is_some_and(
+                    |collection| collection.verified && collection.key == collection_key
+                ),
+                ErrorCode::InvalidCollection
+            );
+            // the master edition only prints editions into other mints
+            let (master_edition, _) = MasterEdition::find_pda(&nft_mint.key());
+            require!(
+                Option::<Pubkey>::from(nft_mint.mint_authority)
+                    .is_none_or(|authority| authority == master_edition),
+                ErrorCode::UnsupportedAsset
+            );
+        }
+        AssetStandard::Token2022 => {
+            require_keys_eq!(
+                *mint_info.owner,
+                spl_token_2022::ID,
+                ErrorCode::UnsupportedAsset
+            );
+
+            // members are added by the group's update authority
+            let data = mint_info.try_borrow_data()?;
+            let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+            let member = mint
+                .get_extension::<TokenGroupMember>()
+                .map_err(|_| ErrorCode::InvalidCollection)?;
+            require_keys_eq!(member.mint, nft_mint.key(), ErrorCode::InvalidCollection);
+            require_keys_eq!(member.group, collection_key, ErrorCode::InvalidCollection);
+            require!(
+                nft_mint.mint_authority.is_none(),
+                ErrorCode::UnsupportedAsset
+            );
+        }
+    }
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.nft_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                mint: mint_info,
+                to: ctx.accounts.nft_escrow.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let nft_stake = &mut ctx.accounts.nft_stake;
+    nft_stake.stake_pool = stake_pool.key();
+    nft_stake.stake_authority = ctx.accounts.stake_authority.key();
+    nft_stake.deposit_authority = ctx.accounts.deposit_authority.key();
+    nft_stake.nft_token_account = ctx.accounts.nft_mint.key();
+    nft_stake.collection = collection_key;
+    nft_stake.amount = 0;
+    nft_stake.commision_rate = args.commision_rate;
+    nft_stake.depositor_count = 0;
+    nft_stake.slash_factor = SLASH_FACTOR_PRECISION;
+    nft_stake.unstaked = false;
+    nft_stake.migrating = false;
+    nft_stake.accumulate_stake(now);
+    nft_stake.rent_payer = ctx.accounts.payer.key();
+    nft_stake.asset_standard = args.asset_standard;
+
+    nft_stake.total_deposited = 0;
+    nft_stake.total_withdrawn = 0;
+    nft_stake.total_slashed = 0;
+    nft_stake.last_activity_timestamp = now;
+
+    stake_pool.nft_stake_count += 1;
+    stake_pool.last_activity_timestamp = now;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::{
+    constants::USER_STAKE_SEED,
+    error::ErrorCode,
+    state::{NftStakeAccount, StakePoolAccount, UserStakeAccount},
+    utils::update_rewards,
+};
+use anchor_lang::prelude::*;
+
+/// Remaining accounts: every reward stream of the pool (mut)
+#[derive(Accounts)]
+pub struct DeactivateStake<'info> {
+    #[account(mut, address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(mut, address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+}
+
+pub fn process_deactivate_stake(
+    ctx: Context<DeactivateStake>,
+    maybe_amount: Option<u64>,
+) -> Result<()> {
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let nft_stake = &mut ctx.accounts.nft_stake;
+    let user_stake = &mut ctx.accounts.user_stake_account;
+
+    require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    user_stake.accumulate_stake(now);
+    nft_stake.accumulate_stake(now);
+    user_stake.settle_slash(nft_stake);
+
+    update_rewards(
+        stake_pool,
+        &mut [&mut **user_stake],
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    user_stake.activation.roll(now);
+    nft_stake.activation.roll(now);
+    stake_pool.activation.roll(now);
+
+    // pending stake can be withdrawn as is, deactivating stake is not counted twice
+    let activation = &user_stake.activation;
+    let active_amount = activation
+        .active_before(user_stake.amount)
+        .saturating_sub(activation.deactivating_amount);
+    let amount = maybe_amount.unwrap_or(active_amount);
+
+    msg!("deactivate stake {}", amount);
+
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    require_gte!(active_amount, amount, ErrorCode::InvalidAmount);
+
+    // the stake keeps earning until the next epoch starts
+    let deactivation_time = stake_pool.config.activation_time(now);
+    user_stake.activation.deactivate(amount, deactivation_time);
+    nft_stake.activation.deactivate(amount, deactivation_time);
+    stake_pool.activation.deactivate(amount, deactivation_time);
+
+    nft_stake.last_activity_timestamp = now;
+    stake_pool.last_activity_timestamp = now;
+
+    Ok(())
+}
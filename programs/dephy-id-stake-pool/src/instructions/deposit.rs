@@ -1,15 +1,24 @@
 use crate::{
-    constants::{POOL_WALLET_SEED, USER_STAKE_SEED},
+    constants::{ADMIN_SEED, POOL_WALLET_SEED, USER_POOL_STAKE_SEED, USER_STAKE_SEED},
     error::ErrorCode,
-    state::{NftStakeAccount, StakePoolAccount, UserStakeAccount},
+    state::{
+        AdminAccount, FeeAccount, NftStakeAccount, StakePoolAccount, UserPoolStakeAccount,
+        UserStakeAccount,
+    },
+    utils::update_rewards,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{
+        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
 };
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
     #[account(mut)]
     pub nft_stake: Account<'info, NftStakeAccount>,
     #[account(mut, address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
@@ -22,6 +31,12 @@ pub struct Deposit<'info> {
         seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump
     )]
     pub user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = UserPoolStakeAccount::DISCRIMINATOR.len() + UserPoolStakeAccount::INIT_SPACE,
+        seeds = [stake_pool.key().as_ref(), USER_POOL_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub user_pool_stake_account: Account<'info, UserPoolStakeAccount>,
     #[account(address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken)]
     pub stake_token_mint: InterfaceAccount<'info, Mint>,
     #[account(mut, address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken)]
@@ -39,6 +54,12 @@ pub struct Deposit<'info> {
     pub payer: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    /// Required when the pool has a receipt mint
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_receipt_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
 }
 
 pub fn process_deposit(ctx: Context<Deposit>, maybe_amount: Option<u64>) -> Result<()> {
@@ -49,10 +70,94 @@ pub fn process_deposit(ctx: Context<Deposit>, maybe_amount: Option<u64>) -> Resu
 
     msg!("deposit {}", amount);
 
-    let stake_pool = &mut ctx.accounts.stake_pool;
+    let user = ctx.accounts.user.key();
+    let accounts = ctx.accounts;
+    deposit(
+        DepositAccounts {
+            admin: &accounts.admin,
+            nft_stake: &mut accounts.nft_stake,
+            stake_pool: &mut accounts.stake_pool,
+            user_stake: &mut accounts.user_stake_account,
+            user_pool_stake: &mut accounts.user_pool_stake_account,
+            beneficiary: user,
+            funder: accounts.user.to_account_info(),
+            rent_payer: accounts.payer.key(),
+            stake_token_mint: &accounts.stake_token_mint,
+            stake_token_account: &mut accounts.stake_token_account,
+            funder_stake_token_account: accounts.user_stake_token_account.to_account_info(),
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            token_program: accounts.token_program.to_account_info(),
+            receipt_mint: accounts.receipt_mint.as_ref(),
+            user_receipt_token_account: accounts.user_receipt_token_account.as_ref(),
+            receipt_token_program: accounts.receipt_token_program.as_ref(),
+        },
+        amount,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )
+}
+
+/// Accounts shared by deposit and deposit_for, the funder's tokens are credited to the beneficiary
+pub(crate) struct DepositAccounts<'a, 'info> {
+    pub admin: &'a AdminAccount,
+    pub nft_stake: &'a mut Account<'info, NftStakeAccount>,
+    pub stake_pool: &'a mut Account<'info, StakePoolAccount>,
+    pub user_stake: &'a mut Account<'info, UserStakeAccount>,
+    pub user_pool_stake: &'a mut Account<'info, UserPoolStakeAccount>,
+    pub beneficiary: Pubkey,
+    pub funder: AccountInfo<'info>,
+    pub rent_payer: Pubkey,
+    pub stake_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub stake_token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub funder_stake_token_account: AccountInfo<'info>,
+    pub pool_wallet: AccountInfo<'info>,
+    pub pool_wallet_bump: u8,
+    pub token_program: AccountInfo<'info>,
+    pub receipt_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub user_receipt_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub receipt_token_program: Option<&'a Program<'info, Token2022>>,
+}
+
+pub(crate) fn deposit(
+    accounts: DepositAccounts,
+    amount: u64,
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+
+    let third_party = accounts.funder.key() != accounts.beneficiary;
+    let vault_amount_before = accounts.stake_token_account.amount;
+
+    // Transfer tokens
+    transfer_checked(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            TransferChecked {
+                from: accounts.funder_stake_token_account,
+                mint: accounts.stake_token_mint.to_account_info(),
+                to: accounts.stake_token_account.to_account_info(),
+                authority: accounts.funder,
+            },
+        ),
+        amount,
+        accounts.stake_token_mint.decimals,
+    )?;
+
+    // a transfer fee makes the vault receive less than amount
+    accounts.stake_token_account.reload()?;
+    let amount = accounts.stake_token_account.amount - vault_amount_before;
+
+    msg!("received {}", amount);
+
+    let stake_pool = accounts.stake_pool;
+    let deposit_fee_rate = stake_pool.config.deposit_fee_rate;
+    let amount = stake_pool.collect_fee(accounts.admin, amount, deposit_fee_rate)?;
     let config = &stake_pool.config;
-    let nft_stake = &mut ctx.accounts.nft_stake;
-    let user_stake = &mut ctx.accounts.user_stake_account;
+    let nft_stake = accounts.nft_stake;
+    let user_stake = accounts.user_stake;
+    let user_pool_stake = accounts.user_pool_stake;
 
     // no longer check deposit authority
     // if nft_stake.deposit_authority.is_some() {
@@ -63,41 +168,132 @@ pub fn process_deposit(ctx: Context<Deposit>, maybe_amount: Option<u64>) -> Resu
     //     }
     // }
 
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
     require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    require_gte!(amount, config.min_deposit_amount, ErrorCode::InvalidAmount);
+    require!(
+        !nft_stake.unstaked && nft_stake.slash_factor > 0,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+    let max_stake_amount = config
+        .max_stake_amount_for(&nft_stake.collection)
+        .ok_or(ErrorCode::InvalidCollection)?;
     require_gte!(
-        config.max_stake_amount,
+        max_stake_amount,
         nft_stake.amount + amount,
         ErrorCode::InvalidAmount
     );
+    if let Some(max_total_amount) = config.max_total_amount {
+        require_gte!(
+            max_total_amount,
+            stake_pool.total_amount + amount,
+            ErrorCode::InvalidAmount
+        );
+    }
+    if let Some(max_per_user) = config.max_per_user {
+        require_gte!(
+            max_per_user,
+            user_pool_stake.amount + amount,
+            ErrorCode::InvalidAmount
+        );
+    }
 
     let clock = Clock::get()?;
     let now = clock.unix_timestamp as u64;
 
-    user_stake.stake_pool = stake_pool.key();
-    user_stake.nft_stake = nft_stake.key();
-    user_stake.user = ctx.accounts.user.key();
+    user_stake.accumulate_stake(now);
+    nft_stake.accumulate_stake(now);
+
+    if user_stake.stake_pool == Pubkey::default() {
+        user_stake.stake_pool = stake_pool.key();
+        user_stake.nft_stake = nft_stake.key();
+        user_stake.user = accounts.beneficiary;
+        user_stake.slash_factor = nft_stake.slash_factor;
+        user_stake.rent_payer = accounts.rent_payer;
+
+        nft_stake.depositor_count += 1;
+    } else {
+        user_stake.settle_slash(nft_stake);
+    }
+    user_stake.snapshot_vote(stake_pool);
+
+    update_rewards(
+        stake_pool,
+        &mut [&mut **user_stake],
+        remaining_accounts,
+        program_id,
+        now,
+    )?;
+
+    if user_pool_stake.stake_pool == Pubkey::default() {
+        user_pool_stake.stake_pool = stake_pool.key();
+        user_pool_stake.user = accounts.beneficiary;
+        user_pool_stake.rent_payer = accounts.rent_payer;
+
+        stake_pool.depositor_count += 1;
+    }
+    user_pool_stake.amount += amount;
+
+    // the deposit earns and counts from the next epoch
+    let activation_time = stake_pool.config.activation_time(now);
+    user_stake.activation.roll(now);
+    user_stake.activation.add(amount, activation_time);
+    nft_stake.activation.roll(now);
+    nft_stake.activation.add(amount, activation_time);
+    stake_pool.activation.roll(now);
+    stake_pool.activation.add(amount, activation_time);
 
     user_stake.amount += amount;
-    user_stake.last_deposit_timestamp = now;
+    user_stake.principal += amount;
+    // the timestamp follows the beneficiary's own deposits, a third party must not restart it
+    if !third_party {
+        user_stake.last_deposit_timestamp = now;
+    }
 
     nft_stake.amount += amount;
+    nft_stake.total_deposited += amount;
+    nft_stake.last_activity_timestamp = now;
 
     stake_pool.total_amount += amount;
+    stake_pool.total_deposited += amount;
+    stake_pool.last_activity_timestamp = now;
 
-    // Transfer tokens
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.user_stake_token_account.to_account_info(),
-                mint: ctx.accounts.stake_token_mint.to_account_info(),
-                to: ctx.accounts.stake_token_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        amount,
-        ctx.accounts.stake_token_mint.decimals,
-    )?;
+    // Mint receipt tokens 1:1
+    if let Some(receipt_mint_key) = stake_pool.receipt_mint {
+        let (Some(receipt_mint), Some(user_receipt_token_account), Some(receipt_token_program)) = (
+            accounts.receipt_mint,
+            accounts.user_receipt_token_account,
+            accounts.receipt_token_program,
+        ) else {
+            return Err(ErrorCode::ReceiptAccountsRequired.into());
+        };
+
+        require_keys_eq!(
+            receipt_mint.key(),
+            receipt_mint_key,
+            ErrorCode::InvalidAccount
+        );
+
+        mint_to(
+            CpiContext::new_with_signer(
+                receipt_token_program.to_account_info(),
+                MintTo {
+                    mint: receipt_mint.to_account_info(),
+                    to: user_receipt_token_account.to_account_info(),
+                    authority: accounts.pool_wallet,
+                },
+                &[&[
+                    stake_pool.key().as_ref(),
+                    POOL_WALLET_SEED,
+                    &[accounts.pool_wallet_bump],
+                ]],
+            ),
+            amount,
+        )?;
+
+        user_stake.receipt_amount += amount;
+    }
 
     Ok(())
 }
@@ -0,0 +1,98 @@
+use crate::{
+    constants::{ADMIN_SEED, POOL_WALLET_SEED, USER_POOL_STAKE_SEED, USER_STAKE_SEED},
+    error::ErrorCode,
+    instructions::deposit::{deposit, DepositAccounts},
+    state::{
+        AdminAccount, NftStakeAccount, StakePoolAccount, UserPoolStakeAccount, UserStakeAccount,
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct DepositFor<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(mut)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(mut, address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    pub funder: Signer<'info>,
+    /// CHECK: only owns the position, withdrawals need its signature
+    pub beneficiary: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = UserStakeAccount::DISCRIMINATOR.len() + UserStakeAccount::INIT_SPACE,
+        seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, beneficiary.key.as_ref()], bump
+    )]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = UserPoolStakeAccount::DISCRIMINATOR.len() + UserPoolStakeAccount::INIT_SPACE,
+        seeds = [stake_pool.key().as_ref(), USER_POOL_STAKE_SEED, beneficiary.key.as_ref()], bump
+    )]
+    pub user_pool_stake_account: Account<'info, UserPoolStakeAccount>,
+    #[account(address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken)]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken)]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = stake_token_mint,
+        token::authority = funder,
+        token::token_program = token_program
+    )]
+    pub funder_stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    /// Required when the pool has a receipt mint
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, Mint>>,
+    /// Receipts go to the beneficiary, who burns them on withdraw
+    #[account(mut, token::authority = beneficiary)]
+    pub beneficiary_receipt_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+}
+
+pub fn process_deposit_for(ctx: Context<DepositFor>, maybe_amount: Option<u64>) -> Result<()> {
+    let amount = match maybe_amount {
+        Some(amount) => amount,
+        None => ctx.accounts.funder_stake_token_account.amount,
+    };
+
+    msg!("deposit {} for {}", amount, ctx.accounts.beneficiary.key());
+
+    let beneficiary = ctx.accounts.beneficiary.key();
+    let accounts = ctx.accounts;
+    deposit(
+        DepositAccounts {
+            admin: &accounts.admin,
+            nft_stake: &mut accounts.nft_stake,
+            stake_pool: &mut accounts.stake_pool,
+            user_stake: &mut accounts.user_stake_account,
+            user_pool_stake: &mut accounts.user_pool_stake_account,
+            beneficiary,
+            funder: accounts.funder.to_account_info(),
+            rent_payer: accounts.payer.key(),
+            stake_token_mint: &accounts.stake_token_mint,
+            stake_token_account: &mut accounts.stake_token_account,
+            funder_stake_token_account: accounts.funder_stake_token_account.to_account_info(),
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            token_program: accounts.token_program.to_account_info(),
+            receipt_mint: accounts.receipt_mint.as_ref(),
+            user_receipt_token_account: accounts.beneficiary_receipt_token_account.as_ref(),
+            receipt_token_program: accounts.receipt_token_program.as_ref(),
+        },
+        amount,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )
+}
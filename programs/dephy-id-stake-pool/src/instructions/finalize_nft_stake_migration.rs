@@ -0,0 +1,38 @@
+use crate::{
+    constants::ADMIN_SEED,
+    error::ErrorCode,
+    state::{AdminAccount, NftStakeAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FinalizeNftStakeMigration<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = admin.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+}
+
+/// Unfreeze a migrated nft stake once the migrated user stakes add up to its amount
+pub fn process_finalize_nft_stake_migration(ctx: Context<FinalizeNftStakeMigration>) -> Result<()> {
+    msg!("finalize nft stake migration");
+
+    let nft_stake = &mut ctx.accounts.nft_stake;
+
+    require!(nft_stake.migrating, ErrorCode::AlreadyMigrated);
+    require_eq!(
+        nft_stake.total_deposited,
+        nft_stake.amount,
+        ErrorCode::InvalidAmount
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    nft_stake.migrating = false;
+    nft_stake.last_activity_timestamp = now;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use crate::{
+    constants::{ASSET_LOCK_SEED, POOL_WALLET_SEED},
+    error::ErrorCode,
+    state::{AssetLockAccount, AssetStandard, NftStakeAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ForceUnstakeNft<'info> {
+    #[account(mut)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(mut, address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    /// CHECK:
+    #[account(mut, address = nft_stake.collection @ ErrorCode::InvalidCollection)]
+    pub mpl_core_collection: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(mut, address = nft_stake.nft_token_account @ ErrorCode::InvalidAccount)]
+    pub mpl_core_asset: UncheckedAccount<'info>,
+    /// Omitted for assets frozen by the pool wallet before asset locks existed
+    #[account(mut, seeds = [ASSET_LOCK_SEED, mpl_core_asset.key().as_ref()], bump)]
+    pub asset_lock: Option<Account<'info, AssetLockAccount>>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: gets the asset lock rent back once the asset thaws, checked in the handler
+    #[account(mut)]
+    pub asset_lock_rent_payer: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK:
+    #[account(address = mpl_core::ID @ ErrorCode::InvalidMplCoreProgram)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Hand the asset back on a sunset pool, so an owner who never unstakes can not keep
+/// the pool from closing
+pub fn process_force_unstake_nft(ctx: Context<ForceUnstakeNft>) -> Result<()> {
+    msg!("force unstake nft");
+
+    require!(
+        ctx.accounts.stake_pool.sunset,
+        ErrorCode::StakePoolNotSunset
+    );
+    require!(
+        !ctx.accounts.nft_stake.unstaked,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(
+        !ctx.accounts.nft_stake.migrating,
+        ErrorCode::NftStakeMigrating
+    );
+    require!(
+        ctx.accounts.nft_stake.asset_standard == AssetStandard::MplCore,
+        ErrorCode::UnsupportedAsset
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let asset_key = ctx.accounts.mpl_core_asset.key();
+
+    // other pools still hold the asset
+    let mut thaw = true;
+    if let Some(asset_lock) = ctx.accounts.asset_lock.as_mut() {
+        asset_lock.release(&stake_pool_key)?;
+        thaw = asset_lock.stake_pools.is_empty();
+    }
+
+    if thaw {
+        let (freeze_authority, signer_seeds) = match &ctx.accounts.asset_lock {
+            Some(asset_lock) => (
+                asset_lock.to_account_info(),
+                [
+                    ASSET_LOCK_SEED,
+                    asset_key.as_ref(),
+                    &[ctx.bumps.asset_lock.ok_or(ErrorCode::InvalidAccount)?],
+                ],
+            ),
+            None => {
+                let (authority, _, _) =
+                    mpl_core::fetch_asset_plugin::<mpl_core::types::FreezeDelegate>(
+                        &ctx.accounts.mpl_core_asset.to_account_info(),
+                        mpl_core::types::PluginType::FreezeDelegate,
+                    )
+                    .map_err(|_| ErrorCode::InvalidAccount)?;
+                require!(
+                    authority
+                        == mpl_core::types::PluginAuthority::Address {
+                            address: ctx.accounts.pool_wallet.key(),
+                        },
+                    ErrorCode::InvalidAccount
+                );
+
+                (
+                    ctx.accounts.pool_wallet.to_account_info(),
+                    [
+                        stake_pool_key.as_ref(),
+                        POOL_WALLET_SEED,
+                        &[ctx.bumps.pool_wallet],
+                    ],
+                )
+            }
+        };
+
+        // only thaw, removing the plugin needs the owner who can still do it at will
+        mpl_core::instructions::UpdatePluginV1Cpi::new(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            mpl_core::instructions::UpdatePluginV1CpiAccounts {
+                asset: &ctx.accounts.mpl_core_asset.to_account_info(),
+                collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+                authority: Some(&freeze_authority),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                payer: &ctx.accounts.payer.to_account_info(),
+                log_wrapper: None,
+            },
+            mpl_core::instructions::UpdatePluginV1InstructionArgs {
+                plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                    frozen: false,
+                }),
+            },
+        )
+        .invoke_signed(&[&signer_seeds])?;
+
+        if let Some(asset_lock) = &ctx.accounts.asset_lock {
+            let Some(asset_lock_rent_payer) = &ctx.accounts.asset_lock_rent_payer else {
+                return Err(ErrorCode::InvalidAccount.into());
+            };
+            require_keys_eq!(
+                asset_lock_rent_payer.key(),
+                asset_lock.rent_payer,
+                ErrorCode::InvalidAccount
+            );
+            asset_lock.close(asset_lock_rent_payer.to_account_info())?;
+        }
+    }
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    ctx.accounts.stake_pool.nft_stake_count -= 1;
+    ctx.accounts.stake_pool.last_activity_timestamp = now;
+
+    // the owner takes the rent back with close_nft_stake once the depositors withdrew
+    ctx.accounts.nft_stake.unstaked = true;
+    ctx.accounts.nft_stake.last_activity_timestamp = now;
+
+    Ok(())
+}
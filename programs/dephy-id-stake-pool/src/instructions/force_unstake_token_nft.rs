@@ -0,0 +1,103 @@
+use crate::{
+    constants::{NFT_ESCROW_SEED, POOL_WALLET_SEED},
+    error::ErrorCode,
+    state::{AssetStandard, NftStakeAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ForceUnstakeTokenNft<'info> {
+    #[account(mut)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(mut, address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(
+        address = nft_stake.nft_token_account @ ErrorCode::InvalidAccount,
+        mint::token_program = nft_token_program
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), NFT_ESCROW_SEED], bump)]
+    pub nft_escrow: InterfaceAccount<'info, TokenAccount>,
+    /// Any token account of the owner
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = nft_stake.stake_authority,
+        token::token_program = nft_token_program
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    /// CHECK: gets the escrow rent back
+    #[account(mut, address = nft_stake.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+    pub nft_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Send an escrowed token nft back to its owner on a sunset pool
+pub fn process_force_unstake_token_nft(ctx: Context<ForceUnstakeTokenNft>) -> Result<()> {
+    msg!("force unstake token nft");
+
+    require!(
+        ctx.accounts.stake_pool.sunset,
+        ErrorCode::StakePoolNotSunset
+    );
+    require!(
+        !ctx.accounts.nft_stake.unstaked,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(
+        ctx.accounts.nft_stake.asset_standard != AssetStandard::MplCore,
+        ErrorCode::UnsupportedAsset
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[ctx.bumps.pool_wallet],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.nft_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.nft_escrow.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.nft_escrow.amount,
+        ctx.accounts.nft_mint.decimals,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.nft_token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.nft_escrow.to_account_info(),
+            destination: ctx.accounts.rent_payer.to_account_info(),
+            authority: ctx.accounts.pool_wallet.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    ctx.accounts.stake_pool.nft_stake_count -= 1;
+    ctx.accounts.stake_pool.last_activity_timestamp = now;
+
+    // the owner takes the rent back with close_nft_stake once the depositors withdrew
+    ctx.accounts.nft_stake.unstaked = true;
+    ctx.accounts.nft_stake.last_activity_timestamp = now;
+
+    Ok(())
+}
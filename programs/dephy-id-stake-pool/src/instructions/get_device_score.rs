@@ -0,0 +1,25 @@
+use crate::{
+    error::ErrorCode,
+    state::{DeviceScoreAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetDeviceScore<'info> {
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub device_score: Account<'info, DeviceScoreAccount>,
+}
+
+/// View used by allocation logic, rejects scores past the pool's max_score_age
+pub fn process_get_device_score(ctx: Context<GetDeviceScore>) -> Result<DeviceScoreAccount> {
+    msg!("get device score");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let device_score = &ctx.accounts.device_score;
+    device_score.require_fresh(&ctx.accounts.stake_pool.config, now)?;
+
+    Ok((**device_score).clone())
+}
@@ -0,0 +1,50 @@
+use crate::{
+    constants::SECONDS_PER_DAY,
+    error::ErrorCode,
+    state::{NftStakeAccount, StakeAverage, UserStakeAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetStakeAverage<'info> {
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(has_one = nft_stake @ ErrorCode::InvalidAccount)]
+    pub user_stake_account: Option<Account<'info, UserStakeAccount>>,
+}
+
+pub fn process_get_stake_average(ctx: Context<GetStakeAverage>, days: u8) -> Result<StakeAverage> {
+    msg!("get stake average {} days", days);
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // bring copies up to now, so that a boundary crossed since the last update is accounted
+    let mut nft_stake = (*ctx.accounts.nft_stake).clone();
+    nft_stake.accumulate_stake(now);
+    let nft_stake_amount =
+        nft_stake
+            .stake_history
+            .average(nft_stake.active_amount(now), days, now)?;
+
+    let user_amount = match &ctx.accounts.user_stake_account {
+        Some(user_stake) => {
+            let mut user_stake = (**user_stake).clone();
+            user_stake.accumulate_stake(now);
+            Some(
+                user_stake
+                    .stake_history
+                    .average(user_stake.active_amount(now), days, now)?,
+            )
+        }
+        None => None,
+    };
+
+    let end_time = now / SECONDS_PER_DAY * SECONDS_PER_DAY;
+
+    Ok(StakeAverage {
+        start_time: end_time - days as u64 * SECONDS_PER_DAY,
+        end_time,
+        nft_stake_amount,
+        user_amount,
+    })
+}
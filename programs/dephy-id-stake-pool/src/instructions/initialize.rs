@@ -17,6 +17,8 @@ pub struct Initialize<'info> {
 pub fn process_initialize(ctx: Context<Initialize>) -> Result<()> {
     let admin = &mut ctx.accounts.admin;
     admin.authority = ctx.accounts.authority.key();
+    admin.protocol_fee_rate = 0;
+    admin.protocol_fee_authority = ctx.accounts.authority.key();
 
     Ok(())
 }
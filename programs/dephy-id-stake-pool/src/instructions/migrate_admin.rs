@@ -0,0 +1,50 @@
+use crate::{
+    constants::ADMIN_SEED,
+    error::ErrorCode,
+    legacy::AdminAccountV0,
+    state::AdminAccount,
+    utils::{load_legacy_account, store_migrated_account},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateAdmin<'info> {
+    /// CHECK: legacy layout, checked in the handler
+    #[account(mut, seeds = [ADMIN_SEED], bump)]
+    pub admin: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move the admin to the current layout, the protocol takes no fee until set
+pub fn process_migrate_admin(ctx: Context<MigrateAdmin>) -> Result<()> {
+    msg!("migrate admin");
+
+    let admin_info = ctx.accounts.admin.to_account_info();
+    let legacy = load_legacy_account::<AdminAccountV0>(
+        &admin_info,
+        AdminAccount::DISCRIMINATOR,
+        ctx.program_id,
+    )?;
+
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        ErrorCode::InvalidAuthority
+    );
+
+    let admin = AdminAccount {
+        authority: legacy.authority,
+        protocol_fee_rate: 0,
+        protocol_fee_authority: legacy.authority,
+    };
+
+    store_migrated_account(
+        &admin_info,
+        &admin,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
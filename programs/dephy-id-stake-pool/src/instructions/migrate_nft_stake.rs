@@ -0,0 +1,84 @@
+use crate::{
+    constants::{ADMIN_SEED, SLASH_FACTOR_PRECISION},
+    error::ErrorCode,
+    legacy::NftStakeAccountV0,
+    state::{
+        AdminAccount, AssetStandard, NftStakeAccount, StakeActivation, StakeHistory,
+        StakePoolAccount,
+    },
+    utils::{load_legacy_account, store_migrated_account},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateNftStake<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = admin.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    /// Has to be migrated first
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    /// CHECK: legacy layout, checked in the handler
+    #[account(mut)]
+    pub nft_stake: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move an nft stake to the current layout. Legacy stakes do not count their depositors,
+/// so the nft stake stays frozen while migrate_user_stake counts them one by one, and
+/// finalize_nft_stake_migration checks none was left out
+pub fn process_migrate_nft_stake(ctx: Context<MigrateNftStake>) -> Result<()> {
+    msg!("migrate nft stake");
+
+    let nft_stake_info = ctx.accounts.nft_stake.to_account_info();
+    let legacy = load_legacy_account::<NftStakeAccountV0>(
+        &nft_stake_info,
+        NftStakeAccount::DISCRIMINATOR,
+        ctx.program_id,
+    )?;
+
+    require_keys_eq!(
+        legacy.stake_pool,
+        ctx.accounts.stake_pool.key(),
+        ErrorCode::InvalidAccount
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let mut nft_stake = NftStakeAccount {
+        stake_pool: legacy.stake_pool,
+        stake_authority: legacy.stake_authority,
+        deposit_authority: legacy.deposit_authority,
+        nft_token_account: legacy.nft_token_account,
+        // legacy pools had a single collection
+        collection: ctx.accounts.stake_pool.config.collection,
+        amount: legacy.amount,
+        commision_rate: legacy.commision_rate,
+        depositor_count: 0,
+        slash_factor: SLASH_FACTOR_PRECISION,
+        unstaked: false,
+        stake_history: StakeHistory::default(),
+        rent_payer: legacy.stake_authority,
+        activation: StakeActivation::default(),
+        asset_standard: AssetStandard::MplCore,
+        total_deposited: 0,
+        total_withdrawn: 0,
+        total_slashed: 0,
+        last_activity_timestamp: now,
+        migrating: true,
+    };
+    nft_stake.accumulate_stake(now);
+
+    ctx.accounts.stake_pool.nft_stake_count += 1;
+
+    store_migrated_account(
+        &nft_stake_info,
+        &nft_stake,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
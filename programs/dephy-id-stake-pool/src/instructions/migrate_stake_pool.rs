@@ -0,0 +1,120 @@
+use crate::{
+    constants::{ADMIN_SEED, ANNOUNCED_CONFIG_SEED, MAX_EXTRA_COLLECTIONS, MAX_REWARD_STREAMS},
+    error::ErrorCode,
+    legacy::StakePoolAccountV0,
+    state::{AdminAccount, StakeActivation, StakePoolAccount, StakePoolConfig},
+    utils::{load_legacy_account, store_migrated_account},
+};
+use anchor_lang::{prelude::*, solana_program::system_program};
+
+#[derive(Accounts)]
+pub struct MigrateStakePool<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = admin.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    /// CHECK: legacy layout, checked in the handler
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+    /// CHECK: a legacy announcement can not be confirmed any more, closed if present
+    #[account(mut, seeds = [stake_pool.key().as_ref(), ANNOUNCED_CONFIG_SEED], bump)]
+    pub announced_config: UncheckedAccount<'info>,
+    /// CHECK: gets the rent of a legacy announcement back, checked in the handler
+    #[account(mut)]
+    pub stake_pool_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a pool to the current layout, every feature added since starts disabled
+pub fn process_migrate_stake_pool(ctx: Context<MigrateStakePool>) -> Result<()> {
+    msg!("migrate stake pool");
+
+    let stake_pool_info = ctx.accounts.stake_pool.to_account_info();
+    let legacy = load_legacy_account::<StakePoolAccountV0>(
+        &stake_pool_info,
+        StakePoolAccount::DISCRIMINATOR,
+        ctx.program_id,
+    )?;
+
+    require_keys_eq!(
+        ctx.accounts.stake_pool_authority.key(),
+        legacy.authority,
+        ErrorCode::InvalidAuthority
+    );
+
+    let announced_config = ctx.accounts.announced_config.to_account_info();
+    if let Some(announced_config_key) = legacy.announced_config {
+        require_keys_eq!(
+            announced_config.key(),
+            announced_config_key,
+            ErrorCode::InvalidAccount
+        );
+        require_keys_eq!(
+            *announced_config.owner,
+            *ctx.program_id,
+            ErrorCode::InvalidAccount
+        );
+
+        let stake_pool_authority = ctx.accounts.stake_pool_authority.to_account_info();
+        **stake_pool_authority.try_borrow_mut_lamports()? += announced_config.lamports();
+        **announced_config.try_borrow_mut_lamports()? = 0;
+        announced_config.assign(&system_program::ID);
+        announced_config.resize(0)?;
+    }
+
+    let stake_pool = StakePoolAccount {
+        authority: legacy.authority,
+        announced_config: None,
+        config: StakePoolConfig {
+            collection: legacy.config.collection,
+            stake_token_mint: legacy.config.stake_token_mint,
+            max_stake_amount: legacy.config.max_stake_amount,
+            config_review_time: legacy.config.config_review_time,
+            slasher: None,
+            score_oracle: None,
+            max_score_age: 0,
+            max_total_amount: None,
+            max_per_user: None,
+            min_deposit_amount: 0,
+            deposit_fee_rate: 0,
+            withdraw_fee_rate: 0,
+            reward_fee_rate: 0,
+            early_exit_penalty_rate: 0,
+            early_exit_period: 0,
+            epoch_duration: 0,
+            extra_collections: [None; MAX_EXTRA_COLLECTIONS],
+            attribute_rules: vec![],
+            governance_quorum_rate: 0,
+        },
+        stake_token_account: legacy.stake_token_account,
+        total_amount: legacy.total_amount,
+        receipt_mint: None,
+        sunset: false,
+        // counted again as the nft stakes are migrated
+        nft_stake_count: 0,
+        reward_vault_count: 0,
+        reward_streams: [None; MAX_REWARD_STREAMS],
+        next_reward_stream_id: 1,
+        config_version: 0,
+        pool_fees: 0,
+        protocol_fees: 0,
+        // legacy stake is active already
+        activation: StakeActivation::default(),
+        // counted again as the user stakes are migrated
+        depositor_count: 0,
+        total_deposited: legacy.total_amount,
+        total_withdrawn: 0,
+        total_slashed: 0,
+        last_activity_timestamp: 0,
+        announced_at: 0,
+    };
+
+    store_migrated_account(
+        &stake_pool_info,
+        &stake_pool,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
@@ -0,0 +1,120 @@
+use crate::{
+    constants::{ADMIN_SEED, MAX_REWARD_STREAMS, SLASH_FACTOR_PRECISION, USER_POOL_STAKE_SEED},
+    error::ErrorCode,
+    legacy::UserStakeAccountV0,
+    state::{
+        AdminAccount, StakeActivation, StakeHistory, StakePoolAccount, UserPoolStakeAccount,
+        UserReward, UserStakeAccount,
+    },
+    utils::{load_legacy_account, load_nft_stake, store_migrated_account},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateUserStake<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = admin.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    /// Has to be migrated first
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    /// CHECK: has to be migrated first unless it is unstaked already, checked in the handler
+    #[account(mut)]
+    pub nft_stake: UncheckedAccount<'info>,
+    /// CHECK: legacy layout, checked in the handler
+    #[account(mut)]
+    pub user_stake_account: UncheckedAccount<'info>,
+    /// CHECK: owner of the position, checked in the handler
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = UserPoolStakeAccount::DISCRIMINATOR.len() + UserPoolStakeAccount::INIT_SPACE,
+        seeds = [stake_pool.key().as_ref(), USER_POOL_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub user_pool_stake_account: Account<'info, UserPoolStakeAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a user stake to the current layout, legacy stakes were never slashed
+/// so the whole amount is principal and slashes since are settled as usual
+pub fn process_migrate_user_stake(ctx: Context<MigrateUserStake>) -> Result<()> {
+    msg!("migrate user stake");
+
+    let user_stake_info = ctx.accounts.user_stake_account.to_account_info();
+    let legacy = load_legacy_account::<UserStakeAccountV0>(
+        &user_stake_info,
+        UserStakeAccount::DISCRIMINATOR,
+        ctx.program_id,
+    )?;
+
+    require_keys_eq!(
+        legacy.stake_pool,
+        ctx.accounts.stake_pool.key(),
+        ErrorCode::InvalidAccount
+    );
+    require_keys_eq!(
+        legacy.user,
+        ctx.accounts.user.key(),
+        ErrorCode::InvalidAccount
+    );
+
+    let nft_stake_info = &ctx.accounts.nft_stake;
+    require_keys_eq!(
+        legacy.nft_stake,
+        nft_stake_info.key(),
+        ErrorCode::InvalidAccount
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // the nft stake counts its depositors from the legacy user stakes
+    if let Some(mut nft_stake) = load_nft_stake(nft_stake_info, ctx.program_id)? {
+        require!(nft_stake.migrating, ErrorCode::InvalidAccount);
+
+        nft_stake.depositor_count += 1;
+        nft_stake.total_deposited += legacy.amount;
+        nft_stake.last_activity_timestamp = now;
+
+        nft_stake.try_serialize(&mut nft_stake_info.data.borrow_mut().as_mut())?;
+    }
+
+    let mut user_stake = UserStakeAccount {
+        stake_pool: legacy.stake_pool,
+        nft_stake: legacy.nft_stake,
+        user: legacy.user,
+        amount: legacy.amount,
+        last_deposit_timestamp: legacy.last_deposit_timestamp,
+        slash_factor: SLASH_FACTOR_PRECISION,
+        receipt_amount: 0,
+        rewards: [UserReward::default(); MAX_REWARD_STREAMS],
+        stake_history: StakeHistory::default(),
+        rent_payer: legacy.user,
+        principal: legacy.amount,
+        activation: StakeActivation::default(),
+        auto_compound: false,
+        vote_snapshot_amount: 0,
+        vote_snapshot_time: 0,
+    };
+    user_stake.accumulate_stake(now);
+
+    let user_pool_stake = &mut ctx.accounts.user_pool_stake_account;
+    if user_pool_stake.stake_pool == Pubkey::default() {
+        user_pool_stake.stake_pool = legacy.stake_pool;
+        user_pool_stake.user = legacy.user;
+        user_pool_stake.rent_payer = ctx.accounts.payer.key();
+
+        ctx.accounts.stake_pool.depositor_count += 1;
+    }
+    user_pool_stake.amount += legacy.amount;
+
+    store_migrated_account(
+        &user_stake_info,
+        &user_stake,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
@@ -1,21 +1,95 @@
 pub mod announce_update_config;
 pub mod cancel_update_config;
+pub mod claim_distribution;
+pub mod claim_fees;
+pub mod claim_rewards;
+pub mod close_config_change;
+pub mod close_device_score;
+pub mod close_distributor;
 pub mod close_nft_stake;
+pub mod close_reward_stream;
+pub mod close_stake_pool;
+pub mod close_vote_record;
+pub mod compound_rewards;
 pub mod confirm_update_config;
+pub mod create_distributor;
 pub mod create_nft_stake;
+pub mod create_receipt_mint;
+pub mod create_reward_stream;
 pub mod create_stake_pool;
+pub mod create_token_nft_stake;
+pub mod deactivate_stake;
 pub mod deposit;
+pub mod deposit_for;
+pub mod finalize_nft_stake_migration;
+pub mod force_unstake_nft;
+pub mod force_unstake_token_nft;
+pub mod get_device_score;
+pub mod get_stake_average;
 pub mod initialize;
+pub mod migrate_admin;
+pub mod migrate_nft_stake;
+pub mod migrate_stake_pool;
+pub mod migrate_user_stake;
+pub mod post_device_score;
+pub mod redelegate;
+pub mod refund_orphaned_stake;
+pub mod set_auto_compound;
+pub mod set_protocol_fee;
+pub mod slash_nft_stake;
+pub mod sunset_stake_pool;
+pub mod transfer_nft_stake;
 pub mod unstake_nft;
+pub mod unstake_token_nft;
+pub mod veto_update_config;
+pub mod vote_config;
 pub mod withdraw;
+pub mod withdraw_treasury;
 
 pub use announce_update_config::*;
 pub use cancel_update_config::*;
+pub use claim_distribution::*;
+pub use claim_fees::*;
+pub use claim_rewards::*;
+pub use close_config_change::*;
+pub use close_device_score::*;
+pub use close_distributor::*;
 pub use close_nft_stake::*;
+pub use close_reward_stream::*;
+pub use close_stake_pool::*;
+pub use close_vote_record::*;
+pub use compound_rewards::*;
 pub use confirm_update_config::*;
+pub use create_distributor::*;
 pub use create_nft_stake::*;
+pub use create_receipt_mint::*;
+pub use create_reward_stream::*;
 pub use create_stake_pool::*;
+pub use create_token_nft_stake::*;
+pub use deactivate_stake::*;
 pub use deposit::*;
+pub use deposit_for::*;
+pub use finalize_nft_stake_migration::*;
+pub use force_unstake_nft::*;
+pub use force_unstake_token_nft::*;
+pub use get_device_score::*;
+pub use get_stake_average::*;
 pub use initialize::*;
+pub use migrate_admin::*;
+pub use migrate_nft_stake::*;
+pub use migrate_stake_pool::*;
+pub use migrate_user_stake::*;
+pub use post_device_score::*;
+pub use redelegate::*;
+pub use refund_orphaned_stake::*;
+pub use set_auto_compound::*;
+pub use set_protocol_fee::*;
+pub use slash_nft_stake::*;
+pub use sunset_stake_pool::*;
+pub use transfer_nft_stake::*;
 pub use unstake_nft::*;
+pub use unstake_token_nft::*;
+pub use veto_update_config::*;
+pub use vote_config::*;
 pub use withdraw::*;
+pub use withdraw_treasury::*;
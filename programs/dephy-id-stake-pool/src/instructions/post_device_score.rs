@@ -0,0 +1,62 @@
+use crate::{
+    constants::{DEVICE_SCORE_SEED, MAX_UPTIME},
+    error::ErrorCode,
+    state::{DeviceScoreAccount, NftStakeAccount, PostDeviceScoreArgs, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PostDeviceScore<'info> {
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    pub oracle: Signer<'info>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = DeviceScoreAccount::DISCRIMINATOR.len() + DeviceScoreAccount::INIT_SPACE,
+        seeds = [nft_stake.key().as_ref(), DEVICE_SCORE_SEED], bump
+    )]
+    pub device_score: Account<'info, DeviceScoreAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_post_device_score(
+    ctx: Context<PostDeviceScore>,
+    args: PostDeviceScoreArgs,
+) -> Result<()> {
+    msg!("post device score epoch {}", args.epoch);
+
+    let stake_pool = &ctx.accounts.stake_pool;
+
+    match stake_pool.config.score_oracle {
+        Some(oracle) if oracle == ctx.accounts.oracle.key() => {}
+        _ => return Err(ErrorCode::InvalidAuthority.into()),
+    }
+
+    require_gte!(MAX_UPTIME, args.uptime_120h, ErrorCode::InvalidScore);
+    require_gte!(MAX_UPTIME, args.uptime_720h, ErrorCode::InvalidScore);
+
+    let device_score = &mut ctx.accounts.device_score;
+
+    // scores only move forward, an older epoch is never accepted
+    if device_score.nft_stake != Pubkey::default() {
+        require_gt!(args.epoch, device_score.epoch, ErrorCode::StaleScore);
+    } else {
+        device_score.rent_payer = ctx.accounts.payer.key();
+    }
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    device_score.stake_pool = stake_pool.key();
+    device_score.nft_stake = ctx.accounts.nft_stake.key();
+    device_score.epoch = args.epoch;
+    device_score.uptime_120h = args.uptime_120h;
+    device_score.uptime_720h = args.uptime_720h;
+    device_score.score = args.score;
+    device_score.timestamp = now;
+
+    Ok(())
+}
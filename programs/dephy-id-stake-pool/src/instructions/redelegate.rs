@@ -0,0 +1,185 @@
+use crate::{
+    constants::USER_STAKE_SEED,
+    error::ErrorCode,
+    state::{NftStakeAccount, StakePoolAccount, UserStakeAccount},
+    utils::{load_nft_stake, update_rewards},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Redelegate<'info> {
+    #[account(mut, address = source_user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    /// CHECK: source nft_stake could be unstaked already
+    #[account(mut, address = source_user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub source_nft_stake: UncheckedAccount<'info>,
+    #[account(mut,
+        seeds = [source_nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub source_user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(mut, has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub target_nft_stake: Account<'info, NftStakeAccount>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = UserStakeAccount::DISCRIMINATOR.len() + UserStakeAccount::INIT_SPACE,
+        seeds = [target_nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub target_user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(address = source_user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+    /// CHECK: gets the rent back if the source position is closed
+    #[account(mut, address = source_user_stake_account.rent_payer @ ErrorCode::InvalidAccount)]
+    pub source_rent_payer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_redelegate(ctx: Context<Redelegate>, maybe_amount: Option<u64>) -> Result<()> {
+    require_keys_neq!(
+        ctx.accounts.source_nft_stake.key(),
+        ctx.accounts.target_nft_stake.key(),
+        ErrorCode::InvalidAccount
+    );
+
+    let stake_pool = &ctx.accounts.stake_pool;
+    let config = &stake_pool.config;
+    let source_user_stake = &mut ctx.accounts.source_user_stake_account;
+    let target_nft_stake = &mut ctx.accounts.target_nft_stake;
+    let target_user_stake = &mut ctx.accounts.target_user_stake_account;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let source_nft_stake_account = &mut ctx.accounts.source_nft_stake;
+    let mut maybe_source_nft_stake = load_nft_stake(source_nft_stake_account, ctx.program_id)?;
+    source_user_stake.accumulate_stake(now);
+    if let Some(source_nft_stake) = maybe_source_nft_stake.as_mut() {
+        source_nft_stake.accumulate_stake(now);
+        source_user_stake.settle_slash(source_nft_stake);
+    }
+
+    target_user_stake.accumulate_stake(now);
+    target_nft_stake.accumulate_stake(now);
+
+    if target_user_stake.stake_pool == Pubkey::default() {
+        target_user_stake.stake_pool = stake_pool.key();
+        target_user_stake.nft_stake = target_nft_stake.key();
+        target_user_stake.user = ctx.accounts.user.key();
+        target_user_stake.slash_factor = target_nft_stake.slash_factor;
+        target_user_stake.rent_payer = ctx.accounts.payer.key();
+
+        target_nft_stake.depositor_count += 1;
+    } else {
+        target_user_stake.settle_slash(target_nft_stake);
+    }
+    target_user_stake.snapshot_vote(stake_pool);
+
+    let amount = match maybe_amount {
+        Some(amount) => amount,
+        None => source_user_stake.amount,
+    };
+
+    msg!("redelegate {}", amount);
+
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    require_gte!(source_user_stake.amount, amount, ErrorCode::InvalidAmount);
+    require!(
+        !target_nft_stake.unstaked && target_nft_stake.slash_factor > 0,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(
+        !target_nft_stake.migrating
+            && maybe_source_nft_stake
+                .as_ref()
+                .is_none_or(|source_nft_stake| !source_nft_stake.migrating),
+        ErrorCode::NftStakeMigrating
+    );
+
+    update_rewards(
+        stake_pool,
+        &mut [&mut **source_user_stake, &mut **target_user_stake],
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    if source_user_stake.amount == amount {
+        require!(
+            !source_user_stake.has_pending_rewards(stake_pool),
+            ErrorCode::RewardsNotClaimed
+        );
+    }
+
+    source_user_stake.activation.roll(now);
+    let moved = source_user_stake.activation.remove(amount);
+
+    let mut moved_amount = amount;
+    if let Some(source_nft_stake) = maybe_source_nft_stake.as_mut() {
+        source_nft_stake.activation.roll(now);
+        source_nft_stake.activation.subtract(&moved);
+
+        moved_amount = source_nft_stake.release(amount, source_user_stake.amount == amount)?;
+        source_nft_stake.total_withdrawn += moved_amount;
+        source_nft_stake.last_activity_timestamp = now;
+        source_nft_stake.try_serialize(&mut source_nft_stake_account.data.borrow_mut().as_mut())?;
+    }
+
+    let moved_principal = source_user_stake.principal_of(amount)?;
+    source_user_stake.principal -= moved_principal;
+
+    source_user_stake.amount -= amount;
+
+    // receipt tokens stay with the user and keep backing the moved stake
+    let moved_receipt_amount = if source_user_stake.amount == 0 {
+        source_user_stake.receipt_amount
+    } else {
+        amount.min(source_user_stake.receipt_amount)
+    };
+    source_user_stake.receipt_amount -= moved_receipt_amount;
+
+    let max_stake_amount = config
+        .max_stake_amount_for(&target_nft_stake.collection)
+        .ok_or(ErrorCode::InvalidCollection)?;
+    require_gte!(
+        max_stake_amount,
+        target_nft_stake.amount + moved_amount,
+        ErrorCode::InvalidAmount
+    );
+
+    target_user_stake.amount += moved_amount;
+    target_user_stake.receipt_amount += moved_receipt_amount;
+    target_user_stake.principal += moved_principal;
+    target_user_stake.last_deposit_timestamp = now;
+
+    target_nft_stake.amount += moved_amount;
+    target_nft_stake.total_deposited += moved_amount;
+    target_nft_stake.last_activity_timestamp = now;
+
+    // moved stake starts earning in the target from the next epoch, like a deposit
+    let activation_time = config.activation_time(now);
+    target_user_stake.activation.roll(now);
+    target_user_stake
+        .activation
+        .add(moved_amount, activation_time);
+    target_nft_stake.activation.roll(now);
+    target_nft_stake
+        .activation
+        .add(moved_amount, activation_time);
+
+    // tokens stay in the pool, so total_amount is unchanged
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.activation.roll(now);
+    stake_pool.activation.subtract(&moved);
+    stake_pool.activation.add(moved_amount, activation_time);
+    stake_pool.last_activity_timestamp = now;
+
+    if source_user_stake.amount == 0 {
+        ctx.accounts
+            .source_user_stake_account
+            .close(ctx.accounts.source_rent_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
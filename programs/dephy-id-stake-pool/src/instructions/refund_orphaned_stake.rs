@@ -0,0 +1,141 @@
+use crate::{
+    constants::{ADMIN_SEED, POOL_WALLET_SEED, USER_POOL_STAKE_SEED, USER_STAKE_SEED},
+    error::ErrorCode,
+    instructions::{
+        claim_rewards::{claim_rewards, ClaimAccounts},
+        withdraw::{withdraw, WithdrawAccounts, Withdrawal},
+    },
+    state::{AdminAccount, StakePoolAccount, UserPoolStakeAccount, UserStakeAccount},
+    utils::load_nft_stake,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Remaining accounts, for every reward stream of the pool:
+/// reward_stream (mut), vault (mut), reward_mint, user's associated reward token account (mut)
+#[derive(Accounts)]
+pub struct RefundOrphanedStake<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(mut, address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    /// CHECK: unstaked or closed already, checked in the handler
+    #[account(mut, address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: UncheckedAccount<'info>,
+    /// CHECK: the depositor, who does not sign
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: UncheckedAccount<'info>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+    /// Can be left out once the user's principal in the pool is gone and the account closed
+    #[account(mut,
+        seeds = [stake_pool.key().as_ref(), USER_POOL_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub user_pool_stake_account: Option<Account<'info, UserPoolStakeAccount>>,
+    #[account(
+        address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
+        mint::token_program = token_program
+    )]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken,
+        token::mint = stake_token_mint,
+        token::authority = pool_wallet,
+        token::token_program = token_program
+    )]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = stake_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    /// CHECK: gets the rent back when the position is closed
+    #[account(mut, address = user_stake_account.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// CHECK: gets the rent back when the pool stake is closed, checked against it
+    #[account(mut)]
+    pub user_pool_stake_rent_payer: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Return the stake of a position left behind by an unstaked nft to its owner, anyone can
+/// crank it. Pending rewards are paid out as well and no withdraw fee or early exit penalty
+/// is charged.
+/// Stake backed by receipts stays for their holders to redeem with withdraw, rewards of
+/// streams in the other token program stay for the owner to claim.
+pub fn process_refund_orphaned_stake<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundOrphanedStake<'info>>,
+) -> Result<()> {
+    msg!("refund orphaned stake");
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let maybe_nft_stake = load_nft_stake(&ctx.accounts.nft_stake, ctx.program_id)?;
+    require!(
+        maybe_nft_stake
+            .as_ref()
+            .is_none_or(|nft_stake| nft_stake.unstaked),
+        ErrorCode::NftStakeActive
+    );
+
+    // the refund is what slashing left beyond the receipts
+    let user_stake = &mut ctx.accounts.user_stake_account;
+    user_stake.accumulate_stake(now);
+    if let Some(nft_stake) = maybe_nft_stake.as_ref() {
+        user_stake.settle_slash(nft_stake);
+    }
+    let amount = user_stake.amount.saturating_sub(user_stake.receipt_amount);
+
+    let user = ctx.accounts.user.key();
+    let accounts = ctx.accounts;
+    claim_rewards(
+        ClaimAccounts {
+            admin: &accounts.admin,
+            stake_pool: &accounts.stake_pool,
+            user_stake: &mut accounts.user_stake_account,
+            recipient: Some(user),
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            token_program: accounts.token_program.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    // no receipts are burned, the owner only gets the stake they do not back
+    withdraw(
+        WithdrawAccounts {
+            admin: &accounts.admin,
+            stake_pool: &mut accounts.stake_pool,
+            nft_stake: &accounts.nft_stake,
+            user_stake: &mut accounts.user_stake_account,
+            user_pool_stake: accounts.user_pool_stake_account.as_mut(),
+            stake_token_mint: &accounts.stake_token_mint,
+            stake_token_account: accounts.stake_token_account.to_account_info(),
+            recipient_stake_token_account: accounts.user_stake_token_account.to_account_info(),
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            rent_payer: accounts.rent_payer.to_account_info(),
+            user_pool_stake_rent_payer: accounts
+                .user_pool_stake_rent_payer
+                .as_ref()
+                .map(|rent_payer| rent_payer.to_account_info()),
+            token_program: accounts.token_program.to_account_info(),
+            authority: accounts.user.to_account_info(),
+            receipt_mint: None,
+            receipt_token_account: None,
+            receipt_token_program: None,
+        },
+        Some(amount),
+        Withdrawal::Refund,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )
+}
@@ -0,0 +1,21 @@
+use crate::{constants::USER_STAKE_SEED, error::ErrorCode, state::UserStakeAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+    /// CHECK: only used as a seed, nft_stake could be unstaked already
+    #[account(address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: UncheckedAccount<'info>,
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+    #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+}
+
+pub fn process_set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+    msg!("set auto compound {}", enabled);
+
+    ctx.accounts.user_stake_account.auto_compound = enabled;
+
+    Ok(())
+}
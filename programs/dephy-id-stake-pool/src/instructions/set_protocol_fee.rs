@@ -0,0 +1,33 @@
+use crate::{
+    constants::{ADMIN_SEED, MAX_FEE_RATE},
+    error::ErrorCode,
+    state::{AdminAccount, SetProtocolFeeArgs},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(mut, seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(address = admin.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+pub fn process_set_protocol_fee(
+    ctx: Context<SetProtocolFee>,
+    args: SetProtocolFeeArgs,
+) -> Result<()> {
+    msg!("set protocol fee {}", args.protocol_fee_rate);
+
+    require_gte!(
+        MAX_FEE_RATE,
+        args.protocol_fee_rate,
+        ErrorCode::InvalidConfig
+    );
+
+    let admin = &mut ctx.accounts.admin;
+    admin.protocol_fee_rate = args.protocol_fee_rate;
+    admin.protocol_fee_authority = args.protocol_fee_authority;
+
+    Ok(())
+}
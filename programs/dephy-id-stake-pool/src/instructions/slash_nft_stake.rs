@@ -0,0 +1,114 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, TREASURY_SEED},
+    error::ErrorCode,
+    state::{NftStakeAccount, SlashNftStakeArgs, StakePoolAccount},
+    utils::{mul_div, update_rewards},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct SlashNftStake<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(mut, has_one = stake_pool @ ErrorCode::InvalidAccount)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    pub slasher: Signer<'info>,
+    #[account(
+        address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
+        mint::token_program = token_program
+    )]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = stake_pool.stake_token_account @ ErrorCode::InvalidStakeToken)]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed, payer = payer,
+        token::mint = stake_token_mint,
+        token::authority = pool_wallet,
+        token::token_program = token_program,
+        seeds = [stake_pool.key().as_ref(), TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_slash_nft_stake(ctx: Context<SlashNftStake>, args: SlashNftStakeArgs) -> Result<()> {
+    msg!("slash nft stake {}%", args.rate);
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let nft_stake = &mut ctx.accounts.nft_stake;
+
+    match stake_pool.config.slasher {
+        Some(slasher) if slasher == ctx.accounts.slasher.key() => {}
+        _ => return Err(ErrorCode::InvalidAuthority.into()),
+    }
+
+    require!(!nft_stake.unstaked, ErrorCode::NftStakeNotActive);
+    require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+    require_gt!(args.rate, 0, ErrorCode::InvalidSlashRate);
+    require_gte!(100, args.rate, ErrorCode::InvalidSlashRate);
+
+    let slash_amount = mul_div(nft_stake.amount, args.rate as u64, 100)?;
+    require_gt!(slash_amount, 0, ErrorCode::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    update_rewards(
+        stake_pool,
+        &mut [],
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    nft_stake.accumulate_stake(now);
+
+    // pending and inactive stake are slashed at the same rate
+    let remaining_amount = nft_stake.amount - slash_amount;
+    nft_stake.activation.roll(now);
+    let amount = nft_stake.amount;
+    let slashed = nft_stake.activation.shrink(remaining_amount, amount);
+    stake_pool.activation.roll(now);
+    stake_pool.activation.subtract(&slashed);
+
+    // user stakes settle lazily against the scaled down factor
+    nft_stake.slash_factor =
+        nft_stake.slash_factor * remaining_amount as u128 / nft_stake.amount as u128;
+    nft_stake.amount = remaining_amount;
+    nft_stake.total_slashed += slash_amount;
+    nft_stake.last_activity_timestamp = now;
+
+    stake_pool.total_amount -= slash_amount;
+    stake_pool.total_slashed += slash_amount;
+    stake_pool.last_activity_timestamp = now;
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.stake_token_account.to_account_info(),
+                mint: ctx.accounts.stake_token_mint.to_account_info(),
+                to: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            &[&[
+                stake_pool.key().as_ref(),
+                POOL_WALLET_SEED,
+                &[ctx.bumps.pool_wallet],
+            ]],
+        ),
+        slash_amount,
+        ctx.accounts.stake_token_mint.decimals,
+    )?;
+
+    Ok(())
+}
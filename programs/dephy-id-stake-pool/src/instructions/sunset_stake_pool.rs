@@ -0,0 +1,22 @@
+use crate::{error::ErrorCode, state::StakePoolAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SunsetStakePool<'info> {
+    #[account(mut)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+pub fn process_sunset_stake_pool(ctx: Context<SunsetStakePool>) -> Result<()> {
+    msg!("sunset stake pool");
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    require!(!stake_pool.sunset, ErrorCode::StakePoolSunset);
+
+    stake_pool.sunset = true;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use crate::{
+    constants::{ASSET_LOCK_SEED, POOL_WALLET_SEED},
+    error::ErrorCode,
+    state::{AssetLockAccount, AssetStandard, NftStakeAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+
+/// Remaining accounts: the nft stakes of the asset in the other pools of the asset lock (mut)
+#[derive(Accounts)]
+pub struct TransferNftStake<'info> {
+    #[account(mut)]
+    pub nft_stake: Account<'info, NftStakeAccount>,
+    #[account(address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = nft_stake.stake_authority @ ErrorCode::InvalidAuthority)]
+    pub stake_authority: Signer<'info>,
+    /// The new owner has to sign to hand the freeze back to the asset lock or pool wallet
+    pub new_stake_authority: Signer<'info>,
+    /// CHECK:
+    #[account(mut, address = nft_stake.collection @ ErrorCode::InvalidCollection)]
+    pub mpl_core_collection: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(mut, address = nft_stake.nft_token_account @ ErrorCode::InvalidAccount)]
+    pub mpl_core_asset: UncheckedAccount<'info>,
+    /// Omitted for assets frozen by the pool wallet before asset locks existed
+    #[account(seeds = [ASSET_LOCK_SEED, mpl_core_asset.key().as_ref()], bump)]
+    pub asset_lock: Option<Account<'info, AssetLockAccount>>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK:
+    #[account(address = mpl_core::ID @ ErrorCode::InvalidMplCoreProgram)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+pub fn process_transfer_nft_stake(ctx: Context<TransferNftStake>) -> Result<()> {
+    msg!("transfer nft stake");
+
+    require!(
+        !ctx.accounts.nft_stake.unstaked,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(
+        !ctx.accounts.nft_stake.migrating,
+        ErrorCode::NftStakeMigrating
+    );
+    require!(
+        ctx.accounts.nft_stake.asset_standard == AssetStandard::MplCore,
+        ErrorCode::UnsupportedAsset
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let asset_key = ctx.accounts.mpl_core_asset.key();
+    let stake_authority_key = ctx.accounts.stake_authority.key();
+    let new_stake_authority_key = ctx.accounts.new_stake_authority.key();
+
+    // the asset follows the sale in every pool holding it, a pool wallet freeze only
+    // belongs to this pool
+    let stake_pools = match &ctx.accounts.asset_lock {
+        Some(asset_lock) => asset_lock.stake_pools.clone(),
+        None => vec![stake_pool_key],
+    };
+    let other_nft_stakes = ctx.remaining_accounts;
+    require_eq!(
+        other_nft_stakes.len() + 1,
+        stake_pools.len(),
+        ErrorCode::InvalidAccount
+    );
+    let mut seen_pools = vec![ctx.accounts.nft_stake.stake_pool];
+    for nft_stake_info in other_nft_stakes {
+        require_keys_eq!(
+            *nft_stake_info.owner,
+            *ctx.program_id,
+            ErrorCode::InvalidAccount
+        );
+        let mut nft_stake =
+            NftStakeAccount::try_deserialize(&mut nft_stake_info.data.borrow().as_ref())?;
+
+        require_keys_eq!(
+            nft_stake.nft_token_account,
+            asset_key,
+            ErrorCode::InvalidAccount
+        );
+        require!(!nft_stake.unstaked, ErrorCode::NftStakeNotActive);
+        require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+        require!(
+            stake_pools.contains(&nft_stake.stake_pool)
+                && !seen_pools.contains(&nft_stake.stake_pool),
+            ErrorCode::InvalidAccount
+        );
+        require_keys_eq!(
+            nft_stake.stake_authority,
+            stake_authority_key,
+            ErrorCode::InvalidAuthority
+        );
+        seen_pools.push(nft_stake.stake_pool);
+
+        nft_stake.stake_authority = new_stake_authority_key;
+        nft_stake.try_serialize(&mut nft_stake_info.data.borrow_mut().as_mut())?;
+    }
+
+    let (freeze_authority, signer_seeds) = match &ctx.accounts.asset_lock {
+        Some(asset_lock) => (
+            asset_lock.to_account_info(),
+            [
+                ASSET_LOCK_SEED,
+                asset_key.as_ref(),
+                &[ctx.bumps.asset_lock.ok_or(ErrorCode::InvalidAccount)?],
+            ],
+        ),
+        None => {
+            let (authority, _, _) =
+                mpl_core::fetch_asset_plugin::<mpl_core::types::FreezeDelegate>(
+                    &ctx.accounts.mpl_core_asset.to_account_info(),
+                    mpl_core::types::PluginType::FreezeDelegate,
+                )
+                .map_err(|_| ErrorCode::InvalidAccount)?;
+            require!(
+                authority
+                    == mpl_core::types::PluginAuthority::Address {
+                        address: ctx.accounts.pool_wallet.key(),
+                    },
+                ErrorCode::InvalidAccount
+            );
+
+            (
+                ctx.accounts.pool_wallet.to_account_info(),
+                [
+                    stake_pool_key.as_ref(),
+                    POOL_WALLET_SEED,
+                    &[ctx.bumps.pool_wallet],
+                ],
+            )
+        }
+    };
+
+    // thaw
+    mpl_core::instructions::UpdatePluginV1Cpi::new(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        mpl_core::instructions::UpdatePluginV1CpiAccounts {
+            asset: &ctx.accounts.mpl_core_asset.to_account_info(),
+            collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+            authority: Some(&freeze_authority),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            log_wrapper: None,
+        },
+        mpl_core::instructions::UpdatePluginV1InstructionArgs {
+            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                frozen: false,
+            }),
+        },
+    )
+    .invoke_signed(&[&signer_seeds])?;
+
+    mpl_core::instructions::TransferV1Cpi::new(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        mpl_core::instructions::TransferV1CpiAccounts {
+            asset: &ctx.accounts.mpl_core_asset.to_account_info(),
+            collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+            payer: &ctx.accounts.payer.to_account_info(),
+            authority: Some(&ctx.accounts.stake_authority.to_account_info()),
+            new_owner: &ctx.accounts.new_stake_authority.to_account_info(),
+            system_program: Some(&ctx.accounts.system_program.to_account_info()),
+            log_wrapper: None,
+        },
+        mpl_core::instructions::TransferV1InstructionArgs {
+            compression_proof: None,
+        },
+    )
+    .invoke()?;
+
+    // owner managed plugin authorities are reset to the new owner on transfer
+    mpl_core::instructions::ApprovePluginAuthorityV1Cpi::new(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        mpl_core::instructions::ApprovePluginAuthorityV1CpiAccounts {
+            asset: &ctx.accounts.mpl_core_asset.to_account_info(),
+            collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+            payer: &ctx.accounts.payer.to_account_info(),
+            authority: Some(&ctx.accounts.new_stake_authority.to_account_info()),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            log_wrapper: None,
+        },
+        mpl_core::instructions::ApprovePluginAuthorityV1InstructionArgs {
+            plugin_type: mpl_core::types::PluginType::FreezeDelegate,
+            new_authority: mpl_core::types::PluginAuthority::Address {
+                address: freeze_authority.key(),
+            },
+        },
+    )
+    .invoke()?;
+
+    // freeze again
+    mpl_core::instructions::UpdatePluginV1Cpi::new(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        mpl_core::instructions::UpdatePluginV1CpiAccounts {
+            asset: &ctx.accounts.mpl_core_asset.to_account_info(),
+            collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
+            authority: Some(&freeze_authority),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            log_wrapper: None,
+        },
+        mpl_core::instructions::UpdatePluginV1InstructionArgs {
+            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                frozen: true,
+            }),
+        },
+    )
+    .invoke_signed(&[&signer_seeds])?;
+
+    let nft_stake = &mut ctx.accounts.nft_stake;
+    nft_stake.stake_authority = new_stake_authority_key;
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
-    constants::POOL_WALLET_SEED,
+    constants::{ASSET_LOCK_SEED, POOL_WALLET_SEED},
     error::ErrorCode,
-    state::{NftStakeAccount, StakePoolAccount},
+    state::{AssetLockAccount, AssetStandard, NftStakeAccount, StakePoolAccount},
 };
 use anchor_lang::prelude::*;
 
@@ -9,20 +9,29 @@ use anchor_lang::prelude::*;
 pub struct UnstakeNft<'info> {
     #[account(mut)]
     pub nft_stake: Account<'info, NftStakeAccount>,
-    #[account(address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
+    #[account(mut, address = nft_stake.stake_pool @ ErrorCode::InvalidAccount)]
     pub stake_pool: Account<'info, StakePoolAccount>,
     #[account(address = nft_stake.stake_authority @ ErrorCode::InvalidAuthority)]
     pub stake_authority: Signer<'info>,
     /// CHECK:
-    #[account(mut, address = stake_pool.config.collection @ ErrorCode::InvalidCollection)]
+    #[account(mut, address = nft_stake.collection @ ErrorCode::InvalidCollection)]
     pub mpl_core_collection: UncheckedAccount<'info>,
     /// CHECK:
-    #[account(mut)]
+    #[account(mut, address = nft_stake.nft_token_account @ ErrorCode::InvalidAccount)]
     pub mpl_core_asset: UncheckedAccount<'info>,
+    /// Omitted for assets frozen by the pool wallet before asset locks existed
+    #[account(mut, seeds = [ASSET_LOCK_SEED, mpl_core_asset.key().as_ref()], bump)]
+    pub asset_lock: Option<Account<'info, AssetLockAccount>>,
     #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
     pub pool_wallet: SystemAccount<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: gets the rent back
+    #[account(mut, address = nft_stake.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// CHECK: gets the asset lock rent back once the asset thaws, checked in the handler
+    #[account(mut)]
+    pub asset_lock_rent_payer: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     /// CHECK:
     #[account(address = mpl_core::ID @ ErrorCode::InvalidMplCoreProgram)]
@@ -32,14 +41,71 @@ pub struct UnstakeNft<'info> {
 pub fn process_unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
     msg!("unstake nft");
 
-    let _nft_stake = &mut ctx.accounts.nft_stake;
+    require!(
+        !ctx.accounts.nft_stake.unstaked,
+        ErrorCode::NftStakeNotActive
+    );
+    require!(
+        !ctx.accounts.nft_stake.migrating,
+        ErrorCode::NftStakeMigrating
+    );
+    require!(
+        ctx.accounts.nft_stake.asset_standard == AssetStandard::MplCore,
+        ErrorCode::UnsupportedAsset
+    );
+
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let asset_key = ctx.accounts.mpl_core_asset.key();
+
+    // other pools still hold the asset
+    if let Some(asset_lock) = ctx.accounts.asset_lock.as_mut() {
+        asset_lock.release(&stake_pool_key)?;
+        if !asset_lock.stake_pools.is_empty() {
+            return remove_nft_stake(ctx);
+        }
+    }
+
+    let (freeze_authority, signer_seeds) = match &ctx.accounts.asset_lock {
+        Some(asset_lock) => (
+            asset_lock.to_account_info(),
+            [
+                ASSET_LOCK_SEED,
+                asset_key.as_ref(),
+                &[ctx.bumps.asset_lock.ok_or(ErrorCode::InvalidAccount)?],
+            ],
+        ),
+        None => {
+            let (authority, _, _) =
+                mpl_core::fetch_asset_plugin::<mpl_core::types::FreezeDelegate>(
+                    &ctx.accounts.mpl_core_asset.to_account_info(),
+                    mpl_core::types::PluginType::FreezeDelegate,
+                )
+                .map_err(|_| ErrorCode::InvalidAccount)?;
+            require!(
+                authority
+                    == mpl_core::types::PluginAuthority::Address {
+                        address: ctx.accounts.pool_wallet.key(),
+                    },
+                ErrorCode::InvalidAccount
+            );
+
+            (
+                ctx.accounts.pool_wallet.to_account_info(),
+                [
+                    stake_pool_key.as_ref(),
+                    POOL_WALLET_SEED,
+                    &[ctx.bumps.pool_wallet],
+                ],
+            )
+        }
+    };
 
     mpl_core::instructions::UpdatePluginV1Cpi::new(
         &ctx.accounts.mpl_core_program.to_account_info(),
         mpl_core::instructions::UpdatePluginV1CpiAccounts {
             asset: &ctx.accounts.mpl_core_asset.to_account_info(),
             collection: Some(&ctx.accounts.mpl_core_collection.to_account_info()),
-            authority: Some(&ctx.accounts.pool_wallet.to_account_info()),
+            authority: Some(&freeze_authority),
             system_program: &ctx.accounts.system_program.to_account_info(),
             payer: &ctx.accounts.payer.to_account_info(),
             log_wrapper: None,
@@ -50,11 +116,7 @@ pub fn process_unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
             }),
         },
     )
-    .invoke_signed(&[&[
-        ctx.accounts.stake_pool.key().as_ref(),
-        POOL_WALLET_SEED,
-        &[ctx.bumps.pool_wallet],
-    ]])?;
+    .invoke_signed(&[&signer_seeds])?;
 
     mpl_core::instructions::RemovePluginV1Cpi::new(
         &ctx.accounts.mpl_core_program.to_account_info(),
@@ -72,9 +134,40 @@ pub fn process_unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
     )
     .invoke()?;
 
+    if let Some(asset_lock) = &ctx.accounts.asset_lock {
+        let Some(asset_lock_rent_payer) = &ctx.accounts.asset_lock_rent_payer else {
+            return Err(ErrorCode::InvalidAccount.into());
+        };
+        require_keys_eq!(
+            asset_lock_rent_payer.key(),
+            asset_lock.rent_payer,
+            ErrorCode::InvalidAccount
+        );
+        asset_lock.close(asset_lock_rent_payer.to_account_info())?;
+    }
+
+    remove_nft_stake(ctx)
+}
+
+fn remove_nft_stake(ctx: Context<UnstakeNft>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    ctx.accounts.stake_pool.nft_stake_count -= 1;
+    ctx.accounts.stake_pool.last_activity_timestamp = now;
+
+    // depositors left settle their slashes against the nft stake, close_nft_stake
+    // takes it back once the last of them withdrew
+    let nft_stake = &mut ctx.accounts.nft_stake;
+    if nft_stake.depositor_count > 0 {
+        nft_stake.unstaked = true;
+        nft_stake.last_activity_timestamp = now;
+        return Ok(());
+    }
+
     ctx.accounts
         .nft_stake
-        .close(ctx.accounts.payer.to_account_info())?;
+        .close(ctx.accounts.rent_payer.to_account_info())?;
 
     Ok(())
 }
@@ -0,0 +1,31 @@
+use crate::{
+    constants::ANNOUNCED_CONFIG_SEED,
+    error::ErrorCode,
+    state::{AnnouncedConfigAccount, StakePoolAccount},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VetoUpdateConfig<'info> {
+    #[account(mut, address = announced_config.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(mut, close = rent_payer, seeds = [stake_pool.key().as_ref(), ANNOUNCED_CONFIG_SEED], bump)]
+    pub announced_config: Account<'info, AnnouncedConfigAccount>,
+    /// CHECK: gets the rent back
+    #[account(mut, address = announced_config.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Drop an announced config the stakers voted down, anyone can call it
+pub fn process_veto_update_config(ctx: Context<VetoUpdateConfig>) -> Result<()> {
+    msg!("veto update config");
+
+    require!(
+        ctx.accounts.announced_config.is_vetoed()?,
+        ErrorCode::QuorumNotReached
+    );
+
+    ctx.accounts.stake_pool.announced_config = None;
+
+    Ok(())
+}
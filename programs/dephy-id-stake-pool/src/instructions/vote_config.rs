@@ -0,0 +1,89 @@
+use crate::{
+    constants::{ANNOUNCED_CONFIG_SEED, USER_STAKE_SEED, VOTE_RECORD_SEED},
+    error::ErrorCode,
+    state::{AnnouncedConfigAccount, StakePoolAccount, UserStakeAccount, VoteRecordAccount},
+    utils::load_nft_stake,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VoteConfig<'info> {
+    #[account(address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(mut, seeds = [stake_pool.key().as_ref(), ANNOUNCED_CONFIG_SEED], bump)]
+    pub announced_config: Account<'info, AnnouncedConfigAccount>,
+    /// CHECK: nft_stake could be unstaked already
+    #[account(address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
+    pub nft_stake: UncheckedAccount<'info>,
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+    #[account(seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
+    pub user_stake_account: Account<'info, UserStakeAccount>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = VoteRecordAccount::DISCRIMINATOR.len() + VoteRecordAccount::INIT_SPACE,
+        seeds = [user_stake_account.key().as_ref(), VOTE_RECORD_SEED], bump,
+    )]
+    pub vote_record: Account<'info, VoteRecordAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Vote with the active stake a position held at the announcement, the tally is not reduced
+/// by later withdrawals
+pub fn process_vote_config(ctx: Context<VoteConfig>, approve: bool) -> Result<()> {
+    msg!("vote config {}", approve);
+
+    let stake_pool = &ctx.accounts.stake_pool;
+    let announced_config = &mut ctx.accounts.announced_config;
+    let user_stake = &ctx.accounts.user_stake_account;
+
+    require_gt!(
+        announced_config.quorum_rate,
+        0,
+        ErrorCode::GovernanceDisabled
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+    require_gt!(
+        stake_pool.config.config_review_time,
+        now - announced_config.timestamp,
+        ErrorCode::VotingEnded
+    );
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    require!(
+        vote_record.announced_config != announced_config.key()
+            || vote_record.announced_at != announced_config.timestamp,
+        ErrorCode::AlreadyVoted
+    );
+
+    // pending slashes count against the vote without settling the position
+    let mut settled = (**user_stake).clone();
+    if let Some(nft_stake) = load_nft_stake(&ctx.accounts.nft_stake, ctx.program_id)? {
+        settled.settle_slash(&nft_stake);
+    }
+    // stake added after the announcement could have voted already from another position
+    let weight = settled.vote_weight(announced_config.timestamp, now);
+    require_gt!(weight, 0, ErrorCode::VoteIneligible);
+
+    if approve {
+        announced_config.yes_votes += weight;
+    } else {
+        announced_config.no_votes += weight;
+    }
+
+    if vote_record.user_stake == Pubkey::default() {
+        vote_record.user_stake = user_stake.key();
+        vote_record.user = ctx.accounts.user.key();
+        vote_record.rent_payer = ctx.accounts.payer.key();
+    }
+    vote_record.announced_config = announced_config.key();
+    vote_record.announced_at = announced_config.timestamp;
+    vote_record.approve = approve;
+    vote_record.weight = weight;
+
+    Ok(())
+}
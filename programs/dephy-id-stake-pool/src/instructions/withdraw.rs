@@ -1,24 +1,41 @@
 use crate::{
-    constants::{POOL_WALLET_SEED, USER_STAKE_SEED},
+    constants::{ADMIN_SEED, POOL_WALLET_SEED, USER_POOL_STAKE_SEED, USER_STAKE_SEED},
     error::ErrorCode,
-    state::{NftStakeAccount, StakePoolAccount, UserStakeAccount},
+    state::{AdminAccount, FeeAccount, StakePoolAccount, UserPoolStakeAccount, UserStakeAccount},
+    utils::{find_stake_mint_stream, load_nft_stake, update_rewards},
 };
-use anchor_lang::{prelude::*, solana_program::system_program};
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{
+        burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
 };
 
+/// Remaining accounts: every reward stream of the pool (mut), and the vault of a stream
+/// in the stake mint (mut) to pay an early exit penalty nobody else shares in the nft stake
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
+    #[account(seeds = [ADMIN_SEED], bump)]
+    pub admin: Account<'info, AdminAccount>,
     #[account(mut, address = user_stake_account.stake_pool @ ErrorCode::InvalidAccount)]
     pub stake_pool: Account<'info, StakePoolAccount>,
     /// CHECK: nft_stake could be unstaked already
     #[account(mut, address = user_stake_account.nft_stake @ ErrorCode::InvalidAccount)]
     pub nft_stake: UncheckedAccount<'info>,
-    #[account(address = user_stake_account.user @ ErrorCode::InvalidAuthority)]
-    pub user: Signer<'info>,
+    /// CHECK: owner of the position
+    #[account(address = user_stake_account.user @ ErrorCode::InvalidAccount)]
+    pub user: UncheckedAccount<'info>,
+    /// The owner of the position, or a holder of the pool's receipts redeeming the stake
+    /// the position backs with receipts
+    pub authority: Signer<'info>,
     #[account(mut, seeds = [nft_stake.key().as_ref(), USER_STAKE_SEED, user.key.as_ref()], bump)]
     pub user_stake_account: Account<'info, UserStakeAccount>,
+    /// Can be left out once the user's principal in the pool is gone and the account closed
+    #[account(mut,
+        seeds = [stake_pool.key().as_ref(), USER_POOL_STAKE_SEED, user.key.as_ref()], bump
+    )]
+    pub user_pool_stake_account: Option<Account<'info, UserPoolStakeAccount>>,
     #[account(
         address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
         mint::token_program = token_program
@@ -31,23 +48,125 @@ pub struct Withdraw<'info> {
         token::token_program = token_program
     )]
     pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Any token account of the stake mint can receive the withdrawal
     #[account(
         mut,
         token::mint = stake_token_mint,
-        token::authority = user,
         token::token_program = token_program
     )]
     pub user_stake_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
     pub pool_wallet: SystemAccount<'info>,
+    /// CHECK: gets the rent back when the position is closed
+    #[account(mut, address = user_stake_account.rent_payer @ ErrorCode::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// CHECK: gets the rent back when the pool stake is closed, checked against it
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub user_pool_stake_rent_payer: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
+    /// Required when the position holds receipt tokens
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, Mint>>,
+    /// Receipts are burned from the signer's own account
+    #[account(mut, token::authority = authority)]
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
 }
 
-pub fn process_withdraw(ctx: Context<Withdraw>, maybe_amount: Option<u64>) -> Result<()> {
-    let user_stake = &mut ctx.accounts.user_stake_account;
+pub fn process_withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    maybe_amount: Option<u64>,
+) -> Result<()> {
+    // receipts are fungible, anyone holding them can redeem stake backed by receipts
+    let withdrawal = if ctx.accounts.authority.key() == ctx.accounts.user.key() {
+        Withdrawal::Owner
+    } else {
+        Withdrawal::Redeem
+    };
+    let accounts = ctx.accounts;
+    withdraw(
+        WithdrawAccounts {
+            admin: &accounts.admin,
+            stake_pool: &mut accounts.stake_pool,
+            nft_stake: &accounts.nft_stake,
+            user_stake: &mut accounts.user_stake_account,
+            user_pool_stake: accounts.user_pool_stake_account.as_mut(),
+            stake_token_mint: &accounts.stake_token_mint,
+            stake_token_account: accounts.stake_token_account.to_account_info(),
+            recipient_stake_token_account: accounts.user_stake_token_account.to_account_info(),
+            pool_wallet: accounts.pool_wallet.to_account_info(),
+            pool_wallet_bump: ctx.bumps.pool_wallet,
+            rent_payer: accounts.rent_payer.to_account_info(),
+            user_pool_stake_rent_payer: accounts
+                .user_pool_stake_rent_payer
+                .as_ref()
+                .map(|rent_payer| rent_payer.to_account_info()),
+            token_program: accounts.token_program.to_account_info(),
+            authority: accounts.authority.to_account_info(),
+            receipt_mint: accounts.receipt_mint.as_ref(),
+            receipt_token_account: accounts.receipt_token_account.as_ref(),
+            receipt_token_program: accounts.receipt_token_program.as_ref(),
+        },
+        maybe_amount,
+        withdrawal,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )
+}
+
+/// Who takes stake out through the shared withdraw path
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Withdrawal {
+    /// The owner of the position
+    Owner,
+    /// A receipt holder redeeming the stake backed by its receipts
+    Redeem,
+    /// A crank returning the stake of an unstaked nft to the owner
+    Refund,
+}
+
+/// Accounts shared by withdraw and refund_orphaned_stake, the authority burns the receipts
+pub(crate) struct WithdrawAccounts<'a, 'info> {
+    pub admin: &'a AdminAccount,
+    pub stake_pool: &'a mut Account<'info, StakePoolAccount>,
+    pub nft_stake: &'a AccountInfo<'info>,
+    pub user_stake: &'a mut Account<'info, UserStakeAccount>,
+    pub user_pool_stake: Option<&'a mut Account<'info, UserPoolStakeAccount>>,
+    pub stake_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub stake_token_account: AccountInfo<'info>,
+    pub recipient_stake_token_account: AccountInfo<'info>,
+    pub pool_wallet: AccountInfo<'info>,
+    pub pool_wallet_bump: u8,
+    pub rent_payer: AccountInfo<'info>,
+    pub user_pool_stake_rent_payer: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub receipt_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub receipt_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub receipt_token_program: Option<&'a Program<'info, Token2022>>,
+}
+
+pub(crate) fn withdraw<'info>(
+    accounts: WithdrawAccounts<'_, 'info>,
+    maybe_amount: Option<u64>,
+    withdrawal: Withdrawal,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let user_stake = accounts.user_stake;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let mut maybe_nft_stake = load_nft_stake(accounts.nft_stake, program_id)?;
+    user_stake.accumulate_stake(now);
+    if let Some(nft_stake) = maybe_nft_stake.as_mut() {
+        require!(!nft_stake.migrating, ErrorCode::NftStakeMigrating);
+
+        nft_stake.accumulate_stake(now);
+        user_stake.settle_slash(nft_stake);
+    }
 
     let amount = match maybe_amount {
         Some(amount) => amount,
@@ -56,56 +175,235 @@ pub fn process_withdraw(ctx: Context<Withdraw>, maybe_amount: Option<u64>) -> Re
 
     msg!("withdraw {}", amount);
 
-    let stake_pool = &mut ctx.accounts.stake_pool;
+    let redeeming = withdrawal == Withdrawal::Redeem;
+    if redeeming {
+        require_gte!(
+            user_stake.receipt_amount,
+            amount,
+            ErrorCode::InvalidAuthority
+        );
+    }
+
+    let stake_pool = accounts.stake_pool;
 
-    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    update_rewards(
+        stake_pool,
+        &mut [&mut **user_stake],
+        remaining_accounts,
+        program_id,
+        now,
+    )?;
+
+    // a position slashed to zero can still be closed
+    require!(
+        amount > 0 || user_stake.amount == 0,
+        ErrorCode::InvalidAmount
+    );
     require_gte!(user_stake.amount, amount, ErrorCode::InvalidAmount);
-    require_gte!(stake_pool.total_amount, amount, ErrorCode::InvalidAmount);
 
-    let nft_stake_account = &mut ctx.accounts.nft_stake;
-    if nft_stake_account.owner.ne(&system_program::id()) {
-        if nft_stake_account.owner.eq(ctx.program_id) {
-            let mut nft_stake_account_data = nft_stake_account.data.borrow_mut();
-            let mut nft_stake =
-                NftStakeAccount::try_deserialize(&mut nft_stake_account_data.as_ref())?;
+    // a redeemed or refunded position keeps its pending rewards for the owner to claim
+    // and close it
+    let has_pending_rewards = user_stake.has_pending_rewards(stake_pool);
+    if user_stake.amount == amount && withdrawal == Withdrawal::Owner {
+        require!(!has_pending_rewards, ErrorCode::RewardsNotClaimed);
+    }
+    let closing = user_stake.amount == amount && !has_pending_rewards;
+    let burn_amount = user_stake.receipt_burn_amount(amount, redeeming);
+
+    // depositors forced out by an unstaked nft or a sunset pool pay no penalty
+    let nft_unstaked = maybe_nft_stake
+        .as_ref()
+        .is_none_or(|nft_stake| nft_stake.unstaked);
+    let penalty = if nft_unstaked || stake_pool.sunset {
+        0
+    } else {
+        stake_pool
+            .config
+            .early_exit_penalty(amount, user_stake.last_deposit_timestamp, now)?
+    };
+    let remaining_amount = user_stake.amount - amount;
+
+    // with epochs active stake leaves only after deactivate_stake and the next boundary,
+    // the stake of an unstaked nft can leave at once
+    user_stake.activation.roll(now);
+    let unlocked = stake_pool.config.epoch_duration == 0 || nft_unstaked;
+    require_gte!(
+        user_stake
+            .activation
+            .withdrawable(user_stake.amount, unlocked),
+        amount,
+        ErrorCode::StakeNotDeactivated
+    );
+    let withdrawn = user_stake.activation.remove(amount);
+    stake_pool.activation.roll(now);
+    stake_pool.activation.subtract(&withdrawn);
 
-            require_gte!(nft_stake.amount, amount, ErrorCode::InvalidAmount);
-            nft_stake.amount -= amount;
+    let mut transfer_amount = amount;
+    let mut redistributed = false;
+    if let Some(nft_stake) = maybe_nft_stake.as_mut() {
+        nft_stake.activation.roll(now);
+        nft_stake.activation.subtract(&withdrawn);
 
-            nft_stake.try_serialize(&mut nft_stake_account_data.as_mut())?;
-        } else {
-            return Err(ErrorCode::InvalidAccount.into());
+        transfer_amount = nft_stake.release(amount, closing)?;
+
+        // the penalty stays staked and grows the other depositors' stakes
+        if penalty > 0 && nft_stake.redistribute(penalty, remaining_amount) {
+            user_stake.slash_factor = nft_stake.slash_factor;
+            redistributed = true;
         }
+
+        // a redistributed penalty never leaves the nft stake
+        nft_stake.total_withdrawn += if redistributed {
+            transfer_amount - penalty
+        } else {
+            transfer_amount
+        };
+        nft_stake.last_activity_timestamp = now;
+
+        nft_stake.try_serialize(&mut accounts.nft_stake.data.borrow_mut().as_mut())?;
+    }
+
+    require_gte!(
+        stake_pool.total_amount,
+        transfer_amount,
+        ErrorCode::InvalidAmount
+    );
+
+    let principal = user_stake.principal_of(amount)?;
+    user_stake.principal -= principal;
+    let mut user_pool_stake = accounts.user_pool_stake;
+    match user_pool_stake.as_mut() {
+        Some(user_pool_stake) => user_pool_stake.amount -= principal,
+        // rounding can leave a position without principal after the pool stake closed
+        None => require_eq!(principal, 0, ErrorCode::InvalidAccount),
     }
 
-    stake_pool.total_amount -= amount;
+    stake_pool.total_amount -= transfer_amount;
+    stake_pool.total_withdrawn += transfer_amount;
+    stake_pool.last_activity_timestamp = now;
     user_stake.amount -= amount;
 
+    let stake_pool_key = stake_pool.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        stake_pool_key.as_ref(),
+        POOL_WALLET_SEED,
+        &[accounts.pool_wallet_bump],
+    ]];
+
+    // without other depositors the penalty is paid to the pool's stakers through
+    // the stream in the stake mint, and waived when there is none
+    let mut penalty = penalty;
+    if redistributed {
+        stake_pool.total_amount += penalty;
+        stake_pool.total_withdrawn -= penalty;
+    } else if penalty > 0 {
+        let total_amount = stake_pool.active_amount(now);
+        let stream = find_stake_mint_stream(stake_pool, remaining_accounts, program_id, now)?;
+        match stream {
+            Some((reward_stream_info, mut reward_stream, vault)) if total_amount > 0 => {
+                let vault_amount_before =
+                    TokenAccount::try_deserialize(&mut vault.data.borrow().as_ref())?.amount;
+
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        accounts.token_program.clone(),
+                        TransferChecked {
+                            from: accounts.stake_token_account.clone(),
+                            mint: accounts.stake_token_mint.to_account_info(),
+                            to: vault.clone(),
+                            authority: accounts.pool_wallet.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    penalty,
+                    accounts.stake_token_mint.decimals,
+                )?;
+
+                // a transfer fee makes the vault receive less
+                let received = TokenAccount::try_deserialize(&mut vault.data.borrow().as_ref())?
+                    .amount
+                    - vault_amount_before;
+                reward_stream.distribute(received, total_amount);
+                reward_stream.try_serialize(&mut reward_stream_info.data.borrow_mut().as_mut())?;
+            }
+            _ => penalty = 0,
+        }
+    }
+    let transfer_amount = transfer_amount - penalty;
+
+    // the fee stays in stake_token_account until claimed, refunds leave no fee
+    let transfer_amount = if withdrawal == Withdrawal::Refund {
+        transfer_amount
+    } else {
+        let withdraw_fee_rate = stake_pool.config.withdraw_fee_rate;
+        stake_pool.collect_fee(accounts.admin, transfer_amount, withdraw_fee_rate)?
+    };
+
+    if burn_amount > 0 {
+        let (Some(receipt_mint), Some(receipt_token_account), Some(receipt_token_program)) = (
+            accounts.receipt_mint,
+            accounts.receipt_token_account,
+            accounts.receipt_token_program,
+        ) else {
+            return Err(ErrorCode::ReceiptAccountsRequired.into());
+        };
+
+        require!(
+            stake_pool.receipt_mint == Some(receipt_mint.key()),
+            ErrorCode::InvalidAccount
+        );
+
+        burn(
+            CpiContext::new(
+                receipt_token_program.to_account_info(),
+                Burn {
+                    mint: receipt_mint.to_account_info(),
+                    from: receipt_token_account.to_account_info(),
+                    authority: accounts.authority,
+                },
+            ),
+            burn_amount,
+        )?;
+
+        user_stake.receipt_amount -= burn_amount;
+    }
+
     // transfer tokens
     transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program,
             TransferChecked {
-                from: ctx.accounts.stake_token_account.to_account_info(),
-                mint: ctx.accounts.stake_token_mint.to_account_info(),
-                to: ctx.accounts.user_stake_token_account.to_account_info(),
-                authority: ctx.accounts.pool_wallet.to_account_info(),
+                from: accounts.stake_token_account,
+                mint: accounts.stake_token_mint.to_account_info(),
+                to: accounts.recipient_stake_token_account,
+                authority: accounts.pool_wallet,
             },
-            &[&[
-                stake_pool.key().as_ref(),
-                POOL_WALLET_SEED,
-                &[ctx.bumps.pool_wallet],
-            ]],
+            signer_seeds,
         ),
-        amount,
-        ctx.accounts.stake_token_mint.decimals,
+        transfer_amount,
+        accounts.stake_token_mint.decimals,
     )?;
 
-    // if remaining amount is zero, close user stake account
-    if user_stake.amount == 0 {
-        ctx.accounts
-            .user_stake_account
-            .close(ctx.accounts.payer.to_account_info())?;
+    // close the emptied user stake account, unless its rewards still wait for the owner
+    if closing {
+        user_stake.close(accounts.rent_payer)?;
+    }
+
+    // no principal left in the pool, close the user pool stake as well
+    if let Some(user_pool_stake) = user_pool_stake {
+        if user_pool_stake.amount == 0 {
+            let rent_payer = accounts
+                .user_pool_stake_rent_payer
+                .ok_or(ErrorCode::InvalidAccount)?;
+            require_keys_eq!(
+                rent_payer.key(),
+                user_pool_stake.rent_payer,
+                ErrorCode::InvalidAccount
+            );
+            user_pool_stake.close(rent_payer)?;
+
+            stake_pool.depositor_count -= 1;
+        }
     }
 
     Ok(())
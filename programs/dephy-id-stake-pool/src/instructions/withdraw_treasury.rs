@@ -0,0 +1,67 @@
+use crate::{
+    constants::{POOL_WALLET_SEED, TREASURY_SEED},
+    error::ErrorCode,
+    state::StakePoolAccount,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub stake_pool: Account<'info, StakePoolAccount>,
+    #[account(address = stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(
+        address = stake_pool.config.stake_token_mint @ ErrorCode::InvalidStakeToken,
+        mint::token_program = token_program
+    )]
+    pub stake_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [stake_pool.key().as_ref(), TREASURY_SEED], bump)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = stake_token_mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [stake_pool.key().as_ref(), POOL_WALLET_SEED], bump)]
+    pub pool_wallet: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Move slashed tokens out of the treasury, all of them when amount is None
+pub fn process_withdraw_treasury(
+    ctx: Context<WithdrawTreasury>,
+    maybe_amount: Option<u64>,
+) -> Result<()> {
+    let treasury_amount = ctx.accounts.treasury_token_account.amount;
+    let amount = maybe_amount.unwrap_or(treasury_amount);
+
+    msg!("withdraw treasury {}", amount);
+
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    require_gte!(treasury_amount, amount, ErrorCode::InvalidAmount);
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_token_account.to_account_info(),
+                mint: ctx.accounts.stake_token_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.pool_wallet.to_account_info(),
+            },
+            &[&[
+                ctx.accounts.stake_pool.key().as_ref(),
+                POOL_WALLET_SEED,
+                &[ctx.bumps.pool_wallet],
+            ]],
+        ),
+        amount,
+        ctx.accounts.stake_token_mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Account layouts of the first release (v0), only read by the migrate instructions.
+//! Each keeps the discriminator of its current account and is told apart by its length.
+
+use anchor_lang::prelude::*;
+
+#[derive(InitSpace, AnchorDeserialize)]
+pub struct AdminAccountV0 {
+    pub authority: Pubkey,
+}
+
+#[derive(InitSpace, AnchorDeserialize)]
+pub struct NftStakeAccountV0 {
+    pub stake_pool: Pubkey,
+    pub stake_authority: Pubkey,
+    pub deposit_authority: Pubkey,
+    pub nft_token_account: Pubkey,
+    pub amount: u64,
+    pub commision_rate: u8,
+}
+
+#[derive(InitSpace, AnchorDeserialize)]
+pub struct StakePoolAccountV0 {
+    pub authority: Pubkey,
+    pub announced_config: Option<Pubkey>,
+    pub config: StakePoolConfigV0,
+    pub stake_token_account: Pubkey,
+    pub total_amount: u64,
+}
+
+#[derive(InitSpace, AnchorDeserialize)]
+pub struct StakePoolConfigV0 {
+    pub collection: Pubkey,
+    pub stake_token_mint: Pubkey,
+    pub max_stake_amount: u64,
+    pub config_review_time: u64,
+}
+
+#[derive(InitSpace, AnchorDeserialize)]
+pub struct UserStakeAccountV0 {
+    pub stake_pool: Pubkey,
+    pub nft_stake: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub last_deposit_timestamp: u64,
+}
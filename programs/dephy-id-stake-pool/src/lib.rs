@@ -7,7 +7,9 @@ declare_id!("DSTKMXnJXgvViSkr6hciBaYsTpcduxZuF334WLrvEZmW");
 mod constants;
 mod error;
 mod instructions;
+mod legacy;
 mod state;
+mod utils;
 
 use instructions::*;
 use state::*;
@@ -20,6 +22,32 @@ pub mod dephy_id_stake_pool {
         process_initialize(ctx)
     }
 
+    /// Legacy accounts are migrated in order: admin, stake pools, nft stakes, user stakes,
+    /// then each nft stake is finalized once all of its user stakes are migrated
+    pub fn migrate_admin(ctx: Context<MigrateAdmin>) -> Result<()> {
+        process_migrate_admin(ctx)
+    }
+
+    pub fn migrate_stake_pool(ctx: Context<MigrateStakePool>) -> Result<()> {
+        process_migrate_stake_pool(ctx)
+    }
+
+    pub fn migrate_nft_stake(ctx: Context<MigrateNftStake>) -> Result<()> {
+        process_migrate_nft_stake(ctx)
+    }
+
+    pub fn migrate_user_stake(ctx: Context<MigrateUserStake>) -> Result<()> {
+        process_migrate_user_stake(ctx)
+    }
+
+    pub fn finalize_nft_stake_migration(ctx: Context<FinalizeNftStakeMigration>) -> Result<()> {
+        process_finalize_nft_stake_migration(ctx)
+    }
+
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, args: SetProtocolFeeArgs) -> Result<()> {
+        process_set_protocol_fee(ctx, args)
+    }
+
     pub fn create_stake_pool(
         ctx: Context<CreateStakePool>,
         args: StakePoolConfigArgs,
@@ -29,7 +57,7 @@ pub mod dephy_id_stake_pool {
 
     pub fn announce_update_config(
         ctx: Context<AnnounceUpdateConfig>,
-        args: StakePoolConfigArgs,
+        args: AnnounceUpdateConfigArgs,
     ) -> Result<()> {
         process_announce_update_config(ctx, args)
     }
@@ -42,24 +70,190 @@ pub mod dephy_id_stake_pool {
         process_confirm_update_config(ctx)
     }
 
-    // Will only support MplCore
+    /// With a governance quorum, positions vote on the announced config during the review time
+    pub fn vote_config(ctx: Context<VoteConfig>, approve: bool) -> Result<()> {
+        process_vote_config(ctx, approve)
+    }
+
+    pub fn veto_update_config(ctx: Context<VetoUpdateConfig>) -> Result<()> {
+        process_veto_update_config(ctx)
+    }
+
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        process_close_vote_record(ctx)
+    }
+
+    pub fn close_config_change(ctx: Context<CloseConfigChange>) -> Result<()> {
+        process_close_config_change(ctx)
+    }
+
+    pub fn sunset_stake_pool(ctx: Context<SunsetStakePool>) -> Result<()> {
+        process_sunset_stake_pool(ctx)
+    }
+
+    pub fn close_stake_pool(ctx: Context<CloseStakePool>) -> Result<()> {
+        process_close_stake_pool(ctx)
+    }
+
     pub fn create_nft_stake(ctx: Context<CreateNftStake>, args: CreateNftStakeArgs) -> Result<()> {
         process_create_nft_stake(ctx, args)
     }
 
+    /// Closes the nft stake when nobody deposited in it, otherwise it is only marked unstaked
+    /// so the depositors left can settle slashes against it. close_nft_stake takes it back
+    /// after the last of them withdrew
     pub fn unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
         process_unstake_nft(ctx)
     }
 
+    pub fn force_unstake_nft(ctx: Context<ForceUnstakeNft>) -> Result<()> {
+        process_force_unstake_nft(ctx)
+    }
+
+    /// SPL Token and Token-2022 nfts are escrowed instead of frozen, one pool at a time
+    pub fn create_token_nft_stake(
+        ctx: Context<CreateTokenNftStake>,
+        args: CreateTokenNftStakeArgs,
+    ) -> Result<()> {
+        process_create_token_nft_stake(ctx, args)
+    }
+
+    pub fn unstake_token_nft(ctx: Context<UnstakeTokenNft>) -> Result<()> {
+        process_unstake_token_nft(ctx)
+    }
+
+    pub fn force_unstake_token_nft(ctx: Context<ForceUnstakeTokenNft>) -> Result<()> {
+        process_force_unstake_token_nft(ctx)
+    }
+
+    pub fn transfer_nft_stake(ctx: Context<TransferNftStake>) -> Result<()> {
+        process_transfer_nft_stake(ctx)
+    }
+
     pub fn close_nft_stake(ctx: Context<CloseNftStake>) -> Result<()> {
         process_close_nft_stake(ctx)
     }
 
+    pub fn create_receipt_mint(ctx: Context<CreateReceiptMint>) -> Result<()> {
+        process_create_receipt_mint(ctx)
+    }
+
     pub fn deposit_token(ctx: Context<Deposit>, amount: Option<u64>) -> Result<()> {
         process_deposit(ctx, amount)
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>, amount: Option<u64>) -> Result<()> {
+    /// Third-party deposits leave the beneficiary's last deposit timestamp as is
+    pub fn deposit_for(ctx: Context<DepositFor>, amount: Option<u64>) -> Result<()> {
+        process_deposit_for(ctx, amount)
+    }
+
+    /// Deactivated stake stops earning at the next epoch boundary and can be withdrawn from then
+    pub fn deactivate_stake(ctx: Context<DeactivateStake>, amount: Option<u64>) -> Result<()> {
+        process_deactivate_stake(ctx, amount)
+    }
+
+    /// Receipts of a pool are fungible, a holder can redeem them against any position of the
+    /// pool with stake backed by receipts. The owner keeps the rest of the position and its
+    /// rewards, and their own receipts redeem against other positions in turn
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        amount: Option<u64>,
+    ) -> Result<()> {
         process_withdraw(ctx, amount)
     }
+
+    pub fn redelegate(ctx: Context<Redelegate>, amount: Option<u64>) -> Result<()> {
+        process_redelegate(ctx, amount)
+    }
+
+    /// Permissionless, refunds a depositor of an unstaked nft to their associated token accounts,
+    /// stake backed by receipts is left for their holders to withdraw. The nft stake may be
+    /// kept by unstake_nft or closed already
+    pub fn refund_orphaned_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundOrphanedStake<'info>>,
+    ) -> Result<()> {
+        process_refund_orphaned_stake(ctx)
+    }
+
+    pub fn claim_pool_fees(ctx: Context<ClaimFees>) -> Result<()> {
+        process_claim_pool_fees(ctx)
+    }
+
+    pub fn claim_protocol_fees(ctx: Context<ClaimFees>) -> Result<()> {
+        process_claim_protocol_fees(ctx)
+    }
+
+    pub fn get_stake_average(ctx: Context<GetStakeAverage>, days: u8) -> Result<StakeAverage> {
+        process_get_stake_average(ctx, days)
+    }
+
+    pub fn slash_nft_stake(ctx: Context<SlashNftStake>, args: SlashNftStakeArgs) -> Result<()> {
+        process_slash_nft_stake(ctx, args)
+    }
+
+    /// Slashed tokens collect in the treasury until the pool authority withdraws them
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: Option<u64>) -> Result<()> {
+        process_withdraw_treasury(ctx, amount)
+    }
+
+    pub fn post_device_score(
+        ctx: Context<PostDeviceScore>,
+        args: PostDeviceScoreArgs,
+    ) -> Result<()> {
+        process_post_device_score(ctx, args)
+    }
+
+    pub fn get_device_score(ctx: Context<GetDeviceScore>) -> Result<DeviceScoreAccount> {
+        process_get_device_score(ctx)
+    }
+
+    pub fn close_device_score(ctx: Context<CloseDeviceScore>) -> Result<()> {
+        process_close_device_score(ctx)
+    }
+
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        args: CreateDistributorArgs,
+    ) -> Result<()> {
+        process_create_distributor(ctx, args)
+    }
+
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        args: ClaimDistributionArgs,
+    ) -> Result<()> {
+        process_claim_distribution(ctx, args)
+    }
+
+    pub fn close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
+        process_close_distributor(ctx)
+    }
+
+    pub fn create_reward_stream(
+        ctx: Context<CreateRewardStream>,
+        args: CreateRewardStreamArgs,
+    ) -> Result<()> {
+        process_create_reward_stream(ctx, args)
+    }
+
+    pub fn close_reward_stream(ctx: Context<CloseRewardStream>) -> Result<()> {
+        process_close_reward_stream(ctx)
+    }
+
+    pub fn claim_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRewards<'info>>,
+    ) -> Result<()> {
+        process_claim_rewards(ctx)
+    }
+
+    pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+        process_set_auto_compound(ctx, enabled)
+    }
+
+    /// Permissionless for positions with auto compound, pays the cranker a bounty from the rewards
+    pub fn compound_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompoundRewards<'info>>,
+    ) -> Result<()> {
+        process_compound_rewards(ctx)
+    }
 }
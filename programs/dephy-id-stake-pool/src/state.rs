@@ -1,9 +1,48 @@
+use crate::constants::{
+    MAX_ASSET_LOCKS, MAX_ATTRIBUTE_LEN, MAX_ATTRIBUTE_RULES, MAX_ATTRIBUTE_VALUES,
+    MAX_CONFIG_CHANGES, MAX_EXTRA_COLLECTIONS, MAX_FEE_RATE, MAX_REWARD_STREAMS, MAX_SLASH_FACTOR,
+    REWARD_CHECKPOINTS, REWARD_PRECISION, SECONDS_PER_DAY, STAKE_HISTORY_DAYS,
+};
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(InitSpace)]
 pub struct AdminAccount {
     pub authority: Pubkey,
+    /// Share of every pool fee going to the protocol, in basis points
+    pub protocol_fee_rate: u16,
+    pub protocol_fee_authority: Pubkey,
+}
+
+impl AdminAccount {
+    /// Split a fee into the pool and protocol parts
+    pub fn split_fee(&self, fee: u64) -> Result<(u64, u64)> {
+        let protocol_fee =
+            crate::utils::mul_div(fee, self.protocol_fee_rate as u64, MAX_FEE_RATE as u64)?;
+        Ok((fee - protocol_fee, protocol_fee))
+    }
+}
+
+/// Accounts keeping the pool and protocol fees they charged until claimed
+pub trait FeeAccount {
+    fn fees_mut(&mut self) -> (&mut u64, &mut u64);
+
+    /// Charge a fee in basis points of amount, returns what is left
+    fn collect_fee(&mut self, admin: &AdminAccount, amount: u64, fee_rate: u16) -> Result<u64> {
+        let fee = crate::utils::mul_div(amount, fee_rate as u64, MAX_FEE_RATE as u64)?;
+        let (pool_fee, protocol_fee) = admin.split_fee(fee)?;
+        let (pool_fees, protocol_fees) = self.fees_mut();
+        *pool_fees += pool_fee;
+        *protocol_fees += protocol_fee;
+
+        Ok(amount - fee)
+    }
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct SetProtocolFeeArgs {
+    pub protocol_fee_rate: u16,
+    pub protocol_fee_authority: Pubkey,
 }
 
 #[account]
@@ -12,9 +51,145 @@ pub struct NftStakeAccount {
     pub stake_pool: Pubkey,
     pub stake_authority: Pubkey,
     pub deposit_authority: Pubkey,
+    /// The mpl core asset, or the mint of a token nft
     pub nft_token_account: Pubkey,
+    pub collection: Pubkey,
     pub amount: u64,
     pub commision_rate: u8,
+    pub depositor_count: u32,
+    /// Cumulative growth of deposits from slashing and redistributed penalties,
+    /// scaled by SLASH_FACTOR_PRECISION
+    pub slash_factor: u128,
+    /// The asset was unstaked with depositors left, they still settle against slash_factor
+    pub unstaked: bool,
+    pub stake_history: StakeHistory,
+    pub activation: StakeActivation,
+    /// Receives the rent back when the account is closed
+    pub rent_payer: Pubkey,
+    pub asset_standard: AssetStandard,
+    /// Cumulative stake entering the nft stake's positions through deposits, redelegations and
+    /// compounded rewards, and leaving them through withdrawals, redelegations and slashing.
+    /// Penalties redistributed to the other depositors stay, so that
+    /// amount == total_deposited - total_withdrawn - total_slashed
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub total_slashed: u64,
+    /// Last time a position of the nft stake changed
+    pub last_activity_timestamp: u64,
+    /// Legacy user stakes are still being migrated, positions stay frozen until
+    /// finalize_nft_stake_migration
+    pub migrating: bool,
+}
+
+/// How the staked asset is held: mpl core assets are frozen in place,
+/// token nfts are escrowed by the pool wallet
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize,
+)]
+pub enum AssetStandard {
+    #[default]
+    MplCore,
+    /// SPL Token or Token-2022 mint in a verified Token Metadata collection
+    TokenMetadata,
+    /// Token-2022 mint whose group member extension points to the collection group
+    Token2022,
+}
+
+impl NftStakeAccount {
+    /// Stake earning at now, pending and inactive stake do not count
+    pub fn active_amount(&self, now: u64) -> u64 {
+        self.activation.active_at(self.amount, now)
+    }
+
+    pub fn accumulate_stake(&mut self, now: u64) {
+        self.stake_history
+            .accumulate_activation(&self.activation, self.amount, now);
+    }
+
+    /// Remove a depositor's amount, the last depositor also takes the rounding dust left by slashing
+    pub fn release(&mut self, amount: u64, closing: bool) -> Result<u64> {
+        require_gte!(self.amount, amount, crate::error::ErrorCode::InvalidAmount);
+        self.amount -= amount;
+
+        let mut released = amount;
+        if closing {
+            self.depositor_count = self
+                .depositor_count
+                .checked_sub(1)
+                .ok_or(crate::error::ErrorCode::InvalidAccount)?;
+
+            if self.depositor_count == 0 {
+                released += self.amount;
+                self.amount = 0;
+            }
+        }
+
+        Ok(released)
+    }
+
+    /// Grow every stake except excluded_amount by their share of amount, returns false when
+    /// nobody else is left to share with or the slash factor would pass MAX_SLASH_FACTOR
+    pub fn redistribute(&mut self, amount: u64, excluded_amount: u64) -> bool {
+        let others = self.amount - excluded_amount;
+        if others == 0 {
+            return false;
+        }
+
+        let slash_factor = match self.slash_factor.checked_mul((others + amount) as u128) {
+            Some(value) => value / others as u128,
+            None => return false,
+        };
+        if slash_factor > MAX_SLASH_FACTOR {
+            return false;
+        }
+
+        self.slash_factor = slash_factor;
+        self.amount += amount;
+
+        true
+    }
+}
+
+/// Holds the freeze of an asset for every pool it is staked in
+#[account]
+#[derive(InitSpace)]
+pub struct AssetLockAccount {
+    pub asset: Pubkey,
+    /// The asset thaws when the last pool releases it
+    #[max_len(MAX_ASSET_LOCKS)]
+    pub stake_pools: Vec<Pubkey>,
+    /// Receives the rent back when the account is closed
+    pub rent_payer: Pubkey,
+}
+
+impl AssetLockAccount {
+    pub fn lock(&mut self, stake_pool: Pubkey) -> Result<()> {
+        use crate::error::ErrorCode;
+
+        require!(
+            !self.stake_pools.contains(&stake_pool),
+            ErrorCode::AssetAlreadyLocked
+        );
+        require_gt!(
+            MAX_ASSET_LOCKS,
+            self.stake_pools.len(),
+            ErrorCode::AssetLocksFull
+        );
+        self.stake_pools.push(stake_pool);
+
+        Ok(())
+    }
+
+    pub fn release(&mut self, stake_pool: &Pubkey) -> Result<()> {
+        let index = self
+            .stake_pools
+            .iter()
+            .position(|pool| pool == stake_pool)
+            .ok_or(crate::error::ErrorCode::InvalidAccount)?;
+        self.stake_pools.swap_remove(index);
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -22,6 +197,12 @@ pub struct CreateNftStakeArgs {
     pub commision_rate: u8,
 }
 
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTokenNftStakeArgs {
+    pub commision_rate: u8,
+    pub asset_standard: AssetStandard,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct StakePoolAccount {
@@ -30,6 +211,46 @@ pub struct StakePoolAccount {
     pub config: StakePoolConfig,
     pub stake_token_account: Pubkey,
     pub total_amount: u64,
+    /// Receipts can change hands, whoever holds them redeems the stake they back with withdraw
+    pub receipt_mint: Option<Pubkey>,
+    /// No new stakes or deposits once set
+    pub sunset: bool,
+    /// Nft stakes not unstaked yet
+    pub nft_stake_count: u64,
+    pub reward_vault_count: u32,
+    pub reward_streams: [Option<Pubkey>; MAX_REWARD_STREAMS],
+    pub next_reward_stream_id: u64,
+    /// Bumped by every confirmed config change
+    pub config_version: u64,
+    /// Unclaimed fees held in stake_token_account, not part of total_amount
+    pub pool_fees: u64,
+    pub protocol_fees: u64,
+    pub activation: StakeActivation,
+    /// Users with principal in the pool, one per UserPoolStakeAccount
+    pub depositor_count: u64,
+    /// Cumulative stake entering the pool through deposits and compounded rewards, and leaving
+    /// it through withdrawals and slashing, so that
+    /// total_amount == total_deposited - total_withdrawn - total_slashed
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub total_slashed: u64,
+    /// Last time a stake in the pool changed
+    pub last_activity_timestamp: u64,
+    /// Timestamp of the latest announced config
+    pub announced_at: u64,
+}
+
+impl FeeAccount for StakePoolAccount {
+    fn fees_mut(&mut self) -> (&mut u64, &mut u64) {
+        (&mut self.pool_fees, &mut self.protocol_fees)
+    }
+}
+
+impl StakePoolAccount {
+    /// Stake earning at now, used to share rewards
+    pub fn active_amount(&self, now: u64) -> u64 {
+        self.activation.active_at(self.total_amount, now)
+    }
 }
 
 #[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -38,12 +259,301 @@ pub struct StakePoolConfig {
     pub stake_token_mint: Pubkey,
     pub max_stake_amount: u64,
     pub config_review_time: u64,
+    pub slasher: Option<Pubkey>,
+    pub score_oracle: Option<Pubkey>,
+    pub max_score_age: u64,
+    pub max_total_amount: Option<u64>,
+    /// Limits the principal a user has in the pool across all positions
+    pub max_per_user: Option<u64>,
+    pub min_deposit_amount: u64,
+    /// Fees in basis points, split with the protocol by AdminAccount.protocol_fee_rate
+    pub deposit_fee_rate: u16,
+    pub withdraw_fee_rate: u16,
+    pub reward_fee_rate: u16,
+    /// Penalty in basis points, decaying to zero over early_exit_period after the last deposit
+    pub early_exit_penalty_rate: u16,
+    pub early_exit_period: u64,
+    /// Deposits activate at the next multiple of epoch_duration, 0 activates them at once
+    pub epoch_duration: u64,
+    pub extra_collections: [Option<EligibleCollection>; MAX_EXTRA_COLLECTIONS],
+    /// Every rule must match the asset Attributes plugin, checked when an nft stake is created
+    #[max_len(MAX_ATTRIBUTE_RULES)]
+    pub attribute_rules: Vec<AttributeRule>,
+    /// Announced configs need votes of this share of the active stake in basis points,
+    /// 0 leaves config changes to the pool authority
+    pub governance_quorum_rate: u16,
+}
+
+#[derive(Debug, Clone, Copy, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct EligibleCollection {
+    pub collection: Pubkey,
+    /// Overrides StakePoolConfig.max_stake_amount for nft stakes of this collection
+    pub max_stake_amount: Option<u64>,
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct AttributeRule {
+    #[max_len(MAX_ATTRIBUTE_LEN)]
+    pub key: String,
+    pub condition: AttributeCondition,
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub enum AttributeCondition {
+    Equals(#[max_len(MAX_ATTRIBUTE_LEN)] String),
+    InSet(#[max_len(MAX_ATTRIBUTE_VALUES, MAX_ATTRIBUTE_LEN)] Vec<String>),
+    Prefix(#[max_len(MAX_ATTRIBUTE_LEN)] String),
+}
+
+impl AttributeRule {
+    pub fn validate(&self) -> Result<()> {
+        use crate::error::ErrorCode;
+
+        require!(!self.key.is_empty(), ErrorCode::InvalidConfig);
+        require_gte!(MAX_ATTRIBUTE_LEN, self.key.len(), ErrorCode::InvalidConfig);
+        let values = match &self.condition {
+            AttributeCondition::Equals(value) | AttributeCondition::Prefix(value) => {
+                std::slice::from_ref(value)
+            }
+            AttributeCondition::InSet(values) => {
+                require!(!values.is_empty(), ErrorCode::InvalidConfig);
+                require_gte!(MAX_ATTRIBUTE_VALUES, values.len(), ErrorCode::InvalidConfig);
+                values.as_slice()
+            }
+        };
+        for value in values {
+            require_gte!(MAX_ATTRIBUTE_LEN, value.len(), ErrorCode::InvalidConfig);
+        }
+
+        Ok(())
+    }
+
+    pub fn matches(&self, attributes: &[mpl_core::types::Attribute]) -> bool {
+        attributes
+            .iter()
+            .filter(|attribute| attribute.key == self.key)
+            .any(|attribute| match &self.condition {
+                AttributeCondition::Equals(value) => attribute.value == *value,
+                AttributeCondition::InSet(values) => values.contains(&attribute.value),
+                AttributeCondition::Prefix(prefix) => attribute.value.starts_with(prefix.as_str()),
+            })
+    }
+}
+
+impl StakePoolConfig {
+    pub fn validate(&self) -> Result<()> {
+        use crate::error::ErrorCode;
+
+        require_gt!(self.config_review_time, 0, ErrorCode::InvalidConfig);
+        require_gt!(self.max_stake_amount, 0, ErrorCode::InvalidConfig);
+        if self.score_oracle.is_some() {
+            require_gt!(self.max_score_age, 0, ErrorCode::InvalidConfig);
+        }
+        if let Some(max_per_user) = self.max_per_user {
+            require_gte!(
+                max_per_user,
+                self.min_deposit_amount,
+                ErrorCode::InvalidConfig
+            );
+        }
+        require_gte!(
+            MAX_FEE_RATE,
+            self.deposit_fee_rate,
+            ErrorCode::InvalidConfig
+        );
+        require_gte!(
+            MAX_FEE_RATE,
+            self.withdraw_fee_rate,
+            ErrorCode::InvalidConfig
+        );
+        require_gte!(MAX_FEE_RATE, self.reward_fee_rate, ErrorCode::InvalidConfig);
+        require_gte!(
+            MAX_FEE_RATE,
+            self.early_exit_penalty_rate,
+            ErrorCode::InvalidConfig
+        );
+        require_gte!(
+            MAX_FEE_RATE,
+            self.governance_quorum_rate,
+            ErrorCode::InvalidConfig
+        );
+        for eligible in self.extra_collections.iter().flatten() {
+            require_keys_neq!(
+                eligible.collection,
+                self.collection,
+                ErrorCode::InvalidCollection
+            );
+            if let Some(max_stake_amount) = eligible.max_stake_amount {
+                require_gt!(max_stake_amount, 0, ErrorCode::InvalidConfig);
+            }
+        }
+        require_gte!(
+            MAX_ATTRIBUTE_RULES,
+            self.attribute_rules.len(),
+            ErrorCode::InvalidConfig
+        );
+        for rule in self.attribute_rules.iter() {
+            rule.validate()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&mut self, change: &ConfigChange) -> Result<()> {
+        match *change {
+            ConfigChange::MaxStakeAmount(value) => self.max_stake_amount = value,
+            ConfigChange::ConfigReviewTime(value) => self.config_review_time = value,
+            ConfigChange::Slasher(value) => self.slasher = value,
+            ConfigChange::ScoreOracle(value) => self.score_oracle = value,
+            ConfigChange::MaxScoreAge(value) => self.max_score_age = value,
+            ConfigChange::MaxTotalAmount(value) => self.max_total_amount = value,
+            ConfigChange::MaxPerUser(value) => self.max_per_user = value,
+            ConfigChange::MinDepositAmount(value) => self.min_deposit_amount = value,
+            ConfigChange::DepositFeeRate(value) => self.deposit_fee_rate = value,
+            ConfigChange::WithdrawFeeRate(value) => self.withdraw_fee_rate = value,
+            ConfigChange::RewardFeeRate(value) => self.reward_fee_rate = value,
+            ConfigChange::EarlyExitPenaltyRate(value) => self.early_exit_penalty_rate = value,
+            ConfigChange::EarlyExitPeriod(value) => self.early_exit_period = value,
+            ConfigChange::EpochDuration(value) => self.epoch_duration = value,
+            ConfigChange::SetExtraCollection(eligible) => {
+                let slot = match self.extra_collection_index(&eligible.collection) {
+                    Some(index) => index,
+                    None => self
+                        .extra_collections
+                        .iter()
+                        .position(|slot| slot.is_none())
+                        .ok_or(crate::error::ErrorCode::InvalidConfig)?,
+                };
+                self.extra_collections[slot] = Some(eligible);
+            }
+            ConfigChange::RemoveExtraCollection(collection) => {
+                let index = self
+                    .extra_collection_index(&collection)
+                    .ok_or(crate::error::ErrorCode::InvalidCollection)?;
+                self.extra_collections[index] = None;
+            }
+            ConfigChange::SetAttributeRule(ref rule) => {
+                match self.attribute_rules.iter().position(|r| r.key == rule.key) {
+                    Some(index) => self.attribute_rules[index] = rule.clone(),
+                    None => self.attribute_rules.push(rule.clone()),
+                }
+            }
+            ConfigChange::RemoveAttributeRule(ref key) => {
+                let index = self
+                    .attribute_rules
+                    .iter()
+                    .position(|rule| rule.key == *key)
+                    .ok_or(crate::error::ErrorCode::InvalidConfig)?;
+                self.attribute_rules.remove(index);
+            }
+            ConfigChange::GovernanceQuorumRate(value) => self.governance_quorum_rate = value,
+        }
+
+        Ok(())
+    }
+
+    fn extra_collection_index(&self, collection: &Pubkey) -> Option<usize> {
+        self.extra_collections.iter().position(|slot| {
+            slot.as_ref()
+                .is_some_and(|eligible| eligible.collection == *collection)
+        })
+    }
+
+    pub fn is_eligible(&self, collection: &Pubkey) -> bool {
+        *collection == self.collection || self.extra_collection_index(collection).is_some()
+    }
+
+    pub fn attributes_match(&self, attributes: &[mpl_core::types::Attribute]) -> bool {
+        self.attribute_rules
+            .iter()
+            .all(|rule| rule.matches(attributes))
+    }
+
+    /// None when the collection is no longer eligible
+    pub fn max_stake_amount_for(&self, collection: &Pubkey) -> Option<u64> {
+        if *collection == self.collection {
+            return Some(self.max_stake_amount);
+        }
+
+        let eligible = self.extra_collections[self.extra_collection_index(collection)?]?;
+        Some(eligible.max_stake_amount.unwrap_or(self.max_stake_amount))
+    }
+
+    /// Start of the next epoch, 0 when epochs are disabled
+    pub fn activation_time(&self, now: u64) -> u64 {
+        if self.epoch_duration == 0 {
+            return 0;
+        }
+
+        (now / self.epoch_duration + 1) * self.epoch_duration
+    }
+
+    pub fn early_exit_penalty(
+        &self,
+        amount: u64,
+        last_deposit_timestamp: u64,
+        now: u64,
+    ) -> Result<u64> {
+        let unlock_time = last_deposit_timestamp + self.early_exit_period;
+        if now >= unlock_time {
+            return Ok(0);
+        }
+
+        let max_penalty = crate::utils::mul_div(
+            amount,
+            self.early_exit_penalty_rate as u64,
+            MAX_FEE_RATE as u64,
+        )?;
+        crate::utils::mul_div(max_penalty, unlock_time - now, self.early_exit_period)
+    }
+}
+
+/// One mutable field of StakePoolConfig, collection and stake_token_mint are fixed
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigChange {
+    MaxStakeAmount(u64),
+    ConfigReviewTime(u64),
+    Slasher(Option<Pubkey>),
+    ScoreOracle(Option<Pubkey>),
+    MaxScoreAge(u64),
+    MaxTotalAmount(Option<u64>),
+    MaxPerUser(Option<u64>),
+    MinDepositAmount(u64),
+    DepositFeeRate(u16),
+    WithdrawFeeRate(u16),
+    RewardFeeRate(u16),
+    EarlyExitPenaltyRate(u16),
+    EarlyExitPeriod(u64),
+    EpochDuration(u64),
+    /// Add an eligible collection or update an existing one
+    SetExtraCollection(EligibleCollection),
+    /// Existing nft stakes of the collection can still unstake but take no more deposits
+    RemoveExtraCollection(Pubkey),
+    /// Add an attribute rule or replace the one with the same key,
+    /// existing nft stakes are not checked again
+    SetAttributeRule(AttributeRule),
+    RemoveAttributeRule(#[max_len(MAX_ATTRIBUTE_LEN)] String),
+    /// Applies from the next announcement, 0 turns governance off
+    GovernanceQuorumRate(u16),
 }
 
 #[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct StakePoolConfigArgs {
     pub config_review_time: u64,
     pub max_stake_amount: u64,
+    pub slasher: Option<Pubkey>,
+    pub score_oracle: Option<Pubkey>,
+    pub max_score_age: u64,
+    pub max_total_amount: Option<u64>,
+    pub max_per_user: Option<u64>,
+    pub min_deposit_amount: u64,
+    pub deposit_fee_rate: u16,
+    pub withdraw_fee_rate: u16,
+    pub reward_fee_rate: u16,
+    pub early_exit_penalty_rate: u16,
+    pub early_exit_period: u64,
+    pub epoch_duration: u64,
+    pub governance_quorum_rate: u16,
 }
 
 #[account]
@@ -52,7 +562,84 @@ pub struct AnnouncedConfigAccount {
     pub stake_pool: Pubkey,
     pub authority: Pubkey,
     pub timestamp: u64,
-    pub config: StakePoolConfigArgs,
+    /// The config_version the changes were validated against
+    pub config_version: u64,
+    #[max_len(MAX_CONFIG_CHANGES)]
+    pub changes: Vec<ConfigChange>,
+    /// The pool's governance_quorum_rate at the announcement, 0 without governance
+    pub quorum_rate: u16,
+    /// Active stake of the pool at the announcement, the quorum is a share of it
+    pub total_stake: u64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    /// Receives the rent back when the announcement is vetoed
+    pub rent_payer: Pubkey,
+}
+
+impl AnnouncedConfigAccount {
+    fn quorum(&self) -> Result<u64> {
+        crate::utils::mul_div(
+            self.total_stake,
+            self.quorum_rate as u64,
+            MAX_FEE_RATE as u64,
+        )
+    }
+
+    /// Votes for reached the quorum and outweigh votes against,
+    /// without stake at the announcement there is nobody to ask
+    pub fn is_approved(&self) -> Result<bool> {
+        if self.quorum_rate == 0 || self.total_stake == 0 {
+            return Ok(true);
+        }
+
+        Ok(self.yes_votes >= self.quorum()? && self.yes_votes > self.no_votes)
+    }
+
+    /// Votes against reached the quorum and outweigh votes for
+    pub fn is_vetoed(&self) -> Result<bool> {
+        if self.quorum_rate == 0 {
+            return Ok(false);
+        }
+
+        Ok(self.no_votes >= self.quorum()? && self.no_votes > self.yes_votes)
+    }
+}
+
+/// A position's vote on an announced config, reused for later announcements
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecordAccount {
+    pub user_stake: Pubkey,
+    pub user: Pubkey,
+    pub announced_config: Pubkey,
+    /// Timestamp of the announcement voted on, the announced config account is reused
+    pub announced_at: u64,
+    pub approve: bool,
+    /// Active stake of the position when it voted
+    pub weight: u64,
+    /// Receives the rent back when the record is closed
+    pub rent_payer: Pubkey,
+}
+
+/// Applied config changes, one account per config_version
+#[account]
+#[derive(InitSpace)]
+pub struct ConfigChangeAccount {
+    pub stake_pool: Pubkey,
+    pub config_version: u64,
+    pub authority: Pubkey,
+    pub announced_at: u64,
+    pub applied_at: u64,
+    #[max_len(MAX_CONFIG_CHANGES)]
+    pub changes: Vec<ConfigChange>,
+    /// Gets the rent back once the pool is closed
+    pub rent_payer: Pubkey,
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct AnnounceUpdateConfigArgs {
+    #[max_len(MAX_CONFIG_CHANGES)]
+    pub changes: Vec<ConfigChange>,
 }
 
 #[account]
@@ -63,4 +650,620 @@ pub struct UserStakeAccount {
     pub user: Pubkey,
     pub amount: u64,
     pub last_deposit_timestamp: u64,
+    /// The nft stake slash_factor at the last settlement
+    pub slash_factor: u128,
+    /// Part of amount backed by receipt tokens, burned on withdraw
+    pub receipt_amount: u64,
+    /// Reward checkpoints, one per reward stream slot of the pool
+    pub rewards: [UserReward; MAX_REWARD_STREAMS],
+    /// Tracks the settled amount, pending slashes only show up after settlement
+    pub stake_history: StakeHistory,
+    /// Receives the rent back when the account is closed
+    pub rent_payer: Pubkey,
+    /// Deposited amount not yet withdrawn, counted against max_per_user
+    pub principal: u64,
+    pub activation: StakeActivation,
+    /// Lets anyone compound rewards in the stake mint into the stake
+    pub auto_compound: bool,
+    /// Amount held before the position first grew after the announcement at
+    /// vote_snapshot_time, stake added later does not vote on it
+    pub vote_snapshot_amount: u64,
+    pub vote_snapshot_time: u64,
+}
+
+impl UserStakeAccount {
+    /// Stake earning at now, pending and inactive stake do not count
+    pub fn active_amount(&self, now: u64) -> u64 {
+        self.activation.active_at(self.amount, now)
+    }
+
+    pub fn accumulate_stake(&mut self, now: u64) {
+        self.stake_history
+            .accumulate_activation(&self.activation, self.amount, now);
+    }
+
+    /// Checkpoint the rewards of a stream brought up to now
+    pub fn accrue_rewards(&mut self, index: usize, reward_stream: &RewardStreamAccount, now: u64) {
+        self.rewards[index].accrue(reward_stream, &self.activation, self.amount, now);
+    }
+
+    /// Must be called with the settled amount before the position grows while a config
+    /// is announced
+    pub fn snapshot_vote(&mut self, stake_pool: &StakePoolAccount) {
+        if stake_pool.announced_config.is_some()
+            && self.vote_snapshot_time != stake_pool.announced_at
+        {
+            self.vote_snapshot_amount = self.amount;
+            self.vote_snapshot_time = stake_pool.announced_at;
+        }
+    }
+
+    /// Active stake already held at the announcement
+    pub fn vote_weight(&self, announced_at: u64, now: u64) -> u64 {
+        let weight = self.active_amount(now);
+        if self.vote_snapshot_time == announced_at {
+            weight.min(self.vote_snapshot_amount)
+        } else {
+            weight
+        }
+    }
+
+    /// Pending rewards of the pool's current streams, checkpoints must be up to date
+    pub fn has_pending_rewards(&self, stake_pool: &StakePoolAccount) -> bool {
+        stake_pool
+            .reward_streams
+            .iter()
+            .zip(self.rewards.iter())
+            .any(|(reward_stream, reward)| reward_stream.is_some() && reward.pending > 0)
+    }
+
+    /// Principal backing part of the settled amount, all of it when the position closes
+    pub fn principal_of(&self, amount: u64) -> Result<u64> {
+        if amount == self.amount {
+            Ok(self.principal)
+        } else {
+            crate::utils::mul_div(self.principal, amount, self.amount)
+        }
+    }
+
+    /// Receipts burned for withdrawing amount, the owner withdraws stake not backed by receipts
+    /// first and a receipt holder only redeems stake backed by them. Slashing may leave less
+    /// stake than receipts, emptying the position burns them all
+    pub fn receipt_burn_amount(&self, amount: u64, redeeming: bool) -> u64 {
+        if amount == self.amount {
+            return self.receipt_amount;
+        }
+
+        let backed_amount = if redeeming {
+            amount
+        } else {
+            amount.saturating_sub(self.amount.saturating_sub(self.receipt_amount))
+        };
+        backed_amount.min(self.receipt_amount)
+    }
+
+    /// Apply slashes that happened on the nft stake since the last settlement
+    pub fn settle_slash(&mut self, nft_stake: &NftStakeAccount) {
+        if self.slash_factor != nft_stake.slash_factor {
+            // MAX_SLASH_FACTOR keeps the product within u128
+            let amount = (self.amount as u128 * nft_stake.slash_factor / self.slash_factor) as u64;
+            // pending and inactive stake are slashed alike, redistributed penalties only grow
+            // active stake
+            if amount < self.amount {
+                self.activation.shrink(amount, self.amount);
+            }
+            self.amount = amount;
+            self.slash_factor = nft_stake.slash_factor;
+        }
+    }
+}
+
+/// A user's principal across all positions in a pool, slashing does not reduce it
+#[account]
+#[derive(InitSpace)]
+pub struct UserPoolStakeAccount {
+    pub stake_pool: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    /// Receives the rent back when the amount drops to zero and the account is closed
+    pub rent_payer: Pubkey,
+}
+
+#[derive(Debug, Clone, Copy, Default, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct UserReward {
+    pub stream_id: u64,
+    pub reward_per_token_paid: u128,
+    pub pending: u64,
+}
+
+impl UserReward {
+    /// Accrue rewards of a stream brought up to now since the last checkpoint,
+    /// the earning part of amount changes at the pending boundary of activation
+    pub fn accrue(
+        &mut self,
+        reward_stream: &RewardStreamAccount,
+        activation: &StakeActivation,
+        amount: u64,
+        now: u64,
+    ) {
+        // the slot was reused by a new stream
+        if self.stream_id != reward_stream.id {
+            *self = UserReward {
+                stream_id: reward_stream.id,
+                ..Default::default()
+            };
+        }
+
+        let reward_per_token = reward_stream.reward_per_token;
+        let before = activation.active_before(amount);
+        let after = activation.active_after(amount);
+        let earned = if activation.activation_time <= now && before != after {
+            // an unrecorded boundary is rounded so that it does not overpay
+            let boundary_reward_per_token = reward_stream
+                .reward_per_token_at(activation.activation_time, after > before)
+                .clamp(self.reward_per_token_paid, reward_per_token);
+            before as u128 * (boundary_reward_per_token - self.reward_per_token_paid)
+                + after as u128 * (reward_per_token - boundary_reward_per_token)
+        } else {
+            activation.active_at(amount, now) as u128
+                * (reward_per_token - self.reward_per_token_paid)
+        };
+
+        self.pending += (earned / REWARD_PRECISION) as u64;
+        self.reward_per_token_paid = reward_per_token;
+    }
+}
+
+/// Stake waiting for the next epoch boundary at activation_time: deposits start earning there
+/// and deactivated stake stops earning and becomes withdrawable
+#[derive(Debug, Clone, Copy, Default, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeActivation {
+    pub activating_amount: u64,
+    pub activation_time: u64,
+    pub deactivating_amount: u64,
+    /// Deactivated stake, it no longer earns and can be withdrawn at any time
+    pub inactive_amount: u64,
+}
+
+impl StakeActivation {
+    /// Earning part of amount until the pending boundary
+    pub fn active_before(&self, amount: u64) -> u64 {
+        amount.saturating_sub(self.activating_amount + self.inactive_amount)
+    }
+
+    /// Earning part of amount from the pending boundary on
+    pub fn active_after(&self, amount: u64) -> u64 {
+        amount.saturating_sub(self.deactivating_amount + self.inactive_amount)
+    }
+
+    pub fn active_at(&self, amount: u64, time: u64) -> u64 {
+        if time >= self.activation_time {
+            self.active_after(amount)
+        } else {
+            self.active_before(amount)
+        }
+    }
+
+    /// Settle pending stake once its boundary has passed,
+    /// rewards must have accrued up to now on every stream first
+    pub fn roll(&mut self, now: u64) {
+        if now >= self.activation_time {
+            self.activating_amount = 0;
+            self.inactive_amount += self.deactivating_amount;
+            self.deactivating_amount = 0;
+        }
+    }
+
+    fn set_boundary(&mut self, time: u64) {
+        if self.activating_amount == 0 && self.deactivating_amount == 0 {
+            self.activation_time = time;
+        } else {
+            self.activation_time = self.activation_time.max(time);
+        }
+    }
+
+    pub fn add(&mut self, amount: u64, activation_time: u64) {
+        if amount == 0 || activation_time == 0 {
+            return;
+        }
+
+        self.set_boundary(activation_time);
+        self.activating_amount += amount;
+    }
+
+    /// Stop active stake from earning at deactivation_time, at once when it is zero
+    pub fn deactivate(&mut self, amount: u64, deactivation_time: u64) {
+        if deactivation_time == 0 {
+            self.inactive_amount += amount;
+            return;
+        }
+
+        if amount > 0 {
+            self.set_boundary(deactivation_time);
+            self.deactivating_amount += amount;
+        }
+    }
+
+    /// Stake that can leave right away, active and deactivating stake only when unlocked
+    pub fn withdrawable(&self, amount: u64, unlocked: bool) -> u64 {
+        if unlocked {
+            amount
+        } else {
+            amount.min(self.activating_amount + self.inactive_amount)
+        }
+    }
+
+    /// Take amount out of a position, pending and inactive stake go first,
+    /// returns the parts taken from each bucket, the rest was active
+    pub fn remove(&mut self, amount: u64) -> StakeActivation {
+        let mut rest = amount;
+        let mut take = |bucket: &mut u64| {
+            let taken = rest.min(*bucket);
+            *bucket -= taken;
+            rest -= taken;
+            taken
+        };
+
+        StakeActivation {
+            activating_amount: take(&mut self.activating_amount),
+            inactive_amount: take(&mut self.inactive_amount),
+            deactivating_amount: take(&mut self.deactivating_amount),
+            activation_time: self.activation_time,
+        }
+    }
+
+    /// Remove a position's buckets from an nft stake or pool, whose boundary is never earlier,
+    /// so stake the position already rolled to inactive can still be deactivating here
+    pub fn subtract(&mut self, removed: &StakeActivation) {
+        let inactive = removed.inactive_amount.min(self.inactive_amount);
+        self.inactive_amount -= inactive;
+        self.deactivating_amount = self
+            .deactivating_amount
+            .saturating_sub(removed.deactivating_amount + removed.inactive_amount - inactive);
+        self.activating_amount = self
+            .activating_amount
+            .saturating_sub(removed.activating_amount);
+    }
+
+    /// Scale every bucket down as amount shrinks to remaining, returns the parts taken
+    pub fn shrink(&mut self, remaining: u64, amount: u64) -> StakeActivation {
+        let scale = |bucket: u64| (bucket as u128 * remaining as u128 / amount as u128) as u64;
+        let taken = StakeActivation {
+            activating_amount: self.activating_amount - scale(self.activating_amount),
+            deactivating_amount: self.deactivating_amount - scale(self.deactivating_amount),
+            inactive_amount: self.inactive_amount - scale(self.inactive_amount),
+            activation_time: self.activation_time,
+        };
+        self.activating_amount -= taken.activating_amount;
+        self.deactivating_amount -= taken.deactivating_amount;
+        self.inactive_amount -= taken.inactive_amount;
+
+        taken
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeCheckpoint {
+    pub day: u64,
+    pub stake_seconds: u128,
+}
+
+#[derive(Debug, Clone, Default, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeHistory {
+    pub start_time: u64,
+    pub last_update_time: u64,
+    /// Staked amount integrated over time since start_time
+    pub stake_seconds: u128,
+    /// stake_seconds at the latest day boundaries, indexed by day % STAKE_HISTORY_DAYS
+    pub checkpoints: [StakeCheckpoint; STAKE_HISTORY_DAYS],
+}
+
+impl StakeHistory {
+    /// Must be called with the amount staked since the last update, before it changes
+    pub fn accumulate(&mut self, amount: u64, now: u64) {
+        if self.start_time == 0 {
+            self.start_time = now;
+            self.last_update_time = now;
+            return;
+        }
+
+        if now <= self.last_update_time {
+            return;
+        }
+
+        // the amount was constant since the last update, so crossed boundaries are exact
+        let first_day = self.last_update_time / SECONDS_PER_DAY + 1;
+        let last_day = now / SECONDS_PER_DAY;
+        let from_day = first_day.max((last_day + 1).saturating_sub(STAKE_HISTORY_DAYS as u64));
+        for day in from_day..=last_day {
+            let elapsed = day * SECONDS_PER_DAY - self.last_update_time;
+            self.checkpoints[(day % STAKE_HISTORY_DAYS as u64) as usize] = StakeCheckpoint {
+                day,
+                stake_seconds: self.stake_seconds + amount as u128 * elapsed as u128,
+            };
+        }
+
+        self.stake_seconds += amount as u128 * (now - self.last_update_time) as u128;
+        self.last_update_time = now;
+    }
+
+    /// Accumulate the earning part of amount, split at a pending boundary crossed since the
+    /// last update
+    pub fn accumulate_activation(&mut self, activation: &StakeActivation, amount: u64, now: u64) {
+        let boundary = activation.activation_time;
+        if self.start_time != 0 && boundary > self.last_update_time && boundary < now {
+            self.accumulate(activation.active_before(amount), boundary);
+        }
+        self.accumulate(activation.active_at(amount, now), now);
+    }
+
+    fn stake_seconds_at(&self, amount: u64, time: u64) -> Option<u128> {
+        if time <= self.start_time {
+            return Some(0);
+        }
+
+        if time >= self.last_update_time {
+            return Some(
+                self.stake_seconds + amount as u128 * (time - self.last_update_time) as u128,
+            );
+        }
+
+        let day = time / SECONDS_PER_DAY;
+        let checkpoint = self.checkpoints[(day % STAKE_HISTORY_DAYS as u64) as usize];
+        (time.is_multiple_of(SECONDS_PER_DAY) && checkpoint.day == day)
+            .then_some(checkpoint.stake_seconds)
+    }
+
+    /// Average amount over the last `days` full days, ending at the latest day boundary
+    pub fn average(&self, amount: u64, days: u8, now: u64) -> Result<u64> {
+        let days = days as u64;
+        require!(
+            days > 0 && days < STAKE_HISTORY_DAYS as u64,
+            crate::error::ErrorCode::InvalidStakeWindow
+        );
+
+        let end_time = now / SECONDS_PER_DAY * SECONDS_PER_DAY;
+        let start_time = end_time - days * SECONDS_PER_DAY;
+
+        let (Some(start), Some(end)) = (
+            self.stake_seconds_at(amount, start_time),
+            self.stake_seconds_at(amount, end_time),
+        ) else {
+            return Err(crate::error::ErrorCode::InvalidStakeWindow.into());
+        };
+
+        Ok(((end - start) / (end_time - start_time) as u128) as u64)
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeAverage {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub nft_stake_amount: u64,
+    pub user_amount: Option<u64>,
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct SlashNftStakeArgs {
+    pub rate: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DeviceScoreAccount {
+    pub stake_pool: Pubkey,
+    pub nft_stake: Pubkey,
+    pub epoch: u64,
+    /// Uptime in basis points
+    pub uptime_120h: u16,
+    pub uptime_720h: u16,
+    pub score: u64,
+    pub timestamp: u64,
+    /// Gets the rent back once the score is closed
+    pub rent_payer: Pubkey,
+}
+
+impl DeviceScoreAccount {
+    /// Scores older than the pool's max_score_age must not be used
+    pub fn require_fresh(&self, config: &StakePoolConfig, now: u64) -> Result<()> {
+        require_gte!(
+            self.timestamp + config.max_score_age,
+            now,
+            crate::error::ErrorCode::StaleScore
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct PostDeviceScoreArgs {
+    pub epoch: u64,
+    pub uptime_120h: u16,
+    pub uptime_720h: u16,
+    pub score: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DistributorAccount {
+    pub stake_pool: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub reward_mint: Pubkey,
+    pub vault: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub num_nodes: u64,
+    pub claimed_count: u64,
+    /// Claims close and the authority may reclaim the vault after this
+    pub claim_deadline: u64,
+    /// One bit per leaf index, allocated on creation
+    #[max_len(0)]
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl DistributorAccount {
+    pub fn space(num_nodes: u64) -> usize {
+        Self::DISCRIMINATOR.len() + Self::INIT_SPACE + num_nodes.div_ceil(8) as usize
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateDistributorArgs {
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub num_nodes: u64,
+    pub claim_deadline: u64,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimDistributionArgs {
+    pub index: u64,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardStreamAccount {
+    pub stake_pool: Pubkey,
+    pub id: u64,
+    /// Slot in StakePoolAccount.reward_streams
+    pub index: u8,
+    pub reward_mint: Pubkey,
+    pub vault: Pubkey,
+    /// Reward tokens per second shared by all stakers
+    pub emission_rate: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub last_update_time: u64,
+    /// Accumulated rewards per staked token, scaled by REWARD_PRECISION
+    pub reward_per_token: u128,
+    /// Rewards emitted to stakers so far
+    pub distributed_amount: u64,
+    /// Rewards taken out of the stakers' pending balances, fees included
+    pub claimed_amount: u64,
+    /// Unclaimed reward fees held in the vault
+    pub pool_fees: u64,
+    pub protocol_fees: u64,
+    /// reward_per_token where the pool's earning stake changed at an epoch boundary
+    pub checkpoints: [RewardCheckpoint; REWARD_CHECKPOINTS],
+}
+
+#[derive(Debug, Clone, Copy, Default, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RewardCheckpoint {
+    pub time: u64,
+    pub reward_per_token: u128,
+}
+
+impl FeeAccount for RewardStreamAccount {
+    fn fees_mut(&mut self) -> (&mut u64, &mut u64) {
+        (&mut self.pool_fees, &mut self.protocol_fees)
+    }
+}
+
+impl RewardStreamAccount {
+    /// Rewards emitted but not claimed yet, rounding dust included
+    pub fn outstanding_amount(&self) -> u64 {
+        self.distributed_amount - self.claimed_amount
+    }
+
+    /// Take pending rewards out of the stream and charge the reward fee,
+    /// returns what is left for the user
+    pub fn claim(&mut self, admin: &AdminAccount, amount: u64, fee_rate: u16) -> Result<u64> {
+        self.claimed_amount += amount;
+        self.collect_fee(admin, amount, fee_rate)
+    }
+
+    /// Pay amount already in the vault to the current stakers at once
+    pub fn distribute(&mut self, amount: u64, total_amount: u64) {
+        self.reward_per_token += amount as u128 * REWARD_PRECISION / total_amount as u128;
+        self.distributed_amount += amount;
+    }
+
+    /// Must be called before the pool's total_amount or activation changes
+    pub fn update(&mut self, activation: &StakeActivation, total_amount: u64, now: u64) {
+        let until = now.min(self.end_time);
+        if until <= self.last_update_time {
+            return;
+        }
+
+        // the pool's earning stake changes at its pending boundary
+        let boundary = activation.activation_time;
+        if boundary > self.last_update_time && boundary <= until {
+            self.emit(activation.active_before(total_amount), boundary);
+            self.record_checkpoint(boundary);
+        }
+        self.emit(activation.active_at(total_amount, until), until);
+    }
+
+    fn emit(&mut self, total_amount: u64, until: u64) {
+        if total_amount > 0 {
+            // create_reward_stream funds at most u64::MAX for the whole schedule
+            let emitted = (until - self.last_update_time) as u128 * self.emission_rate as u128;
+            let emitted = u64::try_from(emitted).unwrap_or(u64::MAX);
+            self.reward_per_token = self
+                .reward_per_token
+                .saturating_add(emitted as u128 * REWARD_PRECISION / total_amount as u128);
+            self.distributed_amount = self.distributed_amount.saturating_add(emitted);
+        }
+        self.last_update_time = until;
+    }
+
+    fn record_checkpoint(&mut self, time: u64) {
+        let oldest = (0..REWARD_CHECKPOINTS)
+            .min_by_key(|&index| self.checkpoints[index].time)
+            .unwrap_or_default();
+        self.checkpoints[oldest] = RewardCheckpoint {
+            time,
+            reward_per_token: self.reward_per_token,
+        };
+    }
+
+    /// reward_per_token at a boundary up to now, a boundary without a checkpoint
+    /// takes the nearest later one with round_up or the nearest earlier one otherwise
+    pub fn reward_per_token_at(&self, time: u64, round_up: bool) -> u128 {
+        if time <= self.start_time {
+            return 0;
+        }
+        if time >= self.last_update_time {
+            return self.reward_per_token;
+        }
+
+        let checkpoints = self
+            .checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.time > 0);
+        if round_up {
+            checkpoints
+                .filter(|checkpoint| checkpoint.time >= time)
+                .map(|checkpoint| checkpoint.reward_per_token)
+                .min()
+                .unwrap_or(self.reward_per_token)
+        } else {
+            checkpoints
+                .filter(|checkpoint| checkpoint.time <= time)
+                .map(|checkpoint| checkpoint.reward_per_token)
+                .max()
+                .unwrap_or(0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateRewardStreamArgs {
+    pub emission_rate: u64,
+    pub start_time: u64,
+    pub end_time: u64,
 }
@@ -1,4 +1,13 @@
-use anchor_lang::prelude::Result;
+use crate::{
+    error::ErrorCode,
+    state::{NftStakeAccount, RewardStreamAccount, StakePoolAccount, UserStakeAccount},
+};
+use anchor_lang::{prelude::*, solana_program::system_program};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+};
+use solana_program::hash::hashv;
 
 /// x * y / z
 pub fn mul_div(x: u64, y: u64, z: u64) -> Result<u64> {
@@ -9,3 +18,225 @@ pub fn mul_div(x: u64, y: u64, z: u64) -> Result<u64> {
         Ok(result as u64)
     }
 }
+
+/// Read an account still in a legacy layout, the discriminator is the one of its current layout
+pub fn load_legacy_account<T: AnchorDeserialize + Space>(
+    account: &AccountInfo,
+    discriminator: &[u8],
+    program_id: &Pubkey,
+) -> Result<T> {
+    require_keys_eq!(*account.owner, *program_id, ErrorCode::InvalidAccount);
+
+    let data = account.data.borrow();
+    require_eq!(
+        data.len(),
+        discriminator.len() + T::INIT_SPACE,
+        ErrorCode::AlreadyMigrated
+    );
+    require!(data.starts_with(discriminator), ErrorCode::InvalidAccount);
+
+    Ok(T::deserialize(&mut &data[discriminator.len()..])?)
+}
+
+/// Grow a legacy account to its current layout and write it, the payer tops up the rent
+pub fn store_migrated_account<'info, T: AccountSerialize + Discriminator + Space>(
+    account: &AccountInfo<'info>,
+    migrated: &T,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let space = T::DISCRIMINATOR.len() + T::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.resize(space)?;
+    migrated.try_serialize(&mut account.data.borrow_mut().as_mut())?;
+
+    Ok(())
+}
+
+/// Leaf of a distribution merkle tree, prefixed to tell it apart from inner nodes
+pub fn distribution_leaf(index: u64, user: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[0u8],
+        &index.to_le_bytes(),
+        user.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Verify a merkle proof built with sorted pairs
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&[1u8], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[1u8], sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
+
+/// Load an nft stake which could be unstaked already
+pub fn load_nft_stake(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<Option<NftStakeAccount>> {
+    if account.owner.eq(&system_program::ID) {
+        return Ok(None);
+    }
+
+    require_keys_eq!(*account.owner, *program_id, ErrorCode::InvalidAccount);
+
+    let nft_stake = NftStakeAccount::try_deserialize(&mut account.data.borrow().as_ref())?;
+    Ok(Some(nft_stake))
+}
+
+/// Bring every reward stream of the pool up to date and checkpoint the users' rewards.
+/// Streams are passed as remaining accounts, this must run before any stake amount changes
+/// and before pending stake is rolled.
+pub fn update_rewards(
+    stake_pool: &StakePoolAccount,
+    user_stakes: &mut [&mut UserStakeAccount],
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    now: u64,
+) -> Result<()> {
+    for (index, reward_stream_key) in stake_pool.reward_streams.iter().enumerate() {
+        let Some(reward_stream_key) = reward_stream_key else {
+            continue;
+        };
+
+        let reward_stream_info = remaining_accounts
+            .iter()
+            .find(|account| account.key == reward_stream_key && account.is_writable)
+            .ok_or(ErrorCode::MissingRewardStream)?;
+        require_keys_eq!(
+            *reward_stream_info.owner,
+            *program_id,
+            ErrorCode::InvalidAccount
+        );
+
+        let mut reward_stream =
+            RewardStreamAccount::try_deserialize(&mut reward_stream_info.data.borrow().as_ref())?;
+        reward_stream.update(&stake_pool.activation, stake_pool.total_amount, now);
+
+        for user_stake in user_stakes.iter_mut() {
+            user_stake.accrue_rewards(index, &reward_stream, now);
+        }
+
+        reward_stream.try_serialize(&mut reward_stream_info.data.borrow_mut().as_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Find a running reward stream of the pool paying in the stake mint,
+/// passed writable in remaining accounts together with its vault
+pub fn find_stake_mint_stream<'info>(
+    stake_pool: &StakePoolAccount,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    now: u64,
+) -> Result<Option<(AccountInfo<'info>, RewardStreamAccount, AccountInfo<'info>)>> {
+    for reward_stream_key in stake_pool.reward_streams.iter().flatten() {
+        let Some(reward_stream_info) = remaining_accounts
+            .iter()
+            .find(|account| account.key == reward_stream_key && account.is_writable)
+        else {
+            continue;
+        };
+        require_keys_eq!(
+            *reward_stream_info.owner,
+            *program_id,
+            ErrorCode::InvalidAccount
+        );
+
+        let reward_stream =
+            RewardStreamAccount::try_deserialize(&mut reward_stream_info.data.borrow().as_ref())?;
+        if reward_stream.reward_mint != stake_pool.config.stake_token_mint
+            || now >= reward_stream.end_time
+        {
+            continue;
+        }
+
+        if let Some(vault) = remaining_accounts
+            .iter()
+            .find(|account| *account.key == reward_stream.vault && account.is_writable)
+        {
+            return Ok(Some((
+                reward_stream_info.clone(),
+                reward_stream,
+                vault.clone(),
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Reject mint extensions that let a third party move, freeze or block the pool's tokens
+pub fn check_stake_token_mint(mint_info: &AccountInfo) -> Result<()> {
+    if mint_info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    for extension in mint.get_extension_types()? {
+        match extension {
+            ExtensionType::PermanentDelegate
+            | ExtensionType::TransferHook
+            | ExtensionType::NonTransferable
+            | ExtensionType::DefaultAccountState
+            | ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialMintBurn
+            | ExtensionType::Pausable => {
+                msg!("unsupported mint extension {:?}", extension);
+                return Err(ErrorCode::UnsupportedMintExtension.into());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Reward vaults are funded with a fixed amount, so a transfer fee is rejected as well
+pub fn check_reward_mint(mint_info: &AccountInfo) -> Result<()> {
+    check_stake_token_mint(mint_info)?;
+
+    if mint_info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    if mint
+        .get_extension_types()?
+        .contains(&ExtensionType::TransferFeeConfig)
+    {
+        msg!(
+            "unsupported mint extension {:?}",
+            ExtensionType::TransferFeeConfig
+        );
+        return Err(ErrorCode::UnsupportedMintExtension.into());
+    }
+
+    Ok(())
+}
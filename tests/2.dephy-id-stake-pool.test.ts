@@ -1,12 +1,17 @@
 import assert from 'assert';
+import { createHash } from 'crypto';
 import {
+  AccountRole,
   Address,
   airdropFactory,
   createSolanaClient, createTransaction, devnet,
   generateKeyPairSigner,
+  getBase64EncodedWireTransaction,
   getAddressDecoder,
+  getAddressEncoder,
   getSignatureFromTransaction, Instruction, isNone, isSolanaError, KeyPairSigner, lamports,
   signTransactionMessageWithSigners,
+  SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM,
   some,
 } from 'gill'
 import * as splToken from 'gill/programs/token'
@@ -50,6 +55,42 @@ describe("dephy-id-stake-pool", () => {
     }
   }
 
+  // return data of a view instruction, read from a simulated transaction
+  const simulateReturnData = async (instructions: Instruction[]) => {
+    const latestBlockhash = (await rpc.getLatestBlockhash().send()).value
+
+    const transaction = createTransaction({
+      feePayer: payer,
+      instructions,
+      latestBlockhash,
+      version: 0
+    })
+
+    const signedTx = await signTransactionMessageWithSigners(transaction)
+    const { value } = await rpc.simulateTransaction(getBase64EncodedWireTransaction(signedTx), {
+      encoding: 'base64',
+      commitment: 'confirmed',
+    }).send()
+    assert.equal(value.err, null)
+    assert(value.returnData)
+
+    return Buffer.from(value.returnData.data[0], 'base64')
+  }
+
+  // a transaction has to fail with the given custom program error
+  const assertProgramError = async (instructions: Instruction[], code: number) => {
+    await assert.rejects(sendAndConfirmIxs(instructions, { showError: false }), (error) => {
+      for (let cause: unknown = error; cause instanceof Error; cause = cause.cause) {
+        if (isSolanaError(cause, SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM)) {
+          assert.equal(cause.context.code, code)
+          return true
+        }
+      }
+
+      return false
+    })
+  }
+
   let authority: KeyPairSigner
   let stakePoolAuthority: KeyPairSigner
   let vendor: KeyPairSigner
@@ -173,10 +214,21 @@ describe("dephy-id-stake-pool", () => {
         payer,
         stakeTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
         collection: productAssetAddress,
-        args: {
-          maxStakeAmount: 20000_000_000n,
-          configReviewTime: 3n,
-        }
+        maxStakeAmount: 20000_000_000n,
+        configReviewTime: 3n,
+        slasher: null,
+        scoreOracle: null,
+        maxScoreAge: 0n,
+        maxTotalAmount: null,
+        maxPerUser: null,
+        minDepositAmount: 0n,
+        depositFeeRate: 0,
+        withdrawFeeRate: 0,
+        rewardFeeRate: 0,
+        earlyExitPenaltyRate: 0,
+        earlyExitPeriod: 0n,
+        epochDuration: 0n,
+        governanceQuorumRate: 0,
       })
     ])
 
@@ -245,16 +297,12 @@ describe("dephy-id-stake-pool", () => {
     userStakeAddress = userStakeAccountPda[0]
 
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getDepositTokenInstructionAsync({
+      await getDepositTokenInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
         user: tokenOwner1,
-        stakeTokenMint: stPhyMintAddress,
         stakeTokenAccount: stakeTokenAddress,
         userStakeTokenAccount: userTokenAddress1,
-        payer,
-        amount: depositAmount,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
@@ -281,7 +329,7 @@ describe("dephy-id-stake-pool", () => {
           stakePool: stakePoolAddress,
           nftStake: nftStake.address,
           stakeAuthority: didOwner1,
-          payer,
+          rentPayer: payer.address,
         })
       ], { showError: false })
     })
@@ -293,16 +341,15 @@ describe("dephy-id-stake-pool", () => {
     let stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, stakePoolAddress)
 
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getWithdrawInstructionAsync({
+      await getWithdrawInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
-        user: tokenOwner1,
-        payer,
+        user: tokenOwner1.address,
+        authority: tokenOwner1,
         amount: withdrawAmount1,
         stakeTokenMint: stakePoolAccount.data.config.stakeTokenMint,
         stakeTokenAccount: stakePoolAccount.data.stakeTokenAccount,
         userStakeTokenAccount: userTokenAddress1,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
@@ -319,16 +366,13 @@ describe("dephy-id-stake-pool", () => {
 
   it('deposit all', async () => {
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getDepositTokenInstructionAsync({
+      await getDepositTokenInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
         user: tokenOwner1,
-        payer,
         amount: null,
-        stakeTokenMint: stPhyMintAddress,
         stakeTokenAccount: stakeTokenAddress,
         userStakeTokenAccount: userTokenAddress1,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
@@ -349,30 +393,17 @@ describe("dephy-id-stake-pool", () => {
   })
 
   it('should fail to deposit when amount exceeds maxStakeAmount', async () => {
-    await sendAndConfirmIxs(
-      splToken.getMintTokensInstructions({
-        feePayer: payer,
-        mint: stPhyMintAddress,
-        mintAuthority: vendor,
-        destination: tokenOwner1.address,
-        ata: userTokenAddress1,
-        amount: 25_000_000_000n,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
-      })
-    )
+    await mintStakeToken(tokenOwner1.address, 25_000_000_000n)
 
     await assert.rejects(async () => {
       await sendAndConfirmIxs([
-        await dephyIdStakePool.getDepositTokenInstructionAsync({
+        await getDepositTokenInstruction({
           stakePool: stakePoolAddress,
           nftStake: nftStake.address,
           user: tokenOwner1,
-          stakeTokenMint: stPhyMintAddress,
           stakeTokenAccount: stakeTokenAddress,
           userStakeTokenAccount: userTokenAddress1,
-          payer,
           amount: 20_000_000_001n,
-          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
         })
       ], { showError: false })
     })
@@ -380,18 +411,17 @@ describe("dephy-id-stake-pool", () => {
 
   it('unstake nft', async () => {
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getUnstakeNftInstructionAsync({
+      await getUnstakeNftInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
         stakeAuthority: didOwner1,
-        mplCoreCollection: productAssetAddress,
         mplCoreAsset: did1Address,
-        payer,
       })
     ])
 
-    const nftStakeAccount = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, nftStake.address)
-    assert.equal(nftStakeAccount.exists, false)
+    // kept for the depositor still in it
+    const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStake.address)
+    assert.equal(nftStakeAccount.data.unstaked, true)
 
     const assetAccount = await mplCore.fetchAssetAccount(rpc, did1Address)
     assert.equal(assetAccount.data.plugins.freezeDelegate, null)
@@ -402,16 +432,14 @@ describe("dephy-id-stake-pool", () => {
     let stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, stakePoolAddress)
 
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getWithdrawInstructionAsync({
+      await getWithdrawInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
-        user: tokenOwner1,
-        payer,
-        amount: null,
+        user: tokenOwner1.address,
+        authority: tokenOwner1,
         stakeTokenMint: stakePoolAccount.data.config.stakeTokenMint,
         stakeTokenAccount: stakePoolAccount.data.stakeTokenAccount,
         userStakeTokenAccount: userTokenAddress1,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
@@ -422,22 +450,25 @@ describe("dephy-id-stake-pool", () => {
     assert.equal(stakePoolAccount.data.totalAmount, 0n, 'stakePool totalAmount')
   })
 
+  it('close the unstaked nft stake', async () => {
+    await sendAndConfirmIxs([
+      await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+        stakePool: stakePoolAddress,
+        nftStake: nftStake.address,
+        stakeAuthority: didOwner1,
+        rentPayer: payer.address,
+      })
+    ])
+
+    const nftStakeAccount = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, nftStake.address)
+    assert.equal(nftStakeAccount.exists, false)
+  })
+
 
   it('multiple user deposit', async () => {
     nftStake = await generateKeyPairSigner()
     const tokenOwner2 = await generateKeyPairSigner()
-    const userTokenAddress2 = await splToken.getAssociatedTokenAccountAddress(stPhyMintAddress, tokenOwner2.address, splToken.TOKEN_2022_PROGRAM_ADDRESS)
-    await sendAndConfirmIxs(
-      splToken.getMintTokensInstructions({
-        feePayer: payer,
-        mint: stPhyMintAddress,
-        mintAuthority: vendor,
-        destination: tokenOwner2.address,
-        ata: userTokenAddress2,
-        amount: startingAmount,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
-      })
-    )
+    const userTokenAddress2 = await mintStakeToken(tokenOwner2.address, startingAmount)
 
     // stake with none deposit authority
     await sendAndConfirmIxs([
@@ -454,30 +485,22 @@ describe("dephy-id-stake-pool", () => {
     ])
 
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getDepositTokenInstructionAsync({
+      await getDepositTokenInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
         user: tokenOwner1,
-        stakeTokenMint: stPhyMintAddress,
         stakeTokenAccount: stakeTokenAddress,
         userStakeTokenAccount: userTokenAddress1,
-        payer,
-        amount: depositAmount,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
     await sendAndConfirmIxs([
-      await dephyIdStakePool.getDepositTokenInstructionAsync({
+      await getDepositTokenInstruction({
         stakePool: stakePoolAddress,
         nftStake: nftStake.address,
         user: tokenOwner2,
-        stakeTokenMint: stPhyMintAddress,
         stakeTokenAccount: stakeTokenAddress,
         userStakeTokenAccount: userTokenAddress2,
-        payer,
-        amount: depositAmount,
-        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
       })
     ])
 
@@ -492,10 +515,10 @@ describe("dephy-id-stake-pool", () => {
         stakePool: stakePoolAddress,
         authority: stakePoolAuthority,
         payer,
-        args: {
-          configReviewTime: 2n,
-          maxStakeAmount: 10000_000_000n,
-        }
+        changes: [
+          dephyIdStakePool.configChange('ConfigReviewTime', [2n]),
+          dephyIdStakePool.configChange('MaxStakeAmount', [10000_000_000n]),
+        ],
       })
     ])
 
@@ -507,16 +530,21 @@ describe("dephy-id-stake-pool", () => {
     const announcedConfig = await dephyIdStakePool.fetchAnnouncedConfigAccount(rpc, announcedConfigPda[0])
     assert.equal(announcedConfig.data.stakePool, stakePoolAddress)
     assert.equal(announcedConfig.data.authority, stakePoolAuthority.address)
-    assert.equal(announcedConfig.data.config.configReviewTime, 2n)
-    assert.equal(announcedConfig.data.config.maxStakeAmount, 10000_000_000n)
+    assert.deepEqual(announcedConfig.data.changes, [
+      dephyIdStakePool.configChange('ConfigReviewTime', [2n]),
+      dephyIdStakePool.configChange('MaxStakeAmount', [10000_000_000n]),
+    ])
   })
 
   it('confirm should fail during config review time', async () => {
+    const configChangePda = await dephyIdStakePool.findConfigChangePda({ stakePool: stakePoolAddress, configVersion: 1n })
+
     await assert.rejects(async () => {
       await sendAndConfirmIxs([
         await dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
           stakePool: stakePoolAddress,
           authority: stakePoolAuthority,
+          configChange: configChangePda[0],
           payer,
         })
       ], { showError: false })
@@ -526,10 +554,13 @@ describe("dephy-id-stake-pool", () => {
   it('confirm update config', async () => {
     await Bun.sleep(4000)
 
+    const configChangePda = await dephyIdStakePool.findConfigChangePda({ stakePool: stakePoolAddress, configVersion: 1n })
+
     await sendAndConfirmIxs([
       await dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
         stakePool: stakePoolAddress,
         authority: stakePoolAuthority,
+        configChange: configChangePda[0],
         payer,
       })
     ])
@@ -537,6 +568,11 @@ describe("dephy-id-stake-pool", () => {
     const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, stakePoolAddress)
     assert.equal(stakePoolAccount.data.config.configReviewTime, 2n)
     assert.equal(stakePoolAccount.data.config.maxStakeAmount, 10000_000_000n)
+    assert.equal(stakePoolAccount.data.configVersion, 1n)
+
+    const configChange = await dephyIdStakePool.fetchConfigChangeAccount(rpc, configChangePda[0])
+    assert.equal(configChange.data.configVersion, 1n)
+    assert.equal(configChange.data.changes.length, 2)
   })
 
   it('cancel update config', async () => {
@@ -546,10 +582,10 @@ describe("dephy-id-stake-pool", () => {
         stakePool: stakePoolAddress,
         authority: stakePoolAuthority,
         payer,
-        args: {
-          configReviewTime: 5n,
-          maxStakeAmount: 20000_000_000n,
-        }
+        changes: [
+          dephyIdStakePool.configChange('ConfigReviewTime', [5n]),
+          dephyIdStakePool.configChange('MaxStakeAmount', [20000_000_000n]),
+        ],
       })
     ])
 
@@ -564,4 +600,3252 @@ describe("dephy-id-stake-pool", () => {
     const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, stakePoolAddress)
     assert(isNone(stakePoolAccount.data.announcedConfig))
   })
+
+  const createAta = async (owner: Address, mint = stPhyMintAddress, tokenProgram: Address = splToken.TOKEN_2022_PROGRAM_ADDRESS) => {
+    const ata = await splToken.getAssociatedTokenAccountAddress(mint, owner, tokenProgram)
+    await sendAndConfirmIxs([
+      splToken.getCreateAssociatedTokenIdempotentInstruction({
+        payer,
+        ata,
+        owner,
+        mint,
+        tokenProgram,
+      })
+    ])
+
+    return ata
+  }
+
+  const mintTokens = async (mint: Address, owner: Address, amount: bigint, tokenProgram: Address = splToken.TOKEN_2022_PROGRAM_ADDRESS) => {
+    const ata = await splToken.getAssociatedTokenAccountAddress(mint, owner, tokenProgram)
+    await sendAndConfirmIxs(
+      splToken.getMintTokensInstructions({
+        feePayer: payer,
+        mint,
+        mintAuthority: vendor,
+        destination: owner,
+        ata,
+        amount,
+        tokenProgram,
+      })
+    )
+
+    return ata
+  }
+
+  const mintStakeToken = (owner: Address, amount: bigint) => mintTokens(stPhyMintAddress, owner, amount)
+
+  // the accounts every scenario shares are filled in, the input overrides them
+  type WithDefaults<T, K extends keyof T> = Omit<T, K> & Partial<Pick<T, K>>
+
+  const getDepositTokenInstruction = (
+    input: WithDefaults<dephyIdStakePool.DepositTokenAsyncInput, 'stakeTokenMint' | 'payer' | 'amount' | 'tokenProgram'>
+  ) =>
+    dephyIdStakePool.getDepositTokenInstructionAsync({
+      stakeTokenMint: stPhyMintAddress,
+      payer,
+      amount: depositAmount,
+      tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      ...input,
+    })
+
+  const getWithdrawInstruction = (
+    input: WithDefaults<dephyIdStakePool.WithdrawAsyncInput, 'stakeTokenMint' | 'rentPayer' | 'userPoolStakeRentPayer' | 'amount' | 'tokenProgram'>
+  ) =>
+    dephyIdStakePool.getWithdrawInstructionAsync({
+      stakeTokenMint: stPhyMintAddress,
+      rentPayer: payer.address,
+      userPoolStakeRentPayer: payer.address,
+      amount: null,
+      tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      ...input,
+    })
+
+  const getUnstakeNftInstruction = (
+    input: WithDefaults<dephyIdStakePool.UnstakeNftAsyncInput, 'mplCoreCollection' | 'payer' | 'rentPayer' | 'assetLockRentPayer'>
+  ) =>
+    dephyIdStakePool.getUnstakeNftInstructionAsync({
+      mplCoreCollection: productAssetAddress,
+      payer,
+      rentPayer: payer.address,
+      assetLockRentPayer: payer.address,
+      ...input,
+    })
+
+  const getRefundOrphanedStakeInstruction = (
+    input: WithDefaults<dephyIdStakePool.RefundOrphanedStakeAsyncInput, 'stakeTokenMint' | 'rentPayer' | 'userPoolStakeRentPayer' | 'tokenProgram'>
+  ) =>
+    dephyIdStakePool.getRefundOrphanedStakeInstructionAsync({
+      stakeTokenMint: stPhyMintAddress,
+      rentPayer: payer.address,
+      userPoolStakeRentPayer: payer.address,
+      tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      ...input,
+    })
+
+  const getCreateStakePoolInstruction = async (
+    stakePool: KeyPairSigner,
+    config: Partial<dephyIdStakePool.CreateStakePoolInstructionDataArgs_> = {},
+    stakeTokenMint = stPhyMintAddress,
+  ) =>
+    dephyIdStakePool.getCreateStakePoolInstructionAsync({
+      stakePool,
+      authority,
+      stakePoolAuthority: stakePoolAuthority.address,
+      stakeTokenMint,
+      payer,
+      stakeTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      collection: productAssetAddress,
+      maxStakeAmount: 20000_000_000n,
+      configReviewTime: 3n,
+      slasher: null,
+      scoreOracle: null,
+      maxScoreAge: 0n,
+      maxTotalAmount: null,
+      maxPerUser: null,
+      minDepositAmount: 0n,
+      depositFeeRate: 0,
+      withdrawFeeRate: 0,
+      rewardFeeRate: 0,
+      earlyExitPenaltyRate: 0,
+      earlyExitPeriod: 0n,
+      epochDuration: 0n,
+      governanceQuorumRate: 0,
+      ...config,
+    })
+
+  const createStakePool = async (config: Partial<dephyIdStakePool.CreateStakePoolInstructionDataArgs_> = {}, stakeTokenMint = stPhyMintAddress) => {
+    const stakePool = await generateKeyPairSigner()
+
+    await sendAndConfirmIxs([await getCreateStakePoolInstruction(stakePool, config, stakeTokenMint)])
+
+    const stakeTokenPda = await dephyIdStakePool.findStakeTokenAccountPda({ stakePool: stakePool.address })
+    return { stakePoolAddress: stakePool.address, stakeTokenAddress: stakeTokenPda[0] }
+  }
+
+  const createDevice = async () => {
+    const deviceOwner = await generateKeyPairSigner()
+    const seed = new Uint8Array(32);
+    crypto.getRandomValues(seed);
+    const deviceAddress = (await dephyId.findDeviceAssetPda({ deviceSeed: seed, productAsset: productAssetAddress }))[0]
+
+    await sendAndConfirmIxs([
+      await dephyId.getCreateDeviceInstructionAsync({
+        mintAuthority: vendor,
+        payer,
+        productAsset: productAssetAddress,
+        owner: deviceOwner.address,
+        seed,
+        name: 'Test Device',
+        uri: '',
+      })
+    ])
+
+    return { deviceOwner, deviceAddress }
+  }
+
+  // every nft stake gets its own device
+  const createNftStake = async (stakePool: Address) => {
+    const { deviceOwner, deviceAddress } = await createDevice()
+
+    const nftStake = await generateKeyPairSigner()
+    await sendAndConfirmIxs([
+      await dephyIdStakePool.getCreateNftStakeInstructionAsync({
+        stakePool,
+        payer,
+        nftStake,
+        stakeAuthority: deviceOwner,
+        depositAuthority: zeroAddress,
+        mplCoreAsset: deviceAddress,
+        mplCoreCollection: productAssetAddress,
+        commisionRate: 0,
+      })
+    ])
+
+    return { nftStakeAddress: nftStake.address, deviceOwner, deviceAddress }
+  }
+
+  const deposit = async (stakePool: Address, stakeToken: Address, nftStake: Address, user: KeyPairSigner, amount: bigint) => {
+    const userTokenAddress = await mintStakeToken(user.address, amount)
+
+    await sendAndConfirmIxs([
+      await getDepositTokenInstruction({
+        stakePool,
+        nftStake,
+        user,
+        stakeTokenAccount: stakeToken,
+        userStakeTokenAccount: userTokenAddress,
+        amount,
+      })
+    ])
+
+    return userTokenAddress
+  }
+
+  // a pool with one nft stake, where most scenarios start
+  const createStakedPool = async (config: Partial<dephyIdStakePool.CreateStakePoolInstructionDataArgs_> = {}) => {
+    const pool = await createStakePool(config)
+    return { ...pool, ...(await createNftStake(pool.stakePoolAddress)) }
+  }
+
+  const createReceiptMint = async (stakePool: Address) => {
+    await sendAndConfirmIxs([
+      await dephyIdStakePool.getCreateReceiptMintInstructionAsync({
+        stakePool,
+        authority: stakePoolAuthority,
+        stakeTokenMint: stPhyMintAddress,
+        payer,
+      })
+    ])
+
+    return (await dephyIdStakePool.findReceiptMintPda({ stakePool }))[0]
+  }
+
+  // the stream starts shortly and is funded for its whole schedule by the pool authority
+  const createRewardStream = async (
+    stakePool: Address,
+    emissionRate: bigint,
+    {
+      rewardMint = stPhyMintAddress,
+      tokenProgram = splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      duration = 4n,
+    }: { rewardMint?: Address, tokenProgram?: Address, duration?: bigint } = {},
+  ) => {
+    const { nextRewardStreamId } = (await dephyIdStakePool.fetchStakePoolAccount(rpc, stakePool)).data
+    const rewardStreamAddress = (await dephyIdStakePool.findRewardStreamPda({ stakePool, streamId: nextRewardStreamId }))[0]
+    const rewardVaultAddress = (await dephyIdStakePool.findRewardVaultPda({ rewardStream: rewardStreamAddress }))[0]
+    const startTime = BigInt(Math.floor(Date.now() / 1000) + 2)
+    const endTime = startTime + duration
+    const authorityTokenAddress = await mintTokens(rewardMint, stakePoolAuthority.address, emissionRate * duration, tokenProgram)
+
+    await sendAndConfirmIxs([
+      await dephyIdStakePool.getCreateRewardStreamInstructionAsync({
+        stakePool,
+        authority: stakePoolAuthority,
+        rewardStream: rewardStreamAddress,
+        rewardMint,
+        authorityTokenAccount: authorityTokenAddress,
+        payer,
+        tokenProgram,
+        emissionRate,
+        startTime,
+        endTime,
+      })
+    ])
+
+    return { rewardStreamAddress, rewardVaultAddress, startTime, endTime }
+  }
+
+  const withRemainingAccounts = (instruction: Instruction, accounts: { address: Address, role: AccountRole }[]): Instruction => ({
+    ...instruction,
+    accounts: [...(instruction.accounts ?? []), ...accounts],
+  })
+
+  const sleepUntil = async (timestamp: bigint) => {
+    await Bun.sleep(Math.max(Number(timestamp) * 1000 - Date.now(), 0) + 2000)
+  }
+
+
+  describe('slashing', () => {
+    let slasher: KeyPairSigner
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let slashedNftStake: Address
+    let deviceOwner: KeyPairSigner
+    let deviceAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    const slashAmount = depositAmount / 10n
+
+    before(async () => {
+      slasher = await generateKeyPairSigner()
+      const pool = await createStakedPool({ slasher: slasher.address })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      slashedNftStake = pool.nftStakeAddress
+      deviceOwner = pool.deviceOwner
+      deviceAddress = pool.deviceAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, slashedNftStake, staker, depositAmount)
+    })
+
+    it('should fail to slash without the slasher', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getSlashNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          slasher: stakePoolAuthority,
+          stakeTokenMint: stPhyMintAddress,
+          stakeTokenAccount: poolTokenAddress,
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          rate: 10,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY)
+    })
+
+    it('slash nft stake', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getSlashNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          slasher,
+          stakeTokenMint: stPhyMintAddress,
+          stakeTokenAccount: poolTokenAddress,
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          rate: 10,
+        })
+      ])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, slashedNftStake)
+      assert.equal(nftStakeAccount.data.amount, depositAmount - slashAmount, 'nftStake amount')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, depositAmount - slashAmount, 'stakePool totalAmount')
+
+      const treasuryPda = await dephyIdStakePool.findTreasuryPda({ stakePool: poolAddress })
+      const treasuryAccount = await splToken.fetchToken(rpc, treasuryPda[0])
+      assert.equal(treasuryAccount.data.amount, slashAmount, 'treasury amount')
+    })
+
+    it('unstake a slashed nft stake with deposits left', async () => {
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          stakeAuthority: deviceOwner,
+          mplCoreAsset: deviceAddress,
+        })
+      ])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, slashedNftStake)
+      assert.equal(nftStakeAccount.data.unstaked, true)
+
+      const assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert.equal(assetAccount.data.plugins.freezeDelegate, null)
+    })
+
+    it('should fail to deposit into an unstaked nft stake', async () => {
+      await assertProgramError([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+          amount: 1n,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_NOT_ACTIVE)
+    })
+
+    it('withdraw settles the slash', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const userTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(userTokenAccount.data.amount, depositAmount - slashAmount)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, 0n, 'stakePool totalAmount')
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, slashedNftStake)
+      assert.equal(nftStakeAccount.data.depositorCount, 0)
+    })
+
+    it('close the slashed nft stake', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: slashedNftStake,
+          stakeAuthority: deviceOwner,
+          rentPayer: payer.address,
+        })
+      ])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, slashedNftStake)
+      assert.equal(nftStakeAccount.exists, false)
+    })
+
+    it('withdraw treasury', async () => {
+      const recipientAddress = await createAta(stakePoolAuthority.address)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getWithdrawTreasuryInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          stakeTokenMint: stPhyMintAddress,
+          recipientTokenAccount: recipientAddress,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          amount: null,
+        })
+      ])
+
+      const recipientAccount = await splToken.fetchToken(rpc, recipientAddress)
+      assert.equal(recipientAccount.data.amount, slashAmount)
+    })
+  })
+
+  describe('device scores', () => {
+    let scoreOracle: KeyPairSigner
+    let poolAddress: Address
+    let scoredNftStake: Address
+    let deviceScoreAddress: Address
+
+    before(async () => {
+      scoreOracle = await generateKeyPairSigner()
+      const pool = await createStakedPool({ scoreOracle: scoreOracle.address, maxScoreAge: 3600n })
+      poolAddress = pool.stakePoolAddress
+      scoredNftStake = pool.nftStakeAddress
+      deviceScoreAddress = (await dephyIdStakePool.findDeviceScorePda({ nftStake: scoredNftStake }))[0]
+    })
+
+    it('should fail to post a score without the oracle', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getPostDeviceScoreInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: scoredNftStake,
+          oracle: stakePoolAuthority,
+          payer,
+          epoch: 1n,
+          uptime120h: 9_000,
+          uptime720h: 8_000,
+          score: 100n,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY)
+    })
+
+    it('post device score', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getPostDeviceScoreInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: scoredNftStake,
+          oracle: scoreOracle,
+          payer,
+          epoch: 1n,
+          uptime120h: 9_000,
+          uptime720h: 8_000,
+          score: 100n,
+        })
+      ])
+
+      const deviceScore = await dephyIdStakePool.fetchDeviceScoreAccount(rpc, deviceScoreAddress)
+      assert.equal(deviceScore.data.stakePool, poolAddress)
+      assert.equal(deviceScore.data.nftStake, scoredNftStake)
+      assert.equal(deviceScore.data.epoch, 1n)
+      assert.equal(deviceScore.data.uptime120h, 9_000)
+      assert.equal(deviceScore.data.uptime720h, 8_000)
+      assert.equal(deviceScore.data.score, 100n)
+      assert.equal(deviceScore.data.rentPayer, payer.address)
+
+      // fresh within maxScoreAge
+      await sendAndConfirmIxs([
+        dephyIdStakePool.getGetDeviceScoreInstruction({
+          stakePool: poolAddress,
+          deviceScore: deviceScoreAddress,
+        })
+      ])
+    })
+
+    it('should fail to post a score for an earlier epoch', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getPostDeviceScoreInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: scoredNftStake,
+          oracle: scoreOracle,
+          payer,
+          epoch: 1n,
+          uptime120h: 10_000,
+          uptime720h: 10_000,
+          score: 200n,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STALE_SCORE)
+    })
+
+    it('should fail to post an uptime above 100%', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getPostDeviceScoreInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: scoredNftStake,
+          oracle: scoreOracle,
+          payer,
+          epoch: 2n,
+          uptime120h: 10_001,
+          uptime720h: 10_000,
+          score: 200n,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_SCORE)
+    })
+  })
+
+  describe('merkle distribution', () => {
+    const sha256 = (...parts: Uint8Array[]) => {
+      const hash = createHash('sha256')
+      parts.forEach((part) => hash.update(part))
+      return new Uint8Array(hash.digest())
+    }
+
+    const u64 = (value: bigint) => {
+      const bytes = Buffer.alloc(8)
+      bytes.writeBigUInt64LE(value)
+      return bytes
+    }
+
+    // leaves and inner nodes are prefixed like the program does, pairs are hashed sorted
+    const distributionLeaf = (index: bigint, user: Address, amount: bigint) =>
+      sha256(new Uint8Array([0]), u64(index), new Uint8Array(getAddressEncoder().encode(user)), u64(amount))
+
+    const hashPair = (a: Uint8Array, b: Uint8Array) =>
+      Buffer.compare(a, b) <= 0
+        ? sha256(new Uint8Array([1]), a, b)
+        : sha256(new Uint8Array([1]), b, a)
+
+    const epoch = 1n
+    const claimAmounts = [300_000_000n, 200_000_000n]
+    let poolAddress: Address
+    let distributorAddress: Address
+    let vaultAddress: Address
+    let claimers: KeyPairSigner[]
+    let leaves: Uint8Array[]
+
+    before(async () => {
+      const pool = await createStakePool()
+      poolAddress = pool.stakePoolAddress
+
+      claimers = [await generateKeyPairSigner(), await generateKeyPairSigner()]
+      leaves = claimers.map((claimer, index) => distributionLeaf(BigInt(index), claimer.address, claimAmounts[index]))
+
+      distributorAddress = (await dephyIdStakePool.findDistributorPda({ stakePool: poolAddress, epoch }))[0]
+      vaultAddress = (await dephyIdStakePool.findDistributorVaultPda({ distributor: distributorAddress }))[0]
+    })
+
+    it('create distributor', async () => {
+      const totalAmount = claimAmounts[0] + claimAmounts[1]
+      const authorityTokenAddress = await mintStakeToken(stakePoolAuthority.address, totalAmount)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCreateDistributorInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          distributor: distributorAddress,
+          rewardMint: stPhyMintAddress,
+          authorityTokenAccount: authorityTokenAddress,
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          epoch,
+          merkleRoot: hashPair(leaves[0], leaves[1]),
+          totalAmount,
+          numNodes: 2n,
+          claimDeadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+        })
+      ])
+
+      const distributor = await dephyIdStakePool.fetchDistributorAccount(rpc, distributorAddress)
+      assert.equal(distributor.data.stakePool, poolAddress)
+      assert.equal(distributor.data.vault, vaultAddress)
+      assert.equal(distributor.data.totalAmount, totalAmount)
+
+      const vaultAccount = await splToken.fetchToken(rpc, vaultAddress)
+      assert.equal(vaultAccount.data.amount, totalAmount)
+    })
+
+    const getClaimInstruction = async (index: number, amount: bigint, proof: Uint8Array[]) => {
+      const claimer = claimers[index]
+      const claimerTokenAddress = await createAta(claimer.address)
+
+      return {
+        claimerTokenAddress,
+        instruction: dephyIdStakePool.getClaimDistributionInstruction({
+          distributor: distributorAddress,
+          user: claimer,
+          rewardMint: stPhyMintAddress,
+          vault: vaultAddress,
+          userTokenAccount: claimerTokenAddress,
+          poolWallet: (await dephyIdStakePool.findPoolWalletPda({ stakePool: poolAddress }))[0],
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          index: BigInt(index),
+          amount,
+          proof,
+        }),
+      }
+    }
+
+    it('should fail to claim with a wrong amount', async () => {
+      const { instruction } = await getClaimInstruction(0, claimAmounts[0] + 1n, [leaves[1]])
+
+      await assertProgramError([instruction], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_PROOF)
+    })
+
+    it('claim distribution', async () => {
+      const { claimerTokenAddress, instruction } = await getClaimInstruction(0, claimAmounts[0], [leaves[1]])
+
+      await sendAndConfirmIxs([instruction])
+
+      const claimerTokenAccount = await splToken.fetchToken(rpc, claimerTokenAddress)
+      assert.equal(claimerTokenAccount.data.amount, claimAmounts[0])
+
+      const distributor = await dephyIdStakePool.fetchDistributorAccount(rpc, distributorAddress)
+      assert.equal(distributor.data.claimedAmount, claimAmounts[0])
+      assert.equal(distributor.data.claimedCount, 1n)
+    })
+
+    it('should fail to claim twice', async () => {
+      const { instruction } = await getClaimInstruction(0, claimAmounts[0], [leaves[1]])
+
+      await assertProgramError([instruction], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__ALREADY_CLAIMED)
+    })
+
+    it('claim distribution with the second leaf', async () => {
+      const { claimerTokenAddress, instruction } = await getClaimInstruction(1, claimAmounts[1], [leaves[0]])
+
+      await sendAndConfirmIxs([instruction])
+
+      const claimerTokenAccount = await splToken.fetchToken(rpc, claimerTokenAddress)
+      assert.equal(claimerTokenAccount.data.amount, claimAmounts[1])
+
+      const vaultAccount = await splToken.fetchToken(rpc, vaultAddress)
+      assert.equal(vaultAccount.data.amount, 0n)
+    })
+  })
+
+  describe('redelegate', () => {
+    let poolAddress: Address
+    let sourceNftStake: Address
+    let targetNftStake: Address
+    let staker: KeyPairSigner
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      sourceNftStake = pool.nftStakeAddress
+      targetNftStake = (await createNftStake(poolAddress)).nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      await deposit(poolAddress, pool.stakeTokenAddress, sourceNftStake, staker, depositAmount)
+    })
+
+    const getRedelegateInstruction = async (amount: bigint | null) =>
+      dephyIdStakePool.getRedelegateInstructionAsync({
+        stakePool: poolAddress,
+        sourceNftStake,
+        targetNftStake,
+        user: staker,
+        sourceRentPayer: payer.address,
+        payer,
+        amount,
+      })
+
+    it('should fail to redelegate more than staked', async () => {
+      await assertProgramError([
+        await getRedelegateInstruction(depositAmount + 1n)
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT)
+    })
+
+    it('redelegate part of the stake', async () => {
+      const movedAmount = depositAmount / 4n
+      await sendAndConfirmIxs([await getRedelegateInstruction(movedAmount)])
+
+      const sourceNftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, sourceNftStake)
+      assert.equal(sourceNftStakeAccount.data.amount, depositAmount - movedAmount)
+      assert.equal(sourceNftStakeAccount.data.depositorCount, 1)
+
+      const targetNftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, targetNftStake)
+      assert.equal(targetNftStakeAccount.data.amount, movedAmount)
+      assert.equal(targetNftStakeAccount.data.depositorCount, 1)
+
+      const targetUserStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: targetNftStake, user: staker.address }))[0]
+      const targetUserStake = await dephyIdStakePool.fetchUserStakeAccount(rpc, targetUserStakeAddress)
+      assert.equal(targetUserStake.data.amount, movedAmount)
+    })
+
+    it('redelegate the rest closes the source user stake', async () => {
+      await sendAndConfirmIxs([await getRedelegateInstruction(null)])
+
+      const sourceNftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, sourceNftStake)
+      assert.equal(sourceNftStakeAccount.data.amount, 0n)
+      assert.equal(sourceNftStakeAccount.data.depositorCount, 0)
+
+      const targetNftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, targetNftStake)
+      assert.equal(targetNftStakeAccount.data.amount, depositAmount)
+      assert.equal(targetNftStakeAccount.data.depositorCount, 1)
+
+      const sourceUserStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: sourceNftStake, user: staker.address }))[0]
+      const sourceUserStake = await dephyIdStakePool.fetchMaybeUserStakeAccount(rpc, sourceUserStakeAddress)
+      assert(!sourceUserStake.exists)
+    })
+  })
+
+  describe('receipt mint', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let receiptMintAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    let stakerReceiptAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+      receiptMintAddress = (await dephyIdStakePool.findReceiptMintPda({ stakePool: poolAddress }))[0]
+      staker = await generateKeyPairSigner()
+    })
+
+    it('create receipt mint', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCreateReceiptMintInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          stakeTokenMint: stPhyMintAddress,
+          payer,
+        })
+      ])
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.deepEqual(stakePoolAccount.data.receiptMint, some(receiptMintAddress))
+    })
+
+    it('should fail to deposit without the receipt accounts', async () => {
+      stakerTokenAddress = await mintStakeToken(staker.address, depositAmount)
+
+      await assertProgramError([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED)
+    })
+
+    it('deposit mints receipts', async () => {
+      stakerReceiptAddress = await createAta(staker.address, receiptMintAddress)
+
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+          receiptMint: receiptMintAddress,
+          userReceiptTokenAccount: stakerReceiptAddress,
+        })
+      ])
+
+      const receiptAccount = await splToken.fetchToken(rpc, stakerReceiptAddress)
+      assert.equal(receiptAccount.data.amount, depositAmount)
+    })
+
+    it('a receipt holder redeems the stake backed by its receipts', async () => {
+      const buyer = await generateKeyPairSigner()
+      const buyerReceiptAddress = await createAta(buyer.address, receiptMintAddress)
+      const buyerTokenAddress = await createAta(buyer.address)
+      const redeemAmount = depositAmount / 2n
+
+      await sendAndConfirmIxs([
+        splToken.getTransferCheckedInstruction({
+          source: stakerReceiptAddress,
+          mint: receiptMintAddress,
+          destination: buyerReceiptAddress,
+          authority: staker,
+          amount: redeemAmount,
+          decimals: 6,
+        }, { programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS })
+      ])
+
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: buyer,
+          amount: redeemAmount,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: buyerTokenAddress,
+          receiptMint: receiptMintAddress,
+          receiptTokenAccount: buyerReceiptAddress,
+        })
+      ])
+
+      const buyerReceiptAccount = await splToken.fetchToken(rpc, buyerReceiptAddress)
+      assert.equal(buyerReceiptAccount.data.amount, 0n)
+      const buyerTokenAccount = await splToken.fetchToken(rpc, buyerTokenAddress)
+      assert.equal(buyerTokenAccount.data.amount, redeemAmount)
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.data.amount, depositAmount - redeemAmount)
+      assert.equal(userStakeAccount.data.receiptAmount, depositAmount - redeemAmount)
+    })
+
+    it('withdraw burns receipts', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+          receiptMint: receiptMintAddress,
+          receiptTokenAccount: stakerReceiptAddress,
+        })
+      ])
+
+      const receiptAccount = await splToken.fetchToken(rpc, stakerReceiptAddress)
+      assert.equal(receiptAccount.data.amount, 0n)
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount / 2n)
+    })
+
+    it('receipts redeem against any position of the pool', async () => {
+      const depositWithReceipts = async (nftStake: Address) => {
+        const user = await generateKeyPairSigner()
+        const userTokenAddress = await mintStakeToken(user.address, depositAmount)
+        const userReceiptAddress = await createAta(user.address, receiptMintAddress)
+        await sendAndConfirmIxs([
+          await getDepositTokenInstruction({
+            stakePool: poolAddress,
+            nftStake,
+            user,
+            stakeTokenAccount: poolTokenAddress,
+            userStakeTokenAccount: userTokenAddress,
+            receiptMint: receiptMintAddress,
+            userReceiptTokenAccount: userReceiptAddress,
+          })
+        ])
+
+        return { user, userTokenAddress, userReceiptAddress }
+      }
+      const redeem = async (nftStake: Address, owner: Address, holder: { user: KeyPairSigner, userTokenAddress: Address, userReceiptAddress: Address }) =>
+        getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake,
+          user: owner,
+          authority: holder.user,
+          amount: depositAmount,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: holder.userTokenAddress,
+          receiptMint: receiptMintAddress,
+          receiptTokenAccount: holder.userReceiptAddress,
+        })
+
+      const otherNftStakeAddress = (await createNftStake(poolAddress)).nftStakeAddress
+      const first = await depositWithReceipts(nftStakeAddress)
+      const second = await depositWithReceipts(otherNftStakeAddress)
+
+      // each depositor takes the other's position with their own receipts
+      await sendAndConfirmIxs([await redeem(nftStakeAddress, first.user.address, second)])
+      await sendAndConfirmIxs([await redeem(otherNftStakeAddress, second.user.address, first)])
+
+      for (const [{ user, userTokenAddress, userReceiptAddress }, nftStake] of [[first, nftStakeAddress], [second, otherNftStakeAddress]] as const) {
+        const tokenAccount = await splToken.fetchToken(rpc, userTokenAddress)
+        assert.equal(tokenAccount.data.amount, depositAmount)
+        const receiptAccount = await splToken.fetchToken(rpc, userReceiptAddress)
+        assert.equal(receiptAccount.data.amount, 0n)
+
+        const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake, user: user.address }))[0]
+        const maybeUserStake = await dephyIdStakePool.fetchMaybeUserStakeAccount(rpc, userStakeAddress)
+        assert(!maybeUserStake.exists)
+      }
+    })
+  })
+
+  describe('transfer nft stake', () => {
+    let poolAddress: Address
+    let nftStakeAddress: Address
+    let deviceOwner: KeyPairSigner
+    let deviceAddress: Address
+    let newOwner: KeyPairSigner
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      nftStakeAddress = pool.nftStakeAddress
+      deviceOwner = pool.deviceOwner
+      deviceAddress = pool.deviceAddress
+      newOwner = await generateKeyPairSigner()
+    })
+
+    const getTransferInstruction = async (stakeAuthority: KeyPairSigner) =>
+      dephyIdStakePool.getTransferNftStakeInstructionAsync({
+        nftStake: nftStakeAddress,
+        stakePool: poolAddress,
+        stakeAuthority,
+        newStakeAuthority: newOwner,
+        mplCoreCollection: productAssetAddress,
+        mplCoreAsset: deviceAddress,
+        payer,
+      })
+
+    it('should fail to transfer without the stake authority', async () => {
+      await assertProgramError([
+        await getTransferInstruction(newOwner)
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY)
+    })
+
+    it('transfer nft stake', async () => {
+      await sendAndConfirmIxs([await getTransferInstruction(deviceOwner)])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.stakeAuthority, newOwner.address)
+
+      const assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert.equal(assetAccount.data.base.owner, newOwner.address)
+      assert(assetAccount.data.plugins.freezeDelegate?.frozen)
+    })
+  })
+
+  describe('sunset and close', () => {
+    let scoreOracle: KeyPairSigner
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let deviceOwner: KeyPairSigner
+    let deviceAddress: Address
+    let deviceScoreAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+
+    before(async () => {
+      scoreOracle = await generateKeyPairSigner()
+      const pool = await createStakedPool({ scoreOracle: scoreOracle.address, maxScoreAge: 3600n })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+      deviceOwner = pool.deviceOwner
+      deviceAddress = pool.deviceAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+
+      deviceScoreAddress = (await dephyIdStakePool.findDeviceScorePda({ nftStake: nftStakeAddress }))[0]
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getPostDeviceScoreInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          oracle: scoreOracle,
+          payer,
+          epoch: 1n,
+          uptime120h: 10_000,
+          uptime720h: 10_000,
+          score: 1n,
+        })
+      ])
+    })
+
+    const getForceUnstakeInstruction = async () =>
+      dephyIdStakePool.getForceUnstakeNftInstructionAsync({
+        nftStake: nftStakeAddress,
+        stakePool: poolAddress,
+        authority: stakePoolAuthority,
+        mplCoreCollection: productAssetAddress,
+        mplCoreAsset: deviceAddress,
+        payer,
+        assetLockRentPayer: payer.address,
+      })
+
+    const getCloseStakePoolInstruction = async () =>
+      dephyIdStakePool.getCloseStakePoolInstructionAsync({
+        stakePool: poolAddress,
+        authority: stakePoolAuthority,
+        stakeTokenMint: stPhyMintAddress,
+        refundTokenAccount: await createAta(stakePoolAuthority.address),
+        payer,
+        tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+      })
+
+    const getCloseDeviceScoreInstruction = () =>
+      dephyIdStakePool.getCloseDeviceScoreInstruction({
+        deviceScore: deviceScoreAddress,
+        nftStake: nftStakeAddress,
+        rentPayer: payer.address,
+      })
+
+    it('should fail to force unstake before sunset', async () => {
+      await assertProgramError([
+        await getForceUnstakeInstruction()
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_SUNSET)
+    })
+
+    it('should fail to close the score of a staked asset', async () => {
+      await assertProgramError([
+        getCloseDeviceScoreInstruction()
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE)
+    })
+
+    it('sunset stake pool', async () => {
+      await sendAndConfirmIxs([
+        dephyIdStakePool.getSunsetStakePoolInstruction({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+        })
+      ])
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert(stakePoolAccount.data.sunset)
+    })
+
+    it('should fail to deposit into a sunset pool', async () => {
+      const userTokenAddress = await mintStakeToken(staker.address, depositAmount)
+
+      await assertProgramError([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: userTokenAddress,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_SUNSET)
+    })
+
+    it('force unstake nft', async () => {
+      await sendAndConfirmIxs([await getForceUnstakeInstruction()])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert(nftStakeAccount.data.unstaked)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.nftStakeCount, 0n)
+
+      const assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert(!assetAccount.data.plugins.freezeDelegate?.frozen)
+    })
+
+    it('should fail to close a pool with deposits left', async () => {
+      await assertProgramError([
+        await getCloseStakePoolInstruction()
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_EMPTY)
+    })
+
+    it('close stake pool once everything is withdrawn', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      await sendAndConfirmIxs([await getCloseStakePoolInstruction()])
+
+      const stakePoolAccount = await dephyIdStakePool.fetchMaybeStakePoolAccount(rpc, poolAddress)
+      assert(!stakePoolAccount.exists)
+    })
+
+    it('close the score and the nft stake after the pool is gone', async () => {
+      await sendAndConfirmIxs([
+        getCloseDeviceScoreInstruction(),
+        await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+          nftStake: nftStakeAddress,
+          stakePool: poolAddress,
+          stakeAuthority: deviceOwner,
+          rentPayer: payer.address,
+        }),
+      ])
+
+      const deviceScore = await dephyIdStakePool.fetchMaybeDeviceScoreAccount(rpc, deviceScoreAddress)
+      assert(!deviceScore.exists)
+
+      const nftStakeAccount = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, nftStakeAddress)
+      assert(!nftStakeAccount.exists)
+    })
+  })
+
+  describe('stake token mint extensions', () => {
+    const createMint = async (extensions: splToken.ExtensionArgs[], initializeExtensions: (mint: Address) => Instruction[]) => {
+      const mint = await generateKeyPairSigner()
+      const space = BigInt(splToken.getMintSize(extensions))
+      const rent = await rpc.getMinimumBalanceForRentExemption(space).send()
+
+      await sendAndConfirmIxs([
+        getCreateAccountInstruction({
+          payer,
+          newAccount: mint,
+          lamports: rent,
+          space,
+          programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        }),
+        ...initializeExtensions(mint.address),
+        splToken.getInitializeMintInstruction({
+          mint: mint.address,
+          decimals: 6,
+          mintAuthority: vendor.address,
+          freezeAuthority: null,
+        }, { programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS })
+      ])
+
+      return mint.address
+    }
+
+    it('should fail to create a pool with a permanent delegate mint', async () => {
+      const delegate = await generateKeyPairSigner()
+      const mint = await createMint(
+        [splToken.extension('PermanentDelegate', { delegate: delegate.address })],
+        (mint) => [splToken.getInitializePermanentDelegateInstruction({ mint, delegate: delegate.address })],
+      )
+
+      await assertProgramError([
+        await getCreateStakePoolInstruction(await generateKeyPairSigner(), {}, mint)
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_MINT_EXTENSION)
+    })
+
+    it('deposit credits the amount received after the transfer fee', async () => {
+      const transferFee = { epoch: 0n, maximumFee: depositAmount, transferFeeBasisPoints: 100 }
+      const mint = await createMint(
+        [splToken.extension('TransferFeeConfig', {
+          transferFeeConfigAuthority: vendor.address,
+          withdrawWithheldAuthority: vendor.address,
+          withheldAmount: 0n,
+          olderTransferFee: transferFee,
+          newerTransferFee: transferFee,
+        })],
+        (mint) => [splToken.getInitializeTransferFeeConfigInstruction({
+          mint,
+          transferFeeConfigAuthority: vendor.address,
+          withdrawWithheldAuthority: vendor.address,
+          transferFeeBasisPoints: transferFee.transferFeeBasisPoints,
+          maximumFee: transferFee.maximumFee,
+        })],
+      )
+
+      const { stakePoolAddress: poolAddress, stakeTokenAddress: poolTokenAddress } = await createStakePool({}, mint)
+      const { nftStakeAddress } = await createNftStake(poolAddress)
+
+      const staker = await generateKeyPairSigner()
+      const stakerTokenAddress = await mintTokens(mint, staker.address, depositAmount)
+
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenMint: mint,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const receivedAmount = depositAmount - depositAmount / 100n
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.amount, receivedAmount)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, receivedAmount)
+    })
+  })
+
+  describe('reward streams', () => {
+    const emissionRate = 1_000_000n
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let streamNftStake: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    let rewardStreamAddress: Address
+    let rewardVaultAddress: Address
+    let startTime: bigint
+    let endTime: bigint
+
+    const getDepositInstruction = async () =>
+      getDepositTokenInstruction({
+        stakePool: poolAddress,
+        nftStake: streamNftStake,
+        user: staker,
+        stakeTokenAccount: poolTokenAddress,
+        userStakeTokenAccount: stakerTokenAddress,
+      })
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      streamNftStake = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await mintStakeToken(staker.address, depositAmount * 2n)
+
+      await sendAndConfirmIxs([await getDepositInstruction()])
+    })
+
+    it('create reward stream', async () => {
+      const stream = await createRewardStream(poolAddress, emissionRate)
+      rewardStreamAddress = stream.rewardStreamAddress
+      rewardVaultAddress = stream.rewardVaultAddress
+      startTime = stream.startTime
+      endTime = stream.endTime
+      const totalRewards = emissionRate * (endTime - startTime)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.deepEqual(stakePoolAccount.data.rewardStreams[0], some(rewardStreamAddress))
+
+      const rewardStream = await dephyIdStakePool.fetchRewardStreamAccount(rpc, rewardStreamAddress)
+      assert.equal(rewardStream.data.vault, rewardVaultAddress)
+      assert.equal(rewardStream.data.emissionRate, emissionRate)
+
+      const vaultAccount = await splToken.fetchToken(rpc, rewardVaultAddress)
+      assert.equal(vaultAccount.data.amount, totalRewards)
+    })
+
+    it('should fail to deposit without the reward stream', async () => {
+      await assertProgramError(
+        [await getDepositInstruction()],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__MISSING_REWARD_STREAM
+      )
+    })
+
+    it('claim rewards', async () => {
+      await sleepUntil(endTime)
+
+      const amountBefore = (await splToken.fetchToken(rpc, stakerTokenAddress)).data.amount
+
+      await sendAndConfirmIxs([
+        withRemainingAccounts(
+          await dephyIdStakePool.getClaimRewardsInstructionAsync({
+            stakePool: poolAddress,
+            nftStake: streamNftStake,
+            user: staker,
+            tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          }),
+          [
+            { address: rewardStreamAddress, role: AccountRole.WRITABLE },
+            { address: rewardVaultAddress, role: AccountRole.WRITABLE },
+            { address: stPhyMintAddress, role: AccountRole.READONLY },
+            { address: stakerTokenAddress, role: AccountRole.WRITABLE },
+          ]
+        )
+      ])
+
+      // the only staker earns the whole schedule, up to rounding
+      const totalRewards = emissionRate * (endTime - startTime)
+      const claimed = (await splToken.fetchToken(rpc, stakerTokenAddress)).data.amount - amountBefore
+      assert(claimed <= totalRewards && claimed >= totalRewards - 10n, `claimed ${claimed}`)
+
+      const rewardStream = await dephyIdStakePool.fetchRewardStreamAccount(rpc, rewardStreamAddress)
+      assert.equal(rewardStream.data.claimedAmount, claimed)
+
+      const vaultAccount = await splToken.fetchToken(rpc, rewardVaultAddress)
+      assert.equal(vaultAccount.data.amount, totalRewards - claimed)
+    })
+  })
+
+  describe('stake average', () => {
+    let poolAddress: Address
+    let averageNftStake: Address
+    let staker: KeyPairSigner
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      averageNftStake = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      await deposit(poolAddress, pool.stakeTokenAddress, averageNftStake, staker, depositAmount)
+    })
+
+    it('get stake average', async () => {
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: averageNftStake, user: staker.address }))[0]
+
+      const returnData = await simulateReturnData([
+        dephyIdStakePool.getGetStakeAverageInstruction({
+          nftStake: averageNftStake,
+          userStakeAccount: userStakeAddress,
+          days: 7,
+        })
+      ])
+      const average = dephyIdStakePool.getStakeAverageDecoder().decode(returnData)
+
+      assert.equal(average.endTime - average.startTime, 7n * 86_400n)
+      // the stake is younger than the last day boundary, so nothing counts yet
+      assert.equal(average.nftStakeAmount, 0n)
+      assert.deepEqual(average.userAmount, some(0n))
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, averageNftStake)
+      assert(nftStakeAccount.data.stakeHistory.startTime > 0n)
+    })
+
+    it('should fail with an invalid window', async () => {
+      await assertProgramError(
+        [dephyIdStakePool.getGetStakeAverageInstruction({ nftStake: averageNftStake, days: 8 })],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_STAKE_WINDOW
+      )
+    })
+  })
+
+  describe('deposit for a beneficiary', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let fundedNftStake: Address
+    let funder: KeyPairSigner
+    let funderTokenAddress: Address
+    let beneficiary: KeyPairSigner
+    let beneficiaryStakeAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      fundedNftStake = pool.nftStakeAddress
+
+      funder = await generateKeyPairSigner()
+      funderTokenAddress = await mintStakeToken(funder.address, depositAmount)
+      beneficiary = await generateKeyPairSigner()
+      beneficiaryStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: fundedNftStake, user: beneficiary.address }))[0]
+    })
+
+    it('deposit for', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getDepositForInstructionAsync({
+          nftStake: fundedNftStake,
+          stakePool: poolAddress,
+          funder,
+          beneficiary: beneficiary.address,
+          stakeTokenMint: stPhyMintAddress,
+          stakeTokenAccount: poolTokenAddress,
+          funderStakeTokenAccount: funderTokenAddress,
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          amount: depositAmount,
+        })
+      ])
+
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, beneficiaryStakeAddress)
+      assert.equal(userStakeAccount.data.user, beneficiary.address)
+      assert.equal(userStakeAccount.data.amount, depositAmount)
+      // only the beneficiary's own deposits move the timestamp
+      assert.equal(userStakeAccount.data.lastDepositTimestamp, 0n)
+
+      const funderTokenAccount = await splToken.fetchToken(rpc, funderTokenAddress)
+      assert.equal(funderTokenAccount.data.amount, 0n)
+    })
+
+    it('should fail to withdraw as the funder', async () => {
+      await assertProgramError([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: fundedNftStake,
+          user: beneficiary.address,
+          authority: funder,
+          userStakeAccount: beneficiaryStakeAddress,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: funderTokenAddress,
+          amount: depositAmount,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AUTHORITY)
+    })
+
+    it('withdraw as the beneficiary', async () => {
+      const beneficiaryTokenAddress = await createAta(beneficiary.address)
+
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: fundedNftStake,
+          user: beneficiary.address,
+          authority: beneficiary,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: beneficiaryTokenAddress,
+          amount: depositAmount,
+        })
+      ])
+
+      const beneficiaryTokenAccount = await splToken.fetchToken(rpc, beneficiaryTokenAddress)
+      assert.equal(beneficiaryTokenAccount.data.amount, depositAmount)
+    })
+  })
+
+  describe('withdraw to a recipient', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let recipientNftStake: Address
+    let staker: KeyPairSigner
+
+    const getRecipientWithdrawInstruction = async (recipientTokenAccount: Address, rentPayer: Address) =>
+      getWithdrawInstruction({
+        stakePool: poolAddress,
+        nftStake: recipientNftStake,
+        user: staker.address,
+        authority: staker,
+        stakeTokenAccount: poolTokenAddress,
+        userStakeTokenAccount: recipientTokenAccount,
+        rentPayer,
+      })
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      recipientNftStake = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      await deposit(poolAddress, poolTokenAddress, recipientNftStake, staker, depositAmount)
+    })
+
+    it('should fail to return the rent to another account', async () => {
+      const recipient = await generateKeyPairSigner()
+      const recipientTokenAddress = await createAta(recipient.address)
+
+      await assertProgramError(
+        [await getRecipientWithdrawInstruction(recipientTokenAddress, recipient.address)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_ACCOUNT
+      )
+    })
+
+    it('withdraw to another recipient', async () => {
+      const recipient = await generateKeyPairSigner()
+      const recipientTokenAddress = await createAta(recipient.address)
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: recipientNftStake, user: staker.address }))[0]
+
+      await sendAndConfirmIxs([await getRecipientWithdrawInstruction(recipientTokenAddress, payer.address)])
+
+      const recipientTokenAccount = await splToken.fetchToken(rpc, recipientTokenAddress)
+      assert.equal(recipientTokenAccount.data.amount, depositAmount)
+
+      // the emptied position is closed to its rent payer
+      const userStakeAccount = await dephyIdStakePool.fetchMaybeUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.exists, false)
+    })
+  })
+
+  describe('deposit limits', () => {
+    const minDepositAmount = 1_000_000n
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let firstNftStake: Address
+    let secondNftStake: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+
+    const getDepositInstruction = async (nftStake: Address, amount: bigint) =>
+      getDepositTokenInstruction({
+        stakePool: poolAddress,
+        nftStake,
+        user: staker,
+        stakeTokenAccount: poolTokenAddress,
+        userStakeTokenAccount: stakerTokenAddress,
+        amount,
+      })
+
+    before(async () => {
+      const pool = await createStakedPool({ maxPerUser: depositAmount, minDepositAmount })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      firstNftStake = pool.nftStakeAddress
+      secondNftStake = (await createNftStake(poolAddress)).nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await mintStakeToken(staker.address, depositAmount * 2n)
+    })
+
+    it('should fail to deposit less than the minimum', async () => {
+      await assertProgramError(
+        [await getDepositInstruction(firstNftStake, minDepositAmount - 1n)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT
+      )
+    })
+
+    it('should fail to exceed the per user limit across positions', async () => {
+      await sendAndConfirmIxs([await getDepositInstruction(firstNftStake, depositAmount)])
+
+      const userPoolStakeAddress = (await dephyIdStakePool.findUserPoolStakePda({ stakePool: poolAddress, user: staker.address }))[0]
+      const userPoolStake = await dephyIdStakePool.fetchUserPoolStakeAccount(rpc, userPoolStakeAddress)
+      assert.equal(userPoolStake.data.amount, depositAmount)
+      assert.equal(userPoolStake.data.rentPayer, payer.address)
+
+      await assertProgramError(
+        [await getDepositInstruction(secondNftStake, minDepositAmount)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT
+      )
+    })
+
+    it('close the user pool stake with the last withdrawal', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: firstNftStake,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const userPoolStakeAddress = (await dephyIdStakePool.findUserPoolStakePda({ stakePool: poolAddress, user: staker.address }))[0]
+      const userPoolStake = await dephyIdStakePool.fetchMaybeUserPoolStakeAccount(rpc, userPoolStakeAddress)
+      assert.equal(userPoolStake.exists, false)
+
+      // the limit applies to what the user has in the pool now
+      await sendAndConfirmIxs([await getDepositInstruction(secondNftStake, depositAmount)])
+    })
+  })
+
+  describe('config changes', () => {
+    let poolAddress: Address
+    let configChangeAddress: Address
+
+    const getAnnounceInstruction = async (changes: dephyIdStakePool.ConfigChangeArgs_[]) =>
+      dephyIdStakePool.getAnnounceUpdateConfigInstructionAsync({
+        stakePool: poolAddress,
+        authority: stakePoolAuthority,
+        payer,
+        changes,
+      })
+
+    const getCloseConfigChangeInstruction = () =>
+      dephyIdStakePool.getCloseConfigChangeInstruction({
+        configChange: configChangeAddress,
+        stakePool: poolAddress,
+        rentPayer: payer.address,
+      })
+
+    before(async () => {
+      poolAddress = (await createStakePool({ configReviewTime: 1n })).stakePoolAddress
+      configChangeAddress = (await dephyIdStakePool.findConfigChangePda({ stakePool: poolAddress, configVersion: 1n }))[0]
+    })
+
+    it('should fail to announce no changes', async () => {
+      await assertProgramError(
+        [await getAnnounceInstruction([])],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_CONFIG
+      )
+    })
+
+    it('should fail to announce an invalid config', async () => {
+      await assertProgramError([
+        await getAnnounceInstruction([
+          dephyIdStakePool.configChange('MinDepositAmount', [2n]),
+          dephyIdStakePool.configChange('MaxPerUser', [some(1n)]),
+        ])
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_CONFIG)
+    })
+
+    it('confirm config changes', async () => {
+      await sendAndConfirmIxs([
+        await getAnnounceInstruction([dephyIdStakePool.configChange('MinDepositAmount', [2n])])
+      ])
+
+      await Bun.sleep(3000)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          configChange: configChangeAddress,
+          payer,
+        })
+      ])
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.config.minDepositAmount, 2n)
+
+      const configChange = await dephyIdStakePool.fetchConfigChangeAccount(rpc, configChangeAddress)
+      assert.equal(configChange.data.rentPayer, payer.address)
+    })
+
+    it('should fail to close the history of an open pool', async () => {
+      await assertProgramError(
+        [getCloseConfigChangeInstruction()],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STAKE_POOL_NOT_CLOSED
+      )
+    })
+
+    it('close the history once the pool is closed', async () => {
+      await sendAndConfirmIxs([
+        dephyIdStakePool.getSunsetStakePoolInstruction({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+        }),
+        await dephyIdStakePool.getCloseStakePoolInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          stakeTokenMint: stPhyMintAddress,
+          refundTokenAccount: await createAta(stakePoolAuthority.address),
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        }),
+      ])
+
+      await sendAndConfirmIxs([getCloseConfigChangeInstruction()])
+
+      const configChange = await dephyIdStakePool.fetchMaybeConfigChangeAccount(rpc, configChangeAddress)
+      assert.equal(configChange.exists, false)
+    })
+  })
+
+  describe('fees', () => {
+    const depositFee = depositAmount / 100n
+    const withdrawFee = (depositAmount - depositFee) / 50n
+    // the protocol takes 20% of every fee
+    const protocolFee = (depositFee + withdrawFee) / 5n
+    const poolFee = depositFee + withdrawFee - protocolFee
+    let protocolFeeAuthority: KeyPairSigner
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let feeNftStake: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+
+    before(async () => {
+      protocolFeeAuthority = await generateKeyPairSigner()
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getSetProtocolFeeInstructionAsync({
+          authority,
+          protocolFeeRate: 2000,
+          protocolFeeAuthority: protocolFeeAuthority.address,
+        })
+      ])
+
+      const pool = await createStakedPool({ depositFeeRate: 100, withdrawFeeRate: 200 })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      feeNftStake = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await mintStakeToken(staker.address, depositAmount)
+    })
+
+    after(async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getSetProtocolFeeInstructionAsync({
+          authority,
+          protocolFeeRate: 0,
+          protocolFeeAuthority: authority.address,
+        })
+      ])
+    })
+
+    it('should fail to set a protocol fee above 100%', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getSetProtocolFeeInstructionAsync({
+          authority,
+          protocolFeeRate: 10001,
+          protocolFeeAuthority: protocolFeeAuthority.address,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_CONFIG)
+    })
+
+    it('deposit pays the deposit fee', async () => {
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: feeNftStake,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const userStakeAccountPda = await dephyIdStakePool.findUserStakeAccountPda({ nftStake: feeNftStake, user: staker.address })
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAccountPda[0])
+      assert.equal(userStakeAccount.data.amount, depositAmount - depositFee, 'userStake amount')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, depositAmount - depositFee, 'totalAmount')
+      assert.equal(stakePoolAccount.data.poolFees + stakePoolAccount.data.protocolFees, depositFee, 'fees')
+      assert.equal(stakePoolAccount.data.protocolFees, depositFee / 5n, 'protocolFees')
+    })
+
+    it('withdraw pays the withdraw fee', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: feeNftStake,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount - depositFee - withdrawFee)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, 0n, 'totalAmount')
+      assert.equal(stakePoolAccount.data.poolFees, poolFee, 'poolFees')
+      assert.equal(stakePoolAccount.data.protocolFees, protocolFee, 'protocolFees')
+
+      // fees stay in the stake token account until claimed
+      const stakeTokenAccount = await splToken.fetchToken(rpc, poolTokenAddress)
+      assert.equal(stakeTokenAccount.data.amount, depositFee + withdrawFee)
+    })
+
+    it('should fail to claim pool fees without the pool authority', async () => {
+      const recipientAddress = await createAta(protocolFeeAuthority.address)
+
+      await assert.rejects(async () => {
+        await sendAndConfirmIxs([
+          await dephyIdStakePool.getClaimPoolFeesInstructionAsync({
+            stakePool: poolAddress,
+            authority: protocolFeeAuthority,
+            feeMint: stPhyMintAddress,
+            feeTokenAccount: poolTokenAddress,
+            recipientTokenAccount: recipientAddress,
+            tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          })
+        ], { showError: false })
+      })
+    })
+
+    it('claim pool fees', async () => {
+      const recipientAddress = await createAta(stakePoolAuthority.address)
+      const amountBefore = (await splToken.fetchToken(rpc, recipientAddress)).data.amount
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getClaimPoolFeesInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          feeMint: stPhyMintAddress,
+          feeTokenAccount: poolTokenAddress,
+          recipientTokenAccount: recipientAddress,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        })
+      ])
+
+      const recipientAccount = await splToken.fetchToken(rpc, recipientAddress)
+      assert.equal(recipientAccount.data.amount - amountBefore, poolFee)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.poolFees, 0n, 'poolFees')
+    })
+
+    it('claim protocol fees', async () => {
+      const recipientAddress = await createAta(protocolFeeAuthority.address)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getClaimProtocolFeesInstructionAsync({
+          stakePool: poolAddress,
+          authority: protocolFeeAuthority,
+          feeMint: stPhyMintAddress,
+          feeTokenAccount: poolTokenAddress,
+          recipientTokenAccount: recipientAddress,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        })
+      ])
+
+      const recipientAccount = await splToken.fetchToken(rpc, recipientAddress)
+      assert.equal(recipientAccount.data.amount, protocolFee)
+
+      const stakeTokenAccount = await splToken.fetchToken(rpc, poolTokenAddress)
+      assert.equal(stakeTokenAccount.data.amount, 0n)
+    })
+  })
+
+  describe('early exit penalty', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let penaltyNftStake: Address
+    let deviceOwner: KeyPairSigner
+    let deviceAddress: Address
+    let stakers: KeyPairSigner[]
+    let stakerTokenAddresses: Address[]
+    let penalty: bigint
+
+    before(async () => {
+      const pool = await createStakedPool({ earlyExitPenaltyRate: 1000, earlyExitPeriod: 1_000_000n })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      ;({ nftStakeAddress: penaltyNftStake, deviceOwner, deviceAddress } = pool)
+
+      stakers = [await generateKeyPairSigner(), await generateKeyPairSigner()]
+      stakerTokenAddresses = []
+      for (const staker of stakers) {
+        stakerTokenAddresses.push(await deposit(poolAddress, poolTokenAddress, penaltyNftStake, staker, depositAmount))
+      }
+    })
+
+    const withdrawAll = async (index: number) => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: penaltyNftStake,
+          user: stakers[index].address,
+          authority: stakers[index],
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddresses[index],
+        })
+      ])
+
+      return (await splToken.fetchToken(rpc, stakerTokenAddresses[index])).data.amount
+    }
+
+    it('early withdraw leaves the penalty to the other depositors', async () => {
+      const received = await withdrawAll(0)
+      penalty = depositAmount - received
+
+      // 10% at the deposit, decaying over the early exit period
+      assert(penalty > 0n && penalty <= depositAmount / 10n, `penalty ${penalty}`)
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, penaltyNftStake)
+      assert.equal(nftStakeAccount.data.amount, depositAmount + penalty, 'nftStake amount')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, depositAmount + penalty, 'stakePool totalAmount')
+    })
+
+    it('the last depositor takes the penalty without paying one', async () => {
+      // nobody is left to share a penalty with and the pool has no stream in the stake mint
+      const received = await withdrawAll(1)
+      assert.equal(received, depositAmount + penalty)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, 0n, 'stakePool totalAmount')
+    })
+
+    it('unstake after the penalty was redistributed', async () => {
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: poolAddress,
+          nftStake: penaltyNftStake,
+          stakeAuthority: deviceOwner,
+          mplCoreAsset: deviceAddress,
+        })
+      ])
+
+      // nobody is left in it, so the nft stake is closed
+      const nftStakeAccount = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, penaltyNftStake)
+      assert.equal(nftStakeAccount.exists, false)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.nftStakeCount, 0n, 'nftStakeCount')
+    })
+  })
+
+  describe('early exit penalty of unstaked nfts', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let deviceAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool({ earlyExitPenaltyRate: 1000, earlyExitPeriod: 1_000_000n })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      ;({ nftStakeAddress, deviceAddress } = pool)
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+    })
+
+    it('a depositor forced out by a force unstake pays no penalty', async () => {
+      await sendAndConfirmIxs([
+        dephyIdStakePool.getSunsetStakePoolInstruction({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+        }),
+        await dephyIdStakePool.getForceUnstakeNftInstructionAsync({
+          nftStake: nftStakeAddress,
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          mplCoreCollection: productAssetAddress,
+          mplCoreAsset: deviceAddress,
+          payer,
+          assetLockRentPayer: payer.address,
+        }),
+      ])
+
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount)
+    })
+  })
+
+  describe('stake activation', () => {
+    const epochDuration = 5n
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let epochNftStake: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    let userStakeAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool({ epochDuration })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      epochNftStake = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await mintStakeToken(staker.address, depositAmount)
+      userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: epochNftStake, user: staker.address }))[0]
+    })
+
+    const getStakerWithdrawInstruction = () => getWithdrawInstruction({
+      stakePool: poolAddress,
+      nftStake: epochNftStake,
+      user: staker.address,
+      authority: staker,
+      stakeTokenAccount: poolTokenAddress,
+      userStakeTokenAccount: stakerTokenAddress,
+    })
+
+    it('deposit activates at the next epoch boundary', async () => {
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: epochNftStake,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      const { activation, lastDepositTimestamp } = userStakeAccount.data
+      assert.equal(activation.activatingAmount, depositAmount, 'activatingAmount')
+      assert.equal(activation.activationTime % epochDuration, 0n, 'activationTime')
+      assert(activation.activationTime > lastDepositTimestamp, 'activationTime')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.activation.activatingAmount, depositAmount, 'stakePool activatingAmount')
+    })
+
+    it('should fail to withdraw active stake', async () => {
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      await sleepUntil(userStakeAccount.data.activation.activationTime)
+
+      await assertProgramError(
+        [await getStakerWithdrawInstruction()],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__STAKE_NOT_DEACTIVATED
+      )
+    })
+
+    it('deactivate stake', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getDeactivateStakeInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: epochNftStake,
+          user: staker,
+          amount: null,
+        })
+      ])
+
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      const { activation } = userStakeAccount.data
+      assert.equal(activation.activatingAmount, 0n, 'activatingAmount')
+      assert.equal(activation.deactivatingAmount, depositAmount, 'deactivatingAmount')
+      assert.equal(activation.activationTime % epochDuration, 0n, 'activationTime')
+    })
+
+    it('withdraw once the stake is deactivated', async () => {
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      await sleepUntil(userStakeAccount.data.activation.activationTime)
+
+      await sendAndConfirmIxs([await getStakerWithdrawInstruction()])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalAmount, 0n, 'totalAmount')
+      assert.equal(stakePoolAccount.data.activation.inactiveAmount, 0n, 'stakePool inactiveAmount')
+    })
+
+    it('redelegated stake activates again at the next epoch boundary', async () => {
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: epochNftStake,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      await sleepUntil(userStakeAccount.data.activation.activationTime)
+
+      const targetNftStake = (await createNftStake(poolAddress)).nftStakeAddress
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getRedelegateInstructionAsync({
+          stakePool: poolAddress,
+          sourceNftStake: epochNftStake,
+          targetNftStake,
+          user: staker,
+          sourceRentPayer: payer.address,
+          payer,
+          amount: null,
+        })
+      ])
+
+      const targetUserStakePda = await dephyIdStakePool.findUserStakeAccountPda({ nftStake: targetNftStake, user: staker.address })
+      const targetUserStake = await dephyIdStakePool.fetchUserStakeAccount(rpc, targetUserStakePda[0])
+      const { activation, lastDepositTimestamp } = targetUserStake.data
+      assert.equal(activation.activatingAmount, depositAmount, 'activatingAmount')
+      assert.equal(activation.activationTime % epochDuration, 0n, 'activationTime')
+      assert(activation.activationTime > lastDepositTimestamp, 'activationTime')
+
+      const targetNftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, targetNftStake)
+      assert.equal(targetNftStakeAccount.data.activation.activatingAmount, depositAmount, 'nftStake activatingAmount')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.activation.activatingAmount, depositAmount, 'stakePool activatingAmount')
+    })
+  })
+
+  describe('extra collections', () => {
+    let poolAddress: Address
+    let otherVendor: KeyPairSigner
+    let otherProductAddress: Address
+
+    const createOtherNftStake = async () => {
+      const deviceOwner = await generateKeyPairSigner()
+      const seed = new Uint8Array(32)
+      crypto.getRandomValues(seed)
+      const deviceAddress = (await dephyId.findDeviceAssetPda({ deviceSeed: seed, productAsset: otherProductAddress }))[0]
+
+      await sendAndConfirmIxs([
+        await dephyId.getCreateDeviceInstructionAsync({
+          mintAuthority: otherVendor,
+          payer,
+          productAsset: otherProductAddress,
+          owner: deviceOwner.address,
+          seed,
+          name: 'Test Device',
+          uri: '',
+        })
+      ])
+
+      const nftStake = await generateKeyPairSigner()
+      return {
+        nftStakeAddress: nftStake.address,
+        instruction: await dephyIdStakePool.getCreateNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          payer,
+          nftStake,
+          stakeAuthority: deviceOwner,
+          depositAuthority: zeroAddress,
+          mplCoreAsset: deviceAddress,
+          mplCoreCollection: otherProductAddress,
+          commisionRate: 0,
+        }),
+      }
+    }
+
+    before(async () => {
+      poolAddress = (await createStakePool({ configReviewTime: 1n })).stakePoolAddress
+
+      otherVendor = await generateKeyPairSigner()
+      const productName = "Other DePHY ID"
+      otherProductAddress = (await dephyId.findProductAssetPda({ productName, vendor: otherVendor.address }))[0]
+      await sendAndConfirmIxs([
+        await dephyId.getCreateProductInstructionAsync({
+          vendor: otherVendor,
+          payer,
+          name: productName,
+          productAsset: otherProductAddress,
+          uri: "https://example.com/Other-DePHY-ID",
+          plugins: null,
+        }),
+      ])
+    })
+
+    it('should fail to stake an asset of a collection that is not eligible', async () => {
+      const { instruction } = await createOtherNftStake()
+      await assertProgramError(
+        [instruction],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_COLLECTION
+      )
+    })
+
+    it('stake an asset of an extra collection', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getAnnounceUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          payer,
+          changes: [
+            dephyIdStakePool.configChange('SetExtraCollection', [{
+              collection: otherProductAddress,
+              maxStakeAmount: some(depositAmount),
+            }]),
+          ],
+        })
+      ])
+
+      await Bun.sleep(3000)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          configChange: (await dephyIdStakePool.findConfigChangePda({ stakePool: poolAddress, configVersion: 1n }))[0],
+          payer,
+        })
+      ])
+
+      const { nftStakeAddress, instruction } = await createOtherNftStake()
+      await sendAndConfirmIxs([instruction])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.collection, otherProductAddress)
+
+      // the extra collection caps its nft stakes below the pool's max_stake_amount
+      const staker = await generateKeyPairSigner()
+      const stakerTokenAddress = await mintStakeToken(staker.address, depositAmount + 1n)
+      await assertProgramError([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenAccount: (await dephyIdStakePool.findStakeTokenAccountPda({ stakePool: poolAddress }))[0],
+          userStakeTokenAccount: stakerTokenAddress,
+          amount: depositAmount + 1n,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_AMOUNT)
+    })
+  })
+
+  describe('asset attribute rules', () => {
+    let poolAddress: Address
+    let seed: Uint8Array
+
+    before(async () => {
+      poolAddress = (await createStakePool({ configReviewTime: 1n })).stakePoolAddress
+
+      // dephy-id devices carry their seed as an attribute
+      seed = new Uint8Array(32)
+      crypto.getRandomValues(seed)
+      const seedPrefix = getAddressDecoder().decode(seed).slice(0, 8)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getAnnounceUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          payer,
+          changes: [
+            dephyIdStakePool.configChange('SetAttributeRule', [{
+              key: 'Seed',
+              condition: dephyIdStakePool.attributeCondition('Prefix', [seedPrefix]),
+            }]),
+          ],
+        })
+      ])
+
+      await Bun.sleep(3000)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          configChange: (await dephyIdStakePool.findConfigChangePda({ stakePool: poolAddress, configVersion: 1n }))[0],
+          payer,
+        })
+      ])
+    })
+
+    it('should fail to stake an asset not matching the rules', async () => {
+      const { deviceOwner, deviceAddress } = await createDevice()
+      await assertProgramError([
+        await dephyIdStakePool.getCreateNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          payer,
+          nftStake: await generateKeyPairSigner(),
+          stakeAuthority: deviceOwner,
+          depositAuthority: zeroAddress,
+          mplCoreAsset: deviceAddress,
+          mplCoreCollection: productAssetAddress,
+          commisionRate: 0,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INELIGIBLE_ASSET)
+    })
+
+    it('stake an asset matching the rules', async () => {
+      const deviceOwner = await generateKeyPairSigner()
+      const deviceAddress = (await dephyId.findDeviceAssetPda({ deviceSeed: seed, productAsset: productAssetAddress }))[0]
+      await sendAndConfirmIxs([
+        await dephyId.getCreateDeviceInstructionAsync({
+          mintAuthority: vendor,
+          payer,
+          productAsset: productAssetAddress,
+          owner: deviceOwner.address,
+          seed,
+          name: 'Test Device',
+          uri: '',
+        })
+      ])
+
+      const nftStake = await generateKeyPairSigner()
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCreateNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          payer,
+          nftStake,
+          stakeAuthority: deviceOwner,
+          depositAuthority: zeroAddress,
+          mplCoreAsset: deviceAddress,
+          mplCoreCollection: productAssetAddress,
+          commisionRate: 0,
+        })
+      ])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStake.address)
+      assert.equal(nftStakeAccount.data.nftTokenAccount, deviceAddress)
+    })
+  })
+
+  describe('shared asset lock', () => {
+    let firstPoolAddress: Address
+    let secondPoolAddress: Address
+    let firstNftStakeAddress: Address
+    let secondNftStakeAddress: Address
+    let deviceOwner: KeyPairSigner
+    let newOwner: KeyPairSigner
+    let deviceAddress: Address
+    let assetLockAddress: Address
+
+    before(async () => {
+      newOwner = await generateKeyPairSigner()
+      firstPoolAddress = (await createStakePool()).stakePoolAddress
+      secondPoolAddress = (await createStakePool()).stakePoolAddress
+
+      const nftStake = await createNftStake(firstPoolAddress)
+      firstNftStakeAddress = nftStake.nftStakeAddress
+      deviceOwner = nftStake.deviceOwner
+      deviceAddress = nftStake.deviceAddress
+      assetLockAddress = (await dephyIdStakePool.findAssetLockPda({ asset: deviceAddress }))[0]
+
+      const secondNftStake = await generateKeyPairSigner()
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCreateNftStakeInstructionAsync({
+          stakePool: secondPoolAddress,
+          payer,
+          nftStake: secondNftStake,
+          stakeAuthority: deviceOwner,
+          depositAuthority: zeroAddress,
+          mplCoreAsset: deviceAddress,
+          mplCoreCollection: productAssetAddress,
+          commisionRate: 0,
+        })
+      ])
+      secondNftStakeAddress = secondNftStake.address
+    })
+
+    const getUnstakeInstruction = async (stakePool: Address, nftStake: Address) =>
+      getUnstakeNftInstruction({
+        stakePool,
+        nftStake,
+        stakeAuthority: newOwner,
+        mplCoreAsset: deviceAddress,
+      })
+
+    it('holds the asset for both pools', async () => {
+      const assetLockAccount = await dephyIdStakePool.fetchAssetLockAccount(rpc, assetLockAddress)
+      assert.deepEqual([...assetLockAccount.data.stakePools].sort(), [firstPoolAddress, secondPoolAddress].sort())
+    })
+
+    const getTransferInstruction = async (otherNftStakes: Address[]) =>
+      withRemainingAccounts(
+        await dephyIdStakePool.getTransferNftStakeInstructionAsync({
+          nftStake: firstNftStakeAddress,
+          stakePool: firstPoolAddress,
+          stakeAuthority: deviceOwner,
+          newStakeAuthority: newOwner,
+          mplCoreCollection: productAssetAddress,
+          mplCoreAsset: deviceAddress,
+          payer,
+        }),
+        otherNftStakes.map((address) => ({ address, role: AccountRole.WRITABLE })),
+      )
+
+    it('should fail to transfer without the nft stakes in the other pools', async () => {
+      await assertProgramError([
+        await getTransferInstruction([])
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_ACCOUNT)
+    })
+
+    it('transfer a shared nft stake in every pool', async () => {
+      await sendAndConfirmIxs([await getTransferInstruction([secondNftStakeAddress])])
+
+      for (const nftStakeAddress of [firstNftStakeAddress, secondNftStakeAddress]) {
+        const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+        assert.equal(nftStakeAccount.data.stakeAuthority, newOwner.address)
+      }
+
+      const assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert.equal(assetAccount.data.base.owner, newOwner.address)
+      assert(assetAccount.data.plugins.freezeDelegate?.frozen)
+    })
+
+    it('should fail to close an nft stake still holding the asset', async () => {
+      await assertProgramError([
+        await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+          nftStake: firstNftStakeAddress,
+          stakePool: firstPoolAddress,
+          stakeAuthority: deviceOwner,
+          rentPayer: payer.address,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE)
+    })
+
+    it('keeps the asset frozen until the last pool unstakes', async () => {
+      await sendAndConfirmIxs([await getUnstakeInstruction(firstPoolAddress, firstNftStakeAddress)])
+
+      let assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert(assetAccount.data.plugins.freezeDelegate?.frozen)
+      const assetLockAccount = await dephyIdStakePool.fetchAssetLockAccount(rpc, assetLockAddress)
+      assert.deepEqual(assetLockAccount.data.stakePools, [secondPoolAddress])
+
+      await sendAndConfirmIxs([await getUnstakeInstruction(secondPoolAddress, secondNftStakeAddress)])
+
+      assetAccount = await mplCore.fetchAssetAccount(rpc, deviceAddress)
+      assert.equal(assetAccount.data.plugins.freezeDelegate, null)
+      const maybeAssetLock = await dephyIdStakePool.fetchMaybeAssetLockAccount(rpc, assetLockAddress)
+      assert(!maybeAssetLock.exists)
+    })
+  })
+
+  describe('token nfts', () => {
+    let poolAddress: Address
+    let groupMintAddress: Address
+
+    // a Token-2022 mint holding its own group or member extension, the
+    // variable length extension is initialized after the mint
+    const createGroupMint = async (
+      pointer: splToken.ExtensionArgs,
+      extension: splToken.ExtensionArgs,
+      initializeInstructions: (mint: Address) => { pointer: Instruction, extension: Instruction },
+    ) => {
+      const mint = await generateKeyPairSigner()
+      const space = BigInt(splToken.getMintSize([pointer]))
+      const rent = await rpc.getMinimumBalanceForRentExemption(BigInt(splToken.getMintSize([pointer, extension]))).send()
+      const ixs = initializeInstructions(mint.address)
+
+      await sendAndConfirmIxs([
+        getCreateAccountInstruction({
+          payer,
+          newAccount: mint,
+          lamports: rent,
+          space,
+          programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        }),
+        ixs.pointer,
+        splToken.getInitializeMintInstruction({
+          mint: mint.address,
+          decimals: 0,
+          mintAuthority: vendor.address,
+          freezeAuthority: null,
+        }, { programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS }),
+        ixs.extension,
+      ])
+
+      return mint.address
+    }
+
+    const createMemberNft = async (group: Address, owner: Address, revokeMintAuthority = true) => {
+      const mint = await createGroupMint(
+        splToken.extension('GroupMemberPointer', { authority: vendor.address, memberAddress: zeroAddress }),
+        splToken.extension('TokenGroupMember', { mint: zeroAddress, group, memberNumber: 0n }),
+        (mint) => ({
+          pointer: splToken.getInitializeGroupMemberPointerInstruction({ mint, authority: vendor.address, memberAddress: mint }),
+          extension: splToken.getInitializeTokenGroupMemberInstruction({
+            member: mint,
+            memberMint: mint,
+            memberMintAuthority: vendor,
+            group,
+            groupUpdateAuthority: vendor,
+          }),
+        }),
+      )
+
+      const ata = await splToken.getAssociatedTokenAccountAddress(mint, owner, splToken.TOKEN_2022_PROGRAM_ADDRESS)
+      await sendAndConfirmIxs([
+        ...splToken.getMintTokensInstructions({
+          feePayer: payer,
+          mint,
+          mintAuthority: vendor,
+          destination: owner,
+          ata,
+          amount: 1n,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        }),
+        ...(revokeMintAuthority ? [splToken.getSetAuthorityInstruction({
+          owned: mint,
+          owner: vendor,
+          authorityType: splToken.AuthorityType.MintTokens,
+          newAuthority: null,
+        }, { programAddress: splToken.TOKEN_2022_PROGRAM_ADDRESS })] : []),
+      ])
+
+      return { mint, ata }
+    }
+
+    const getCreateTokenNftStakeInstruction = async (nftStake: KeyPairSigner, owner: KeyPairSigner, nft: { mint: Address, ata: Address }, collection = groupMintAddress) =>
+      dephyIdStakePool.getCreateTokenNftStakeInstructionAsync({
+        stakePool: poolAddress,
+        nftStake,
+        stakeAuthority: owner,
+        depositAuthority: zeroAddress,
+        nftMint: nft.mint,
+        nftTokenAccount: nft.ata,
+        collection,
+        payer,
+        nftTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        commisionRate: 0,
+        assetStandard: dephyIdStakePool.AssetStandard.Token2022,
+      })
+
+    before(async () => {
+      groupMintAddress = await createGroupMint(
+        splToken.extension('GroupPointer', { authority: vendor.address, groupAddress: zeroAddress }),
+        splToken.extension('TokenGroup', { updateAuthority: vendor.address, mint: zeroAddress, size: 0n, maxSize: 10n }),
+        (mint) => ({
+          pointer: splToken.getInitializeGroupPointerInstruction({ mint, authority: vendor.address, groupAddress: mint }),
+          extension: splToken.getInitializeTokenGroupInstruction({
+            group: mint,
+            mint,
+            mintAuthority: vendor,
+            updateAuthority: vendor.address,
+            maxSize: 10n,
+          }),
+        }),
+      )
+
+      poolAddress = (await createStakePool({ collection: groupMintAddress })).stakePoolAddress
+    })
+
+    it('should fail to stake a member of another group', async () => {
+      const owner = await generateKeyPairSigner()
+      const nft = await createMemberNft(groupMintAddress, owner.address)
+
+      await assertProgramError([
+        await getCreateTokenNftStakeInstruction(await generateKeyPairSigner(), owner, nft, productAssetAddress)
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__INVALID_COLLECTION)
+    })
+
+    it('should fail to stake an nft whose mint authority is live', async () => {
+      const owner = await generateKeyPairSigner()
+      const nft = await createMemberNft(groupMintAddress, owner.address, false)
+
+      await assertProgramError([
+        await getCreateTokenNftStakeInstruction(await generateKeyPairSigner(), owner, nft)
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__UNSUPPORTED_ASSET)
+    })
+
+    it('escrows the nft until it is unstaked', async () => {
+      const owner = await generateKeyPairSigner()
+      const nft = await createMemberNft(groupMintAddress, owner.address)
+      const nftStake = await generateKeyPairSigner()
+      const nftEscrowAddress = (await dephyIdStakePool.findNftEscrowPda({ nftStake: nftStake.address }))[0]
+
+      await sendAndConfirmIxs([await getCreateTokenNftStakeInstruction(nftStake, owner, nft)])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStake.address)
+      assert.equal(nftStakeAccount.data.nftTokenAccount, nft.mint)
+      assert.equal(nftStakeAccount.data.assetStandard, dephyIdStakePool.AssetStandard.Token2022)
+      const escrowAccount = await splToken.fetchToken(rpc, nftEscrowAddress)
+      assert.equal(escrowAccount.data.amount, 1n)
+
+      // closing the stake would strand the escrowed nft
+      await assertProgramError([
+        await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+          nftStake: nftStake.address,
+          stakePool: poolAddress,
+          stakeAuthority: owner,
+          rentPayer: payer.address,
+        })
+      ], dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE)
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getUnstakeTokenNftInstructionAsync({
+          nftStake: nftStake.address,
+          stakePool: poolAddress,
+          stakeAuthority: owner,
+          nftMint: nft.mint,
+          nftTokenAccount: nft.ata,
+          rentPayer: payer.address,
+          nftTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        })
+      ])
+
+      const ownerTokenAccount = await splToken.fetchToken(rpc, nft.ata)
+      assert.equal(ownerTokenAccount.data.amount, 1n)
+      const maybeEscrow = await splToken.fetchMaybeToken(rpc, nftEscrowAddress)
+      assert(!maybeEscrow.exists)
+      const maybeNftStake = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, nftStake.address)
+      assert(!maybeNftStake.exists)
+    })
+  })
+
+  describe('refund orphaned stake', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let deviceOwner: KeyPairSigner
+    let deviceAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+
+    const getRefundInstruction = async () =>
+      getRefundOrphanedStakeInstruction({
+        stakePool: poolAddress,
+        nftStake: nftStakeAddress,
+        user: staker.address,
+        stakeTokenAccount: poolTokenAddress,
+        userStakeTokenAccount: stakerTokenAddress,
+      })
+
+    before(async () => {
+      // refunds are not charged the withdraw fee
+      const pool = await createStakedPool({ withdrawFeeRate: 200 })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+      deviceOwner = pool.deviceOwner
+      deviceAddress = pool.deviceAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+    })
+
+    it('should fail to refund a stake of an active nft', async () => {
+      await assertProgramError(
+        [await getRefundInstruction()],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__NFT_STAKE_ACTIVE
+      )
+    })
+
+    it('anyone refunds the depositors of an unstaked nft', async () => {
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          stakeAuthority: deviceOwner,
+          mplCoreAsset: deviceAddress,
+        })
+      ])
+
+      // the transaction is only signed by the fee payer
+      await sendAndConfirmIxs([await getRefundInstruction()])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount)
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const userStakeAccount = await dephyIdStakePool.fetchMaybeUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.exists, false)
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.depositorCount, 0)
+      assert.equal(nftStakeAccount.data.amount, 0n)
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.poolFees + stakePoolAccount.data.protocolFees, 0n)
+    })
+
+    it('the owner closes the nft stake kept for the refunded depositors', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCloseNftStakeInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          stakeAuthority: deviceOwner,
+          rentPayer: payer.address,
+        })
+      ])
+
+      const maybeNftStake = await dephyIdStakePool.fetchMaybeNftStakeAccount(rpc, nftStakeAddress)
+      assert(!maybeNftStake.exists)
+    })
+  })
+
+  describe('refund orphaned stake with receipts', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let receiptMintAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    let stakerReceiptAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+
+      // the first deposit predates the receipt mint and is not backed by receipts
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+
+      receiptMintAddress = await createReceiptMint(poolAddress)
+      stakerReceiptAddress = await createAta(staker.address, receiptMintAddress)
+      await mintStakeToken(staker.address, depositAmount)
+      await sendAndConfirmIxs([
+        await getDepositTokenInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+          receiptMint: receiptMintAddress,
+          userReceiptTokenAccount: stakerReceiptAddress,
+        })
+      ])
+
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          stakeAuthority: pool.deviceOwner,
+          mplCoreAsset: pool.deviceAddress,
+        })
+      ])
+    })
+
+    it('refunds the stake not backed by receipts', async () => {
+      await sendAndConfirmIxs([
+        await getRefundOrphanedStakeInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount)
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.data.amount, depositAmount)
+      assert.equal(userStakeAccount.data.receiptAmount, depositAmount)
+    })
+
+    it('the receipt holder redeems the rest with withdraw', async () => {
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+          receiptMint: receiptMintAddress,
+          receiptTokenAccount: stakerReceiptAddress,
+        })
+      ])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount * 2n)
+      const receiptAccount = await splToken.fetchToken(rpc, stakerReceiptAddress)
+      assert.equal(receiptAccount.data.amount, 0n)
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.depositorCount, 0)
+    })
+  })
+
+  describe('refund orphaned stake with a legacy reward mint', () => {
+    const emissionRate = 1_000_000n
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let staker: KeyPairSigner
+    let stakerTokenAddress: Address
+    let rewardMintAddress: Address
+    let rewardStreamAddress: Address
+    let rewardVaultAddress: Address
+    let stakerRewardAddress: Address
+
+    // the program address leaves out a closed user pool stake
+    const getRefundInstruction = async (userPoolStakeAccount?: Address) =>
+      withRemainingAccounts(
+        await getRefundOrphanedStakeInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          userPoolStakeAccount,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        }),
+        [
+          { address: rewardStreamAddress, role: AccountRole.WRITABLE },
+          { address: rewardVaultAddress, role: AccountRole.WRITABLE },
+          { address: rewardMintAddress, role: AccountRole.READONLY },
+          { address: stakerRewardAddress, role: AccountRole.WRITABLE },
+        ]
+      )
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+
+      // the rewards are paid in a mint of the legacy token program
+      const rewardMint = await generateKeyPairSigner()
+      rewardMintAddress = rewardMint.address
+      const mintSize = 82n
+      await sendAndConfirmIxs([
+        getCreateAccountInstruction({
+          payer,
+          newAccount: rewardMint,
+          lamports: await rpc.getMinimumBalanceForRentExemption(mintSize).send(),
+          space: mintSize,
+          programAddress: splToken.TOKEN_PROGRAM_ADDRESS,
+        }),
+        splToken.getInitializeMintInstruction({
+          mint: rewardMintAddress,
+          decimals: 6,
+          mintAuthority: vendor.address,
+          freezeAuthority: null,
+        }, { programAddress: splToken.TOKEN_PROGRAM_ADDRESS })
+      ])
+      stakerRewardAddress = await splToken.getAssociatedTokenAccountAddress(rewardMintAddress, staker.address, splToken.TOKEN_PROGRAM_ADDRESS)
+
+      const stream = await createRewardStream(poolAddress, emissionRate, {
+        rewardMint: rewardMintAddress,
+        tokenProgram: splToken.TOKEN_PROGRAM_ADDRESS,
+      })
+      rewardStreamAddress = stream.rewardStreamAddress
+      rewardVaultAddress = stream.rewardVaultAddress
+
+      await sleepUntil(stream.endTime)
+
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          stakeAuthority: pool.deviceOwner,
+          mplCoreAsset: pool.deviceAddress,
+        })
+      ])
+    })
+
+    it('refunds the stake and keeps the rewards the token program can not pay', async () => {
+      await sendAndConfirmIxs([await getRefundInstruction()])
+
+      const stakerTokenAccount = await splToken.fetchToken(rpc, stakerTokenAddress)
+      assert.equal(stakerTokenAccount.data.amount, depositAmount)
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.data.amount, 0n)
+      assert(userStakeAccount.data.rewards[0].pending > 0n)
+    })
+
+    it('the owner claims the rest with the legacy token program', async () => {
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const pending = (await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)).data.rewards[0].pending
+
+      await sendAndConfirmIxs([
+        splToken.getCreateAssociatedTokenIdempotentInstruction({
+          payer,
+          ata: stakerRewardAddress,
+          owner: staker.address,
+          mint: rewardMintAddress,
+          tokenProgram: splToken.TOKEN_PROGRAM_ADDRESS,
+        }),
+        withRemainingAccounts(
+          await dephyIdStakePool.getClaimRewardsInstructionAsync({
+            stakePool: poolAddress,
+            nftStake: nftStakeAddress,
+            user: staker,
+            tokenProgram: splToken.TOKEN_PROGRAM_ADDRESS,
+          }),
+          [
+            { address: rewardStreamAddress, role: AccountRole.WRITABLE },
+            { address: rewardVaultAddress, role: AccountRole.WRITABLE },
+            { address: rewardMintAddress, role: AccountRole.READONLY },
+            { address: stakerRewardAddress, role: AccountRole.WRITABLE },
+          ]
+        )
+      ])
+
+      const stakerRewardAccount = await splToken.fetchToken(rpc, stakerRewardAddress)
+      assert.equal(stakerRewardAccount.data.amount, pending)
+
+      // with the rewards paid the next refund closes the position
+      await sendAndConfirmIxs([await getRefundInstruction(dephyIdStakePool.DEPHY_ID_STAKE_POOL_PROGRAM_ADDRESS)])
+
+      const maybeUserStake = await dephyIdStakePool.fetchMaybeUserStakeAccount(rpc, userStakeAddress)
+      assert(!maybeUserStake.exists)
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.depositorCount, 0)
+    })
+  })
+
+  describe('config governance', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let announcedConfigAddress: Address
+    let staker: KeyPairSigner
+    let otherStaker: KeyPairSigner
+
+    const announce = async (changes: dephyIdStakePool.ConfigChangeArgs_[]) =>
+      sendAndConfirmIxs([
+        await dephyIdStakePool.getAnnounceUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          payer,
+          changes,
+        })
+      ])
+
+    const getVoteInstruction = async (user: KeyPairSigner, approve: boolean) =>
+      dephyIdStakePool.getVoteConfigInstructionAsync({
+        stakePool: poolAddress,
+        nftStake: nftStakeAddress,
+        user,
+        payer,
+        approve,
+      })
+
+    const getConfirmInstruction = async () =>
+      dephyIdStakePool.getConfirmUpdateConfigInstructionAsync({
+        stakePool: poolAddress,
+        authority: stakePoolAuthority,
+        configChange: (await dephyIdStakePool.findConfigChangePda({ stakePool: poolAddress, configVersion: 1n }))[0],
+        payer,
+      })
+
+    before(async () => {
+      const pool = await createStakedPool({ configReviewTime: 3n, governanceQuorumRate: 5000 })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+      announcedConfigAddress = (await dephyIdStakePool.findAnnouncedConfigPda({ stakePool: poolAddress }))[0]
+
+      staker = await generateKeyPairSigner()
+      otherStaker = await generateKeyPairSigner()
+      await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+      await deposit(poolAddress, poolTokenAddress, nftStakeAddress, otherStaker, depositAmount)
+    })
+
+    it('should fail to confirm a config without the quorum', async () => {
+      await announce([dephyIdStakePool.configChange('MinDepositAmount', [2n])])
+
+      await Bun.sleep(4000)
+
+      await assertProgramError(
+        [await getVoteInstruction(staker, true)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__VOTING_ENDED
+      )
+      await assertProgramError(
+        [await getConfirmInstruction()],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__QUORUM_NOT_REACHED
+      )
+
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getCancelUpdateConfigInstructionAsync({
+          stakePool: poolAddress,
+          authority: stakePoolAuthority,
+          payer,
+        })
+      ])
+    })
+
+    it('confirm a config approved by the stakers', async () => {
+      await announce([dephyIdStakePool.configChange('MinDepositAmount', [2n])])
+
+      await sendAndConfirmIxs([await getVoteInstruction(staker, true)])
+      await assertProgramError(
+        [await getVoteInstruction(staker, false)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__ALREADY_VOTED
+      )
+
+      const announcedConfig = await dephyIdStakePool.fetchAnnouncedConfigAccount(rpc, announcedConfigAddress)
+      assert.equal(announcedConfig.data.totalStake, 2n * depositAmount, 'totalStake')
+      assert.equal(announcedConfig.data.yesVotes, depositAmount, 'yesVotes')
+
+      await Bun.sleep(4000)
+
+      await sendAndConfirmIxs([await getConfirmInstruction()])
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.config.minDepositAmount, 2n)
+    })
+
+    it('stakers veto an announced config', async () => {
+      await announce([dephyIdStakePool.configChange('MinDepositAmount', [3n])])
+
+      const lateStaker = await generateKeyPairSigner()
+      await deposit(poolAddress, poolTokenAddress, nftStakeAddress, lateStaker, depositAmount)
+      await assertProgramError(
+        [await getVoteInstruction(lateStaker, false)],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__VOTE_INELIGIBLE
+      )
+
+      await sendAndConfirmIxs([await getVoteInstruction(staker, false)])
+      await assertProgramError(
+        [await dephyIdStakePool.getVetoUpdateConfigInstructionAsync({ stakePool: poolAddress, rentPayer: payer.address })],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__QUORUM_NOT_REACHED
+      )
+
+      await sendAndConfirmIxs([
+        await getVoteInstruction(otherStaker, false),
+        await dephyIdStakePool.getVetoUpdateConfigInstructionAsync({ stakePool: poolAddress, rentPayer: payer.address }),
+      ])
+
+      const announcedConfig = await dephyIdStakePool.fetchMaybeAnnouncedConfigAccount(rpc, announcedConfigAddress)
+      assert.equal(announcedConfig.exists, false)
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert(isNone(stakePoolAccount.data.announcedConfig))
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const voteRecordAddress = (await dephyIdStakePool.findVoteRecordPda({ userStake: userStakeAddress }))[0]
+      await sendAndConfirmIxs([
+        dephyIdStakePool.getCloseVoteRecordInstruction({
+          voteRecord: voteRecordAddress,
+          announcedConfig: announcedConfigAddress,
+          rentPayer: payer.address,
+        })
+      ])
+
+      const voteRecord = await dephyIdStakePool.fetchMaybeVoteRecordAccount(rpc, voteRecordAddress)
+      assert.equal(voteRecord.exists, false)
+    })
+
+    it('stake added after the announcement does not vote', async () => {
+      await announce([dephyIdStakePool.configChange('MinDepositAmount', [4n])])
+      await sendAndConfirmIxs([await getVoteInstruction(staker, true)])
+
+      // the staker leaves the voted position and gets funded into another one
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: await createAta(staker.address),
+        })
+      ])
+
+      const otherNftStakeAddress = (await createNftStake(poolAddress)).nftStakeAddress
+      const funder = await generateKeyPairSigner()
+      const funderTokenAddress = await mintStakeToken(funder.address, depositAmount * 2n)
+      const getDepositForInstruction = async (nftStake: Address, beneficiary: Address) =>
+        dephyIdStakePool.getDepositForInstructionAsync({
+          nftStake,
+          stakePool: poolAddress,
+          funder,
+          beneficiary,
+          stakeTokenMint: stPhyMintAddress,
+          stakeTokenAccount: poolTokenAddress,
+          funderStakeTokenAccount: funderTokenAddress,
+          payer,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          amount: depositAmount,
+        })
+
+      await sendAndConfirmIxs([await getDepositForInstruction(otherNftStakeAddress, staker.address)])
+      await assertProgramError(
+        [
+          await dephyIdStakePool.getVoteConfigInstructionAsync({
+            stakePool: poolAddress,
+            nftStake: otherNftStakeAddress,
+            user: staker,
+            payer,
+            approve: true,
+          })
+        ],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__VOTE_INELIGIBLE
+      )
+
+      // a top up keeps the vote at the stake held at the announcement
+      await sendAndConfirmIxs([await getDepositForInstruction(nftStakeAddress, otherStaker.address)])
+      await sendAndConfirmIxs([await getVoteInstruction(otherStaker, false)])
+
+      const announcedConfig = await dephyIdStakePool.fetchAnnouncedConfigAccount(rpc, announcedConfigAddress)
+      assert.equal(announcedConfig.data.yesVotes, depositAmount, 'yesVotes')
+      assert.equal(announcedConfig.data.noVotes, depositAmount, 'noVotes')
+    })
+  })
+
+  describe('auto compound', () => {
+    const emissionRate = 1_000_000n
+    // only part of the rewards fits under the cap
+    const maxStakeAmount = depositAmount + emissionRate
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+    let staker: KeyPairSigner
+    let rewardStreamAddress: Address
+    let rewardVaultAddress: Address
+    let endTime: bigint
+
+    const getCompoundInstruction = async (cranker: KeyPairSigner, crankerTokenAccount: Address) =>
+      withRemainingAccounts(
+        await dephyIdStakePool.getCompoundRewardsInstructionAsync({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          rewardStream: rewardStreamAddress,
+          rewardVault: rewardVaultAddress,
+          stakeTokenMint: stPhyMintAddress,
+          stakeTokenAccount: poolTokenAddress,
+          cranker,
+          crankerTokenAccount,
+          tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+        }),
+        [{ address: rewardStreamAddress, role: AccountRole.WRITABLE }]
+      )
+
+    before(async () => {
+      const pool = await createStakedPool({ maxStakeAmount })
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+
+      staker = await generateKeyPairSigner()
+      await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+
+      const stream = await createRewardStream(poolAddress, emissionRate)
+      rewardStreamAddress = stream.rewardStreamAddress
+      rewardVaultAddress = stream.rewardVaultAddress
+      endTime = stream.endTime
+    })
+
+    it('should fail to compound a position without auto compound', async () => {
+      const cranker = await generateKeyPairSigner()
+      await assertProgramError(
+        [await getCompoundInstruction(cranker, await createAta(cranker.address))],
+        dephyIdStakePool.DEPHY_ID_STAKE_POOL_ERROR__AUTO_COMPOUND_DISABLED
+      )
+    })
+
+    it('anyone compounds rewards up to the max stake amount for a bounty', async () => {
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getSetAutoCompoundInstructionAsync({
+          nftStake: nftStakeAddress,
+          user: staker,
+          enabled: true,
+        })
+      ])
+
+      await sleepUntil(endTime)
+
+      const cranker = await generateKeyPairSigner()
+      const crankerTokenAddress = await createAta(cranker.address)
+      await sendAndConfirmIxs([await getCompoundInstruction(cranker, crankerTokenAddress)])
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert(nftStakeAccount.data.amount <= maxStakeAmount && nftStakeAccount.data.amount >= maxStakeAmount - 10n, `amount ${nftStakeAccount.data.amount}`)
+      // compounded rewards count as deposited
+      assert.equal(nftStakeAccount.data.totalDeposited, nftStakeAccount.data.amount, 'totalDeposited')
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.totalDeposited, stakePoolAccount.data.totalAmount, 'pool totalDeposited')
+
+      const userStakeAddress = (await dephyIdStakePool.findUserStakeAccountPda({ nftStake: nftStakeAddress, user: staker.address }))[0]
+      const userStakeAccount = await dephyIdStakePool.fetchUserStakeAccount(rpc, userStakeAddress)
+      assert.equal(userStakeAccount.data.amount, nftStakeAccount.data.amount, 'amount')
+      assert.equal(userStakeAccount.data.principal, depositAmount, 'principal')
+      // what does not fit stays pending
+      assert(userStakeAccount.data.rewards[0].pending > 0n, 'pending')
+
+      const crankerTokenAccount = await splToken.fetchToken(rpc, crankerTokenAddress)
+      assert(crankerTokenAccount.data.amount > 0n, 'bounty')
+    })
+  })
+
+  describe('pool statistics', () => {
+    let poolAddress: Address
+    let poolTokenAddress: Address
+    let nftStakeAddress: Address
+
+    before(async () => {
+      const pool = await createStakedPool()
+      poolAddress = pool.stakePoolAddress
+      poolTokenAddress = pool.stakeTokenAddress
+      nftStakeAddress = pool.nftStakeAddress
+    })
+
+    it('counts stakes, depositors and flows of the pool', async () => {
+      const staker = await generateKeyPairSigner()
+      const otherStaker = await generateKeyPairSigner()
+      const stakerTokenAddress = await deposit(poolAddress, poolTokenAddress, nftStakeAddress, staker, depositAmount)
+      await deposit(poolAddress, poolTokenAddress, nftStakeAddress, otherStaker, depositAmount)
+
+      let stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.nftStakeCount, 1n, 'nftStakeCount')
+      assert.equal(stakePoolAccount.data.depositorCount, 2n, 'depositorCount')
+      assert.equal(stakePoolAccount.data.totalDeposited, 2n * depositAmount, 'totalDeposited')
+      assert(stakePoolAccount.data.lastActivityTimestamp > 0n, 'lastActivityTimestamp')
+
+      await sendAndConfirmIxs([
+        await getWithdrawInstruction({
+          stakePool: poolAddress,
+          nftStake: nftStakeAddress,
+          user: staker.address,
+          authority: staker,
+          stakeTokenAccount: poolTokenAddress,
+          userStakeTokenAccount: stakerTokenAddress,
+        })
+      ])
+
+      stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, poolAddress)
+      assert.equal(stakePoolAccount.data.depositorCount, 1n, 'depositorCount')
+      assert.equal(stakePoolAccount.data.totalWithdrawn, depositAmount, 'totalWithdrawn')
+
+      const nftStakeAccount = await dephyIdStakePool.fetchNftStakeAccount(rpc, nftStakeAddress)
+      assert.equal(nftStakeAccount.data.depositorCount, 1, 'nft depositorCount')
+      assert.equal(nftStakeAccount.data.totalDeposited, 2n * depositAmount, 'nft totalDeposited')
+      assert.equal(nftStakeAccount.data.totalWithdrawn, depositAmount, 'nft totalWithdrawn')
+      assert.equal(nftStakeAccount.data.lastActivityTimestamp, stakePoolAccount.data.lastActivityTimestamp)
+    })
+
+    it('counters match the live stake after compounding, slashing, penalties and refunds', async () => {
+      const slasher = await generateKeyPairSigner()
+      const pool = await createStakePool({ slasher: slasher.address, earlyExitPenaltyRate: 1000, earlyExitPeriod: 1_000_000n })
+      const statsPool = pool.stakePoolAddress
+      const statsPoolToken = pool.stakeTokenAddress
+      const statsNftStake = (await createNftStake(statsPool)).nftStakeAddress
+      const orphanedNftStake = await createNftStake(statsPool)
+
+      const compounder = await generateKeyPairSigner()
+      const leaver = await generateKeyPairSigner()
+      const orphan = await generateKeyPairSigner()
+      await deposit(statsPool, statsPoolToken, statsNftStake, compounder, depositAmount)
+      const leaverTokenAddress = await deposit(statsPool, statsPoolToken, statsNftStake, leaver, depositAmount)
+      const orphanTokenAddress = await deposit(statsPool, statsPoolToken, orphanedNftStake.nftStakeAddress, orphan, depositAmount)
+
+      // a stream in the stake mint gives the compounder rewards to restake
+      const emissionRate = 1_000_000n
+      const { rewardStreamAddress, rewardVaultAddress, endTime } = await createRewardStream(statsPool, emissionRate)
+      await sendAndConfirmIxs([
+        await dephyIdStakePool.getSetAutoCompoundInstructionAsync({
+          nftStake: statsNftStake,
+          user: compounder,
+          enabled: true,
+        })
+      ])
+      const rewardStream = [{ address: rewardStreamAddress, role: AccountRole.WRITABLE }]
+
+      await sleepUntil(endTime)
+
+      const cranker = await generateKeyPairSigner()
+      await sendAndConfirmIxs([
+        withRemainingAccounts(
+          await dephyIdStakePool.getCompoundRewardsInstructionAsync({
+            stakePool: statsPool,
+            nftStake: statsNftStake,
+            user: compounder.address,
+            rewardStream: rewardStreamAddress,
+            rewardVault: rewardVaultAddress,
+            stakeTokenMint: stPhyMintAddress,
+            stakeTokenAccount: statsPoolToken,
+            cranker,
+            crankerTokenAccount: await createAta(cranker.address),
+            tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+          }),
+          rewardStream
+        )
+      ])
+
+      await sendAndConfirmIxs([
+        withRemainingAccounts(
+          await dephyIdStakePool.getSlashNftStakeInstructionAsync({
+            stakePool: statsPool,
+            nftStake: statsNftStake,
+            slasher,
+            stakeTokenMint: stPhyMintAddress,
+            stakeTokenAccount: statsPoolToken,
+            payer,
+            tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
+            rate: 10,
+          }),
+          rewardStream
+        )
+      ])
+
+      // the early exit penalty stays with the compounder
+      await sendAndConfirmIxs([
+        withRemainingAccounts(
+          await getWithdrawInstruction({
+            stakePool: statsPool,
+            nftStake: statsNftStake,
+            user: leaver.address,
+            authority: leaver,
+            amount: depositAmount / 2n,
+            stakeTokenAccount: statsPoolToken,
+            userStakeTokenAccount: leaverTokenAddress,
+          }),
+          rewardStream
+        )
+      ])
+      const leaverTokenAccount = await splToken.fetchToken(rpc, leaverTokenAddress)
+      assert(leaverTokenAccount.data.amount < depositAmount / 2n, 'penalty')
+
+      await sendAndConfirmIxs([
+        await getUnstakeNftInstruction({
+          stakePool: statsPool,
+          nftStake: orphanedNftStake.nftStakeAddress,
+          stakeAuthority: orphanedNftStake.deviceOwner,
+          mplCoreAsset: orphanedNftStake.deviceAddress,
+        })
+      ])
+      await sendAndConfirmIxs([
+        withRemainingAccounts(
+          await getRefundOrphanedStakeInstruction({
+            stakePool: statsPool,
+            nftStake: orphanedNftStake.nftStakeAddress,
+            user: orphan.address,
+            stakeTokenAccount: statsPoolToken,
+            userStakeTokenAccount: orphanTokenAddress,
+          }),
+          [
+            ...rewardStream,
+            { address: rewardVaultAddress, role: AccountRole.WRITABLE },
+            { address: stPhyMintAddress, role: AccountRole.READONLY },
+            { address: orphanTokenAddress, role: AccountRole.WRITABLE },
+          ]
+        )
+      ])
+
+      const nftStakeAccounts = await Promise.all(
+        [statsNftStake, orphanedNftStake.nftStakeAddress].map((address) => dephyIdStakePool.fetchNftStakeAccount(rpc, address))
+      )
+      for (const { data } of nftStakeAccounts) {
+        assert(data.totalSlashed > 0n || data.totalWithdrawn > 0n, 'flows')
+        assert.equal(data.amount, data.totalDeposited - data.totalWithdrawn - data.totalSlashed, 'nft stake counters')
+      }
+      // compounded rewards went into the stake
+      assert(nftStakeAccounts[0].data.totalDeposited > 2n * depositAmount, 'compounded')
+      assert.equal(nftStakeAccounts[1].data.amount, 0n, 'refunded')
+
+      const stakePoolAccount = await dephyIdStakePool.fetchStakePoolAccount(rpc, statsPool)
+      const { totalAmount, totalDeposited, totalWithdrawn, totalSlashed, poolFees, protocolFees } = stakePoolAccount.data
+      assert.equal(totalAmount, totalDeposited - totalWithdrawn - totalSlashed, 'pool counters')
+      assert.equal(totalSlashed, nftStakeAccounts[0].data.totalSlashed, 'totalSlashed')
+      assert.equal(totalAmount, nftStakeAccounts[0].data.amount + nftStakeAccounts[1].data.amount, 'totalAmount')
+
+      const poolTokenAccount = await splToken.fetchToken(rpc, statsPoolToken)
+      assert.equal(poolTokenAccount.data.amount, totalAmount + poolFees + protocolFees, 'stake token account')
+    })
+  })
 })